@@ -0,0 +1,202 @@
+use crate::{isotope::ATOMIC_MASS_ENERGY, Isomer, Isotope, Uncertain};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{f64::consts::LN_2, time::SystemTime};
+
+/// Avogadro constant in 1/mol (SI 2019, exact)
+const AVOGADRO: f64 = 6.022_140_76e23;
+
+/// Radionuclide
+///
+/// Half-life and relative atomic mass of a radioactive species, which is all
+/// that the conversions between mass (g), number of atoms and activity (Bq)
+/// need. Time is in seconds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Radionuclide {
+    half_life: Uncertain,
+    relative_atomic_mass: Uncertain,
+}
+
+impl Radionuclide {
+    /// Curie in Bq
+    pub const CURIE: f64 = 3.7e10;
+
+    /// Radionuclide of a half-life in seconds and a relative atomic mass
+    ///
+    /// For species that are not tabulated; tabulated ones come from
+    /// [`Isotope::radionuclide`] and [`Isomer::radionuclide`].
+    #[must_use]
+    pub const fn new(half_life: Uncertain, relative_atomic_mass: Uncertain) -> Self {
+        Self {
+            half_life,
+            relative_atomic_mass,
+        }
+    }
+
+    /// Activity in Ci of an activity in Bq
+    #[must_use]
+    pub const fn to_curies(becquerels: Uncertain) -> Uncertain {
+        Uncertain::new(
+            becquerels.value / Self::CURIE,
+            becquerels.uncertainty / Self::CURIE,
+        )
+    }
+
+    /// Activity in Bq of an activity in Ci
+    #[must_use]
+    pub const fn from_curies(curies: Uncertain) -> Uncertain {
+        Uncertain::new(curies.value * Self::CURIE, curies.uncertainty * Self::CURIE)
+    }
+
+    /// Half-life in seconds
+    #[must_use]
+    pub const fn half_life(&self) -> Uncertain {
+        self.half_life
+    }
+
+    /// Relative atomic mass
+    #[must_use]
+    pub const fn relative_atomic_mass(&self) -> Uncertain {
+        self.relative_atomic_mass
+    }
+
+    /// Decay constant λ = ln 2 / T½ in 1/s
+    #[must_use]
+    pub fn decay_constant(&self) -> Uncertain {
+        let value = LN_2 / self.half_life.value;
+        Uncertain::new(
+            value,
+            value * self.half_life.uncertainty / self.half_life.value,
+        )
+    }
+
+    /// Specific activity in Bq/g
+    #[must_use]
+    pub fn specific_activity(&self) -> Uncertain {
+        self.mass_to_activity(Uncertain::new(1.0, 0.0))
+    }
+
+    /// Number of atoms in a mass in g
+    #[must_use]
+    pub fn mass_to_atoms(&self, mass: Uncertain) -> Uncertain {
+        let molar_mass = self.relative_atomic_mass;
+        Uncertain::new(
+            mass.value * AVOGADRO / molar_mass.value,
+            (mass.uncertainty + mass.value * molar_mass.uncertainty / molar_mass.value) * AVOGADRO
+                / molar_mass.value,
+        )
+    }
+
+    /// Mass in g of a number of atoms
+    #[must_use]
+    pub fn atoms_to_mass(&self, atoms: Uncertain) -> Uncertain {
+        let molar_mass = self.relative_atomic_mass;
+        Uncertain::new(
+            atoms.value * molar_mass.value / AVOGADRO,
+            (atoms.uncertainty * molar_mass.value + atoms.value * molar_mass.uncertainty)
+                / AVOGADRO,
+        )
+    }
+
+    /// Activity in Bq of a number of atoms
+    ///
+    /// A = λN
+    #[must_use]
+    pub fn atoms_to_activity(&self, atoms: Uncertain) -> Uncertain {
+        let decay_constant = self.decay_constant();
+        Uncertain::new(
+            decay_constant.value * atoms.value,
+            decay_constant.uncertainty * atoms.value + decay_constant.value * atoms.uncertainty,
+        )
+    }
+
+    /// Number of atoms of an activity in Bq
+    #[must_use]
+    pub fn activity_to_atoms(&self, activity: Uncertain) -> Uncertain {
+        let decay_constant = self.decay_constant();
+        let value = activity.value / decay_constant.value;
+        Uncertain::new(
+            value,
+            activity.uncertainty / decay_constant.value
+                + value * decay_constant.uncertainty / decay_constant.value,
+        )
+    }
+
+    /// Activity in Bq of a mass in g
+    #[must_use]
+    pub fn mass_to_activity(&self, mass: Uncertain) -> Uncertain {
+        self.atoms_to_activity(self.mass_to_atoms(mass))
+    }
+
+    /// Mass in g of an activity in Bq
+    #[must_use]
+    pub fn activity_to_mass(&self, activity: Uncertain) -> Uncertain {
+        self.atoms_to_mass(self.activity_to_atoms(activity))
+    }
+
+    /// Decay an activity (or number of atoms) over `elapsed` seconds
+    ///
+    /// A(t) = A₀ · exp(-λt). A negative `elapsed` corrects back in time.
+    #[must_use]
+    pub fn decay(&self, activity: Uncertain, elapsed: f64) -> Uncertain {
+        let decay_constant = self.decay_constant();
+        let factor = (-decay_constant.value * elapsed).exp();
+        Uncertain::new(
+            activity.value * factor,
+            (activity.uncertainty + activity.value * elapsed.abs() * decay_constant.uncertainty)
+                * factor,
+        )
+    }
+
+    /// Decay-correct an activity measured at `from` to `to`
+    ///
+    /// `to` may be earlier than `from`.
+    #[must_use]
+    pub fn decay_between(
+        &self,
+        activity: Uncertain,
+        from: SystemTime,
+        to: SystemTime,
+    ) -> Uncertain {
+        let elapsed = match to.duration_since(from) {
+            Ok(elapsed) => elapsed.as_secs_f64(),
+            Err(error) => -error.duration().as_secs_f64(),
+        };
+        self.decay(activity, elapsed)
+    }
+}
+
+impl Isotope {
+    /// Radionuclide
+    ///
+    /// `None` for stable isotopes and isotopes with an unknown half-life.
+    #[must_use]
+    pub fn radionuclide(&self) -> Option<Radionuclide> {
+        let half_life = self
+            .half_life()
+            .filter(|half_life| half_life.value.is_finite())?;
+        Some(Radionuclide::new(half_life, self.relative_atomic_mass()))
+    }
+}
+
+impl Isomer {
+    /// Radionuclide
+    ///
+    /// The mass is that of the ground-state isotope plus the excitation
+    /// energy. `None` if the isomer is not observed to decay, its half-life is
+    /// unknown or its ground state is not tabulated.
+    #[must_use]
+    pub fn radionuclide(&self) -> Option<Radionuclide> {
+        let half_life = self
+            .half_life()
+            .filter(|half_life| half_life.value.is_finite())?;
+        let ground_state = self.isotope()?.relative_atomic_mass();
+        let excitation_energy = self.excitation_energy()?;
+        let relative_atomic_mass = Uncertain::new(
+            ground_state.value + excitation_energy.value / ATOMIC_MASS_ENERGY,
+            ground_state.uncertainty + excitation_energy.uncertainty / ATOMIC_MASS_ENERGY,
+        );
+        Some(Radionuclide::new(half_life, relative_atomic_mass))
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{isotope::ATOMIC_MASS_ENERGY, isotopes::H, Isotope, Nuclide, Uncertain};
+
+/// Neutron mass in u (CODATA 2018)
+pub(crate) const NEUTRON_MASS: Uncertain = Uncertain::new(1.008_664_915_95, 0.000_000_000_49);
+
+/// Binding energies
+///
+/// Derived from atomic masses: the electrons are counted with the ¹H atoms,
+/// and their binding energies are neglected, as in the AME. All energies are
+/// in keV.
+impl Isotope {
+    /// Binding energy in keV
+    ///
+    /// B = (Z · M(¹H) + N · mₙ - M) · mᵤc²
+    #[must_use]
+    pub fn binding_energy(&self) -> Uncertain {
+        binding_energy(
+            self.atomic_number(),
+            self.neutron_number(),
+            self.relative_atomic_mass(),
+        )
+    }
+
+    /// Binding energy per nucleon B/A in keV
+    #[must_use]
+    pub fn binding_energy_per_nucleon(&self) -> Uncertain {
+        let binding_energy = self.binding_energy();
+        let mass_number = self.mass_number() as f64;
+        Uncertain::new(
+            binding_energy.value / mass_number,
+            binding_energy.uncertainty / mass_number,
+        )
+    }
+
+    /// One-neutron separation energy Sₙ in keV
+    ///
+    /// Sₙ = (M(Z, N - 1) + mₙ - M(Z, N)) · mᵤc². `None` if the lighter
+    /// nuclide is not tabulated.
+    #[must_use]
+    pub fn neutron_separation_energy(&self) -> Option<Uncertain> {
+        self.separation_energy(0, 1)
+    }
+
+    /// Two-neutron separation energy S₂ₙ in keV
+    ///
+    /// S₂ₙ = (M(Z, N - 2) + 2mₙ - M(Z, N)) · mᵤc². `None` if the lighter
+    /// nuclide is not tabulated.
+    #[must_use]
+    pub fn two_neutron_separation_energy(&self) -> Option<Uncertain> {
+        self.separation_energy(0, 2)
+    }
+
+    /// One-proton separation energy Sₚ in keV
+    ///
+    /// Sₚ = (M(Z - 1, N) + M(¹H) - M(Z, N)) · mᵤc². `None` if the lighter
+    /// nuclide is not tabulated.
+    #[must_use]
+    pub fn proton_separation_energy(&self) -> Option<Uncertain> {
+        self.separation_energy(1, 0)
+    }
+
+    /// Two-proton separation energy S₂ₚ in keV
+    ///
+    /// S₂ₚ = (M(Z - 2, N) + 2M(¹H) - M(Z, N)) · mᵤc². `None` if the lighter
+    /// nuclide is not tabulated.
+    #[must_use]
+    pub fn two_proton_separation_energy(&self) -> Option<Uncertain> {
+        self.separation_energy(2, 0)
+    }
+
+    /// Separation energy of `protons` protons and `neutrons` neutrons
+    ///
+    /// Computed from the masses directly, so that the uncertainties of M(¹H)
+    /// and mₙ shared by both binding energies are not counted twice.
+    fn separation_energy(&self, protons: usize, neutrons: usize) -> Option<Uncertain> {
+        let atomic_number = self.atomic_number().checked_sub(protons)?;
+        let neutron_number = self.neutron_number().checked_sub(neutrons)?;
+        let residual = match atomic_number {
+            // Free neutrons are not bound
+            0 => Uncertain::new(
+                neutron_number as f64 * NEUTRON_MASS.value,
+                neutron_number as f64 * NEUTRON_MASS.uncertainty,
+            ),
+            _ => Nuclide::new(atomic_number, atomic_number + neutron_number)?
+                .relative_atomic_mass()?,
+        };
+        let hydrogen = Isotope::H(H::One).relative_atomic_mass();
+        let mass = self.relative_atomic_mass();
+        let (protons, neutrons) = (protons as f64, neutrons as f64);
+        Some(Uncertain::new(
+            (residual.value + protons * hydrogen.value + neutrons * NEUTRON_MASS.value
+                - mass.value)
+                * ATOMIC_MASS_ENERGY,
+            (residual.uncertainty
+                + protons * hydrogen.uncertainty
+                + neutrons * NEUTRON_MASS.uncertainty
+                + mass.uncertainty)
+                * ATOMIC_MASS_ENERGY,
+        ))
+    }
+}
+
+fn binding_energy(atomic_number: usize, neutron_number: usize, mass: Uncertain) -> Uncertain {
+    let hydrogen = Isotope::H(H::One).relative_atomic_mass();
+    let (atomic_number, neutron_number) = (atomic_number as f64, neutron_number as f64);
+    Uncertain::new(
+        (atomic_number * hydrogen.value + neutron_number * NEUTRON_MASS.value - mass.value)
+            * ATOMIC_MASS_ENERGY,
+        (atomic_number * hydrogen.uncertainty
+            + neutron_number * NEUTRON_MASS.uncertainty
+            + mass.uncertainty)
+            * ATOMIC_MASS_ENERGY,
+    )
+}
@@ -0,0 +1,195 @@
+use self::Isotope::*;
+use crate::{Isotope, ELEMENTS, ISOTOPES};
+
+/// Index of the first isotope of each element in [`ISOTOPES`]
+const OFFSETS: [u16; ELEMENTS.len()] = {
+    let mut offsets = [0; ELEMENTS.len()];
+    // Backwards, so that the first isotope of each element is written last
+    let mut index = ISOTOPES.len();
+    while index > 0 {
+        index -= 1;
+        offsets[ISOTOPES[index].atomic_number() - 1] = index as u16;
+    }
+    offsets
+};
+
+/// Base of the PDG nuclear codes, 10LZZZAAAI with L = I = 0
+const PDG: u32 = 1_000_000_000;
+
+impl Isotope {
+    /// ZAID
+    ///
+    /// ZZZAAA code as used by ENDF and MCNP, Z · 1000 + A.
+    #[must_use]
+    pub const fn zaid(&self) -> u32 {
+        (self.element().atomic_number() * 1000 + self.mass_number()) as u32
+    }
+
+    /// Isotope of a ZAID
+    #[must_use]
+    pub fn from_zaid(zaid: u32) -> Option<Self> {
+        let atomic_number = (zaid / 1000) as usize;
+        let element = *ELEMENTS.get(atomic_number.checked_sub(1)?)?;
+        Self::new(element, (zaid % 1000) as usize)
+    }
+
+    /// Index
+    ///
+    /// Dense index of the isotope in [`ISOTOPES`]. It follows the data tables,
+    /// so it is only valid within one version of the crate; persist
+    /// [`zaid`](Self::zaid) or [`pdg`](Self::pdg) instead.
+    #[must_use]
+    pub const fn index(&self) -> u16 {
+        let offset = OFFSETS[self.element().atomic_number() - 1];
+        let variant = match self {
+            H(h) => *h as u16,
+            He(he) => *he as u16,
+            Li(li) => *li as u16,
+            Be(be) => *be as u16,
+            B(b) => *b as u16,
+            C(c) => *c as u16,
+            N(n) => *n as u16,
+            O(o) => *o as u16,
+            F(f) => *f as u16,
+            Ne(ne) => *ne as u16,
+            Na(na) => *na as u16,
+            Mg(mg) => *mg as u16,
+            Al(al) => *al as u16,
+            Si(si) => *si as u16,
+            P(p) => *p as u16,
+            S(s) => *s as u16,
+            Cl(cl) => *cl as u16,
+            Ar(ar) => *ar as u16,
+            K(k) => *k as u16,
+            Ca(ca) => *ca as u16,
+            Sc(sc) => *sc as u16,
+            Ti(ti) => *ti as u16,
+            V(v) => *v as u16,
+            Cr(cr) => *cr as u16,
+            Mn(mn) => *mn as u16,
+            Fe(fe) => *fe as u16,
+            Co(co) => *co as u16,
+            Ni(ni) => *ni as u16,
+            Cu(cu) => *cu as u16,
+            Zn(zn) => *zn as u16,
+            Ga(ga) => *ga as u16,
+            Ge(ge) => *ge as u16,
+            As(r#as) => *r#as as u16,
+            Se(se) => *se as u16,
+            Br(br) => *br as u16,
+            Kr(kr) => *kr as u16,
+            Rb(rb) => *rb as u16,
+            Sr(sr) => *sr as u16,
+            Y(y) => *y as u16,
+            Zr(zr) => *zr as u16,
+            Nb(nb) => *nb as u16,
+            Mo(mo) => *mo as u16,
+            Tc(tc) => *tc as u16,
+            Ru(ru) => *ru as u16,
+            Rh(rh) => *rh as u16,
+            Pd(pd) => *pd as u16,
+            Ag(ag) => *ag as u16,
+            Cd(cd) => *cd as u16,
+            In(r#in) => *r#in as u16,
+            Sn(sn) => *sn as u16,
+            Sb(sb) => *sb as u16,
+            Te(te) => *te as u16,
+            I(i) => *i as u16,
+            Xe(xe) => *xe as u16,
+            Cs(cs) => *cs as u16,
+            Ba(ba) => *ba as u16,
+            La(la) => *la as u16,
+            Ce(ce) => *ce as u16,
+            Pr(pr) => *pr as u16,
+            Nd(nd) => *nd as u16,
+            Pm(pm) => *pm as u16,
+            Sm(sm) => *sm as u16,
+            Eu(eu) => *eu as u16,
+            Gd(gd) => *gd as u16,
+            Tb(tb) => *tb as u16,
+            Dy(dy) => *dy as u16,
+            Ho(ho) => *ho as u16,
+            Er(er) => *er as u16,
+            Tm(tm) => *tm as u16,
+            Yb(yb) => *yb as u16,
+            Lu(lu) => *lu as u16,
+            Hf(hf) => *hf as u16,
+            Ta(ta) => *ta as u16,
+            W(w) => *w as u16,
+            Re(re) => *re as u16,
+            Os(os) => *os as u16,
+            Ir(ir) => *ir as u16,
+            Pt(pt) => *pt as u16,
+            Au(au) => *au as u16,
+            Hg(hg) => *hg as u16,
+            Tl(tl) => *tl as u16,
+            Pb(pb) => *pb as u16,
+            Bi(bi) => *bi as u16,
+            Po(po) => *po as u16,
+            At(at) => *at as u16,
+            Rn(rn) => *rn as u16,
+            Fr(fr) => *fr as u16,
+            Ra(ra) => *ra as u16,
+            Ac(ac) => *ac as u16,
+            Th(th) => *th as u16,
+            Pa(pa) => *pa as u16,
+            U(u) => *u as u16,
+            Np(np) => *np as u16,
+            Pu(pu) => *pu as u16,
+            Am(am) => *am as u16,
+            Cm(cm) => *cm as u16,
+            Bk(bk) => *bk as u16,
+            Cf(cf) => *cf as u16,
+            Es(es) => *es as u16,
+            Fm(fm) => *fm as u16,
+            Md(md) => *md as u16,
+            No(no) => *no as u16,
+            Lr(lr) => *lr as u16,
+            Rf(rf) => *rf as u16,
+            Db(db) => *db as u16,
+            Sg(sg) => *sg as u16,
+            Bh(bh) => *bh as u16,
+            Hs(hs) => *hs as u16,
+            Mt(mt) => *mt as u16,
+            Ds(ds) => *ds as u16,
+            Rg(rg) => *rg as u16,
+            Cn(cn) => *cn as u16,
+            Nh(nh) => *nh as u16,
+            Fl(fl) => *fl as u16,
+            Mc(mc) => *mc as u16,
+            Lv(lv) => *lv as u16,
+            Ts(ts) => *ts as u16,
+            Og(og) => *og as u16,
+        };
+        offset + variant
+    }
+
+    /// Isotope of an index
+    ///
+    /// Only for indices from the same version of the crate, see
+    /// [`index`](Self::index).
+    #[must_use]
+    pub fn from_index(index: u16) -> Option<Self> {
+        ISOTOPES.get(index as usize).copied()
+    }
+
+    /// PDG code
+    ///
+    /// Monte Carlo particle numbering code of the nucleus, 100ZZZAAA0.
+    #[must_use]
+    pub const fn pdg(&self) -> u32 {
+        PDG + self.element().atomic_number() as u32 * 10_000 + self.mass_number() as u32 * 10
+    }
+
+    /// Isotope of a PDG code
+    ///
+    /// Codes of isomers (I ≠ 0) or hypernuclei (L ≠ 0) have no isotope.
+    #[must_use]
+    pub fn from_pdg(pdg: u32) -> Option<Self> {
+        let code = pdg.checked_sub(PDG)?;
+        if code >= 10_000_000 || code % 10 != 0 {
+            return None;
+        }
+        Self::from_zaid(code / 10)
+    }
+}
@@ -0,0 +1,322 @@
+use crate::{Element, Error, Isotope, IsotopicComposition, Result, Uncertain};
+
+/// Tolerance of the non-negative least squares solver
+const TOLERANCE: f64 = 1e-12;
+
+/// Correction
+///
+/// Natural isotope abundance correction of mass isotopomer distributions
+/// (MIDs) in tracer experiments.
+///
+/// Column `j` of the correction matrix is the MID expected for a molecule
+/// with `j` labeled positions: the labeled positions carry the tracer, all
+/// other atoms (including those of a derivatization agent, if they are part
+/// of the formula) have their natural isotopic composition.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Correction {
+    tracer: Isotope,
+    matrix: Vec<Vec<f64>>,
+}
+
+impl Correction {
+    /// Correction matrix with the natural isotopic compositions
+    ///
+    /// - `formula` - elements of the molecule and their counts
+    /// - `tracer` - isotope used for labeling
+    /// - `labeled` - number of positions that can carry the tracer
+    /// - `length` - number of measured mass isotopomers (M+0, M+1, ...)
+    pub fn new(
+        formula: &[(Element, usize)],
+        tracer: Isotope,
+        labeled: usize,
+        length: usize,
+    ) -> Result<Self> {
+        Self::with_compositions(formula, tracer, labeled, length, &[])
+    }
+
+    /// Correction matrix with the given isotopic compositions
+    ///
+    /// Elements without a composition in `compositions` have their natural
+    /// one.
+    pub fn with_compositions(
+        formula: &[(Element, usize)],
+        tracer: Isotope,
+        labeled: usize,
+        length: usize,
+        compositions: &[IsotopicComposition],
+    ) -> Result<Self> {
+        let composition = |element: Element| {
+            let composition = compositions
+                .iter()
+                .find(|composition| composition.element() == element)
+                .cloned()
+                .unwrap_or_else(|| IsotopicComposition::natural(element));
+            composition.validate()?;
+            Ok::<_, Error>(composition)
+        };
+        let element = tracer.element();
+        let count = formula
+            .iter()
+            .filter(|(other, _)| *other == element)
+            .map(|(_, count)| count)
+            .sum();
+        if labeled > count {
+            return Err(Error::TooManyLabels {
+                element,
+                labeled,
+                count,
+            });
+        }
+        // Unlabeled atoms
+        let mut unlabeled = vec![0.0; length];
+        if let Some(first) = unlabeled.first_mut() {
+            *first = 1.0;
+        }
+        for &(other, count) in formula.iter().filter(|(other, _)| *other != element) {
+            let distribution = distribution(&composition(other)?, length);
+            for _ in 0..count {
+                unlabeled = convolve(&unlabeled, &distribution);
+            }
+        }
+        // Tracer element
+        let natural = composition(element)?;
+        let shift = tracer
+            .mass_number()
+            .checked_sub(lightest(&natural))
+            .ok_or(Error::InvalidTracer { tracer })?;
+        let distribution = distribution(&natural, length);
+        let mut columns = vec![Vec::new(); labeled + 1];
+        let mut column = unlabeled;
+        for _ in 0..count - labeled {
+            column = convolve(&column, &distribution);
+        }
+        for labels in (0..=labeled).rev() {
+            let mut shifted = vec![0.0; length];
+            for (index, value) in column.iter().enumerate() {
+                if let Some(target) = shifted.get_mut(index + labels * shift) {
+                    *target = *value;
+                }
+            }
+            columns[labels] = shifted;
+            column = convolve(&column, &distribution);
+        }
+        let matrix = (0..length)
+            .map(|row| columns.iter().map(|column| column[row]).collect())
+            .collect();
+        Ok(Self { tracer, matrix })
+    }
+
+    /// Tracer
+    #[must_use]
+    pub const fn tracer(&self) -> Isotope {
+        self.tracer
+    }
+
+    /// Correction matrix
+    ///
+    /// Rows are the measured mass isotopomers, columns the number of labeled
+    /// positions.
+    #[must_use]
+    pub fn matrix(&self) -> &[Vec<f64>] {
+        &self.matrix
+    }
+
+    /// Corrects a measured mass isotopomer distribution
+    ///
+    /// Solves `matrix · x = measured` for non-negative `x` in the least
+    /// squares sense and returns the labeling fractions (`x` normalized to
+    /// sum to one). The uncertainties of the measured intensities are
+    /// propagated linearly through the solution, neglecting the correlation
+    /// introduced by the normalization.
+    pub fn correct(&self, measured: &[Uncertain]) -> Result<Vec<Uncertain>> {
+        if measured.len() != self.matrix.len() {
+            return Err(Error::InvalidLength {
+                expected: self.matrix.len(),
+                found: measured.len(),
+            });
+        }
+        let values: Vec<_> = measured.iter().map(|intensity| intensity.value).collect();
+        let solution = nnls(&self.matrix, &values);
+        // Propagation through the pseudo-inverse of the passive columns
+        let passive: Vec<_> = (0..solution.len())
+            .filter(|&column| solution[column] > 0.0)
+            .collect();
+        let mut uncertainties = vec![0.0; solution.len()];
+        if let Some(pseudo_inverse) = pseudo_inverse(&self.matrix, &passive) {
+            for (row, &column) in pseudo_inverse.iter().zip(&passive) {
+                uncertainties[column] = row
+                    .iter()
+                    .zip(measured)
+                    .map(|(weight, intensity)| weight.abs() * intensity.uncertainty)
+                    .sum();
+            }
+        }
+        let sum: f64 = solution.iter().sum();
+        let sum = if sum > 0.0 { sum } else { 1.0 };
+        Ok(solution
+            .into_iter()
+            .zip(uncertainties)
+            .map(|(value, uncertainty)| Uncertain::new(value / sum, uncertainty / sum))
+            .collect())
+    }
+}
+
+/// Lightest mass number present in the composition
+fn lightest(composition: &IsotopicComposition) -> usize {
+    composition
+        .iter()
+        .filter(|(_, fraction)| fraction.value > 0.0)
+        .map(|(isotope, _)| isotope.mass_number())
+        .min()
+        .unwrap_or_default()
+}
+
+/// Nominal mass shift distribution of one atom
+fn distribution(composition: &IsotopicComposition, length: usize) -> Vec<f64> {
+    let lightest = lightest(composition);
+    let mut distribution = vec![0.0; length];
+    for (isotope, fraction) in composition
+        .iter()
+        .filter(|(_, fraction)| fraction.value > 0.0)
+    {
+        if let Some(target) = distribution.get_mut(isotope.mass_number() - lightest) {
+            *target += fraction.value;
+        }
+    }
+    distribution
+}
+
+/// Convolution truncated to the length of `left`
+fn convolve(left: &[f64], right: &[f64]) -> Vec<f64> {
+    let mut output = vec![0.0; left.len()];
+    for (i, left) in left.iter().enumerate() {
+        for (j, right) in right.iter().enumerate() {
+            if let Some(target) = output.get_mut(i + j) {
+                *target += left * right;
+            }
+        }
+    }
+    output
+}
+
+/// Non-negative least squares (Lawson–Hanson)
+fn nnls(matrix: &[Vec<f64>], target: &[f64]) -> Vec<f64> {
+    let columns = matrix.first().map_or(0, Vec::len);
+    let mut solution = vec![0.0; columns];
+    let mut passive = vec![false; columns];
+    for _ in 0..3 * columns + 1 {
+        let gradient = gradient(matrix, target, &solution);
+        let Some(next) = (0..columns)
+            .filter(|&column| !passive[column] && gradient[column] > TOLERANCE)
+            .max_by(|&left, &right| gradient[left].total_cmp(&gradient[right]))
+        else {
+            break;
+        };
+        passive[next] = true;
+        loop {
+            let indices: Vec<_> = (0..columns).filter(|&column| passive[column]).collect();
+            let Some(candidate) = least_squares(matrix, target, &indices) else {
+                passive[next] = false;
+                break;
+            };
+            if candidate.iter().all(|&value| value > TOLERANCE) {
+                solution = vec![0.0; columns];
+                for (&column, value) in indices.iter().zip(candidate) {
+                    solution[column] = value;
+                }
+                break;
+            }
+            let mut step: f64 = 1.0;
+            for (&column, &value) in indices.iter().zip(&candidate) {
+                if value <= TOLERANCE {
+                    step = step.min(solution[column] / (solution[column] - value));
+                }
+            }
+            for (&column, &value) in indices.iter().zip(&candidate) {
+                solution[column] += step * (value - solution[column]);
+                if solution[column] <= TOLERANCE {
+                    solution[column] = 0.0;
+                    passive[column] = false;
+                }
+            }
+        }
+    }
+    solution
+}
+
+/// Gradient of the squared residual, `Aᵀ(b - Ax)`
+fn gradient(matrix: &[Vec<f64>], target: &[f64], solution: &[f64]) -> Vec<f64> {
+    let residual: Vec<f64> = matrix
+        .iter()
+        .zip(target)
+        .map(|(row, target)| target - row.iter().zip(solution).map(|(a, x)| a * x).sum::<f64>())
+        .collect();
+    (0..solution.len())
+        .map(|column| {
+            matrix
+                .iter()
+                .zip(&residual)
+                .map(|(row, r)| row[column] * r)
+                .sum()
+        })
+        .collect()
+}
+
+/// Unconstrained least squares on a subset of columns
+fn least_squares(matrix: &[Vec<f64>], target: &[f64], columns: &[usize]) -> Option<Vec<f64>> {
+    let pseudo_inverse = pseudo_inverse(matrix, columns)?;
+    Some(
+        pseudo_inverse
+            .iter()
+            .map(|row| row.iter().zip(target).map(|(a, b)| a * b).sum())
+            .collect(),
+    )
+}
+
+/// Pseudo-inverse `(AₚᵀAₚ)⁻¹Aₚᵀ` of a subset of columns
+fn pseudo_inverse(matrix: &[Vec<f64>], columns: &[usize]) -> Option<Vec<Vec<f64>>> {
+    let size = columns.len();
+    // Augmented normal matrix [AₚᵀAₚ | Aₚᵀ]
+    let mut augmented: Vec<Vec<f64>> = columns
+        .iter()
+        .map(|&i| {
+            let normal = columns
+                .iter()
+                .map(|&j| matrix.iter().map(|row| row[i] * row[j]).sum());
+            let transposed = matrix.iter().map(|row| row[i]);
+            normal.chain(transposed).collect()
+        })
+        .collect();
+    // Gauss–Jordan elimination with partial pivoting
+    for pivot in 0..size {
+        let best = (pivot..size).max_by(|&left, &right| {
+            augmented[left][pivot]
+                .abs()
+                .total_cmp(&augmented[right][pivot].abs())
+        })?;
+        if augmented[best][pivot].abs() < TOLERANCE {
+            return None;
+        }
+        augmented.swap(pivot, best);
+        let divisor = augmented[pivot][pivot];
+        for value in &mut augmented[pivot] {
+            *value /= divisor;
+        }
+        for row in 0..size {
+            if row != pivot {
+                let factor = augmented[row][pivot];
+                if factor != 0.0 {
+                    for column in 0..augmented[row].len() {
+                        augmented[row][column] -= factor * augmented[pivot][column];
+                    }
+                }
+            }
+        }
+    }
+    Some(
+        augmented
+            .into_iter()
+            .map(|row| row[size..].to_vec())
+            .collect(),
+    )
+}
@@ -0,0 +1,69 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+use strum::EnumIter;
+
+/// Decay mode
+#[derive(Clone, Copy, Debug, EnumIter, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DecayMode {
+    /// α decay
+    Alpha,
+    /// β⁻ decay
+    BetaMinus,
+    /// Electron capture, including β⁺ decay (ε)
+    ElectronCapture,
+    /// Double β⁻ decay
+    DoubleBetaMinus,
+    /// Double electron capture
+    DoubleElectronCapture,
+    /// Spontaneous fission
+    SpontaneousFission,
+}
+
+impl DecayMode {
+    /// Symbol
+    #[must_use]
+    pub const fn symbol(&self) -> &'static str {
+        match self {
+            Self::Alpha => "α",
+            Self::BetaMinus => "β-",
+            Self::ElectronCapture => "ε",
+            Self::DoubleBetaMinus => "2β-",
+            Self::DoubleElectronCapture => "2ε",
+            Self::SpontaneousFission => "SF",
+        }
+    }
+
+    /// Change of the atomic number
+    #[must_use]
+    pub const fn atomic_number_change(&self) -> Option<isize> {
+        match self {
+            Self::Alpha => Some(-2),
+            Self::BetaMinus => Some(1),
+            Self::ElectronCapture => Some(-1),
+            Self::DoubleBetaMinus => Some(2),
+            Self::DoubleElectronCapture => Some(-2),
+            Self::SpontaneousFission => None,
+        }
+    }
+
+    /// Change of the mass number
+    #[must_use]
+    pub const fn mass_number_change(&self) -> Option<isize> {
+        match self {
+            Self::Alpha => Some(-4),
+            Self::BetaMinus
+            | Self::ElectronCapture
+            | Self::DoubleBetaMinus
+            | Self::DoubleElectronCapture => Some(0),
+            Self::SpontaneousFission => None,
+        }
+    }
+}
+
+impl Display for DecayMode {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
@@ -6,8 +6,6 @@ use self::Element::{
     Ti, Tl, Tm, Ts, Xe, Yb, Zn, Zr, B, C, F, H, I, K, N, O, P, S, U, V, W, Y,
 };
 use crate::{Error, Result};
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
 use std::{
     fmt::{self, Display, Formatter},
     str::FromStr,
@@ -25,7 +23,6 @@ pub const COUNT: usize = 118;
 
 /// Element
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Element {
     H = 1,
     He,
@@ -275,7 +272,7 @@ impl Element {
             Mc => "Moscovium",
             Lv => "Livermorium",
             Ts => "Tennessine",
-            Og => "Oganesson ",
+            Og => "Oganesson",
         }
     }
 
@@ -406,6 +403,15 @@ impl Element {
 }
 
 impl Element {
+    /// Element a misspelled symbol or name most likely refers to
+    pub(crate) fn suggest(value: &str) -> Option<Self> {
+        let value = value.trim();
+        ELEMENTS.into_iter().find(|element| {
+            element.symbol().eq_ignore_ascii_case(value)
+                || element.name().eq_ignore_ascii_case(value)
+        })
+    }
+
     #[must_use]
     pub fn split(&self) -> (&[Self], &[Self]) {
         let split = ELEMENTS.split_at(*self as _);
@@ -546,7 +552,7 @@ impl FromStr for Element {
             "Lv" => Ok(Lv),
             "Ts" => Ok(Ts),
             "Og" => Ok(Og),
-            _ => Err(Error::Parse),
+            _ => Err(Error::unknown_symbol(value, 0, Self::suggest(value))),
         }
     }
 }
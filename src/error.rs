@@ -1,11 +1,183 @@
+use crate::{Element, Isotope, ReferenceMaterial, Uncertain};
+use std::fmt::{self, Display, Formatter};
 use thiserror::Error;
 
 /// Result
 pub type Result<T, E = Error> = core::result::Result<T, E>;
 
 /// Error
-#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+///
+/// Parse errors carry the offending text, its byte offset in the parsed
+/// input and, where one can be guessed, a suggested correction. The other
+/// variants report invalid or missing data, such as an unknown isotope or an
+/// amount fraction outside [0, 1].
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
 pub enum Error {
-    #[error("failed to parse atom")]
-    Parse,
+    #[error("unknown symbol `{text}` at byte {offset}{}", Hint(suggestion))]
+    UnknownSymbol {
+        text: String,
+        offset: usize,
+        suggestion: Option<String>,
+    },
+    #[error("invalid mass number `{text}` at byte {offset}{}", Hint(suggestion))]
+    InvalidMassNumber {
+        text: String,
+        offset: usize,
+        suggestion: Option<String>,
+    },
+    #[error("unknown isomer `{text}` at byte {offset}")]
+    UnknownIsomer { text: String, offset: usize },
+    #[error("invalid count `{text}` at byte {offset}")]
+    InvalidCount { text: String, offset: usize },
+    #[error("unbalanced bracket `{text}` at byte {offset}")]
+    UnbalancedBracket { text: String, offset: usize },
+    #[error("{element} has no isotope with mass number {mass_number}")]
+    UnknownIsotope {
+        element: Element,
+        mass_number: usize,
+    },
+    #[error("{isotope} is not an isotope of {element}")]
+    ForeignIsotope { element: Element, isotope: Isotope },
+    #[error("amount fraction {fraction} of {isotope} is outside [0, 1]")]
+    InvalidFraction {
+        isotope: Isotope,
+        fraction: Uncertain,
+    },
+    #[error("composition of {element} has no {isotope}")]
+    MissingIsotope { element: Element, isotope: Isotope },
+    #[error("amount fractions of {element} sum to {sum} instead of 1")]
+    NotNormalized { element: Element, sum: Uncertain },
+    #[error("{labeled} labeled positions exceed the {count} atoms of {element}")]
+    TooManyLabels {
+        element: Element,
+        labeled: usize,
+        count: usize,
+    },
+    #[error("tracer {tracer} is lighter than the isotopes of its element present")]
+    InvalidTracer { tracer: Isotope },
+    #[error("expected {expected} intensities, found {found}")]
+    InvalidLength { expected: usize, found: usize },
+    #[error("remaining fraction {remainder} is outside (0, 1]")]
+    InvalidRemainder { remainder: Uncertain },
+    #[error("invalid number of batches {batches}")]
+    InvalidBatches { batches: usize },
+    #[error("invalid record `{text}` on line {line}")]
+    InvalidRecord { text: String, line: usize },
+    #[error("{numerator}/{denominator} ratio does not match {expected_numerator}/{expected_denominator}")]
+    RatioMismatch {
+        expected_numerator: Isotope,
+        expected_denominator: Isotope,
+        numerator: Isotope,
+        denominator: Isotope,
+    },
+    #[error("missing {numerator}/{denominator} ratio")]
+    MissingRatio {
+        numerator: Isotope,
+        denominator: Isotope,
+    },
+    #[error("{} has no {numerator}/{denominator} ratio", reference.name())]
+    UnknownRatio {
+        reference: ReferenceMaterial,
+        numerator: Isotope,
+        denominator: Isotope,
+    },
+}
+
+impl Error {
+    pub(crate) fn unknown_symbol(
+        text: &str,
+        offset: usize,
+        suggestion: Option<impl ToString>,
+    ) -> Self {
+        Self::UnknownSymbol {
+            text: text.to_owned(),
+            offset,
+            suggestion: suggestion.map(|suggestion| suggestion.to_string()),
+        }
+    }
+
+    pub(crate) fn invalid_mass_number(
+        text: &str,
+        offset: usize,
+        suggestion: Option<impl ToString>,
+    ) -> Self {
+        Self::InvalidMassNumber {
+            text: text.to_owned(),
+            offset,
+            suggestion: suggestion.map(|suggestion| suggestion.to_string()),
+        }
+    }
+
+    pub(crate) fn unbalanced_bracket(text: &str, offset: usize) -> Self {
+        Self::UnbalancedBracket {
+            text: text.to_owned(),
+            offset,
+        }
+    }
+
+    /// Offending text of a parse error
+    #[must_use]
+    pub fn text(&self) -> Option<&str> {
+        match self {
+            Self::UnknownSymbol { text, .. }
+            | Self::InvalidMassNumber { text, .. }
+            | Self::UnknownIsomer { text, .. }
+            | Self::InvalidCount { text, .. }
+            | Self::UnbalancedBracket { text, .. } => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Byte offset of the offending text of a parse error
+    #[must_use]
+    pub const fn offset(&self) -> Option<usize> {
+        match self {
+            Self::UnknownSymbol { offset, .. }
+            | Self::InvalidMassNumber { offset, .. }
+            | Self::UnknownIsomer { offset, .. }
+            | Self::InvalidCount { offset, .. }
+            | Self::UnbalancedBracket { offset, .. } => Some(*offset),
+            _ => None,
+        }
+    }
+
+    /// Suggested correction
+    #[must_use]
+    pub fn suggestion(&self) -> Option<&str> {
+        match self {
+            Self::UnknownSymbol { suggestion, .. } | Self::InvalidMassNumber { suggestion, .. } => {
+                suggestion.as_deref()
+            }
+            _ => None,
+        }
+    }
+
+    /// Shift the offset by `offset` bytes
+    ///
+    /// Use this to report errors relative to a larger input, when the parsed
+    /// text is a slice of it.
+    #[must_use]
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        match &mut self {
+            Self::UnknownSymbol { offset: inner, .. }
+            | Self::InvalidMassNumber { offset: inner, .. }
+            | Self::UnknownIsomer { offset: inner, .. }
+            | Self::InvalidCount { offset: inner, .. }
+            | Self::UnbalancedBracket { offset: inner, .. } => *inner += offset,
+            _ => {}
+        }
+        self
+    }
+}
+
+/// Hint
+struct Hint<'a>(&'a Option<String>);
+
+impl Display for Hint<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.0 {
+            Some(suggestion) => write!(f, ", did you mean `{suggestion}`?"),
+            None => Ok(()),
+        }
+    }
 }
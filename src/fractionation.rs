@@ -0,0 +1,389 @@
+use crate::{Element, Error, Isotope, IsotopeRatio, IsotopicComposition, Result, Uncertain};
+
+/// Per mil
+const PER_MIL: f64 = 1000.0;
+
+/// Relative step of the numerical derivatives
+const STEP: f64 = 1e-6;
+
+/// Fractionation factor
+///
+/// α = Rₚ / Rᵣ, the isotope ratio of a product over the isotope ratio of the
+/// reactant (or of one phase over another), together with its conversions to
+/// the ε and Δ notations and the Rayleigh, equilibrium and batch models.
+///
+/// The models treat the isotope ratio as proportional to the amount of the
+/// numerator isotope, which is accurate for rare isotopes (R ≪ 1).
+/// Uncertainties of α, of the initial ratio and of the remaining fraction are
+/// propagated to first order.
+///
+/// The `*_composition` models evolve a whole isotopic composition. α is the
+/// fractionation factor of one isotope pair, and the other isotopes follow the
+/// exponential mass-dependent law αᵢ = α^β, β = ln(mᵢ/m_d) / ln(mₙ/m_d).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Fractionation {
+    alpha: Uncertain,
+}
+
+impl Fractionation {
+    /// Fractionation factor α
+    #[must_use]
+    pub const fn new(alpha: Uncertain) -> Self {
+        Self { alpha }
+    }
+
+    /// Fractionation factor from the enrichment ε (‰)
+    ///
+    /// α = 1 + ε / 1000
+    #[must_use]
+    pub fn from_epsilon(epsilon: Uncertain) -> Self {
+        Self::new(Uncertain::new(
+            1.0 + epsilon.value / PER_MIL,
+            epsilon.uncertainty / PER_MIL,
+        ))
+    }
+
+    /// Fractionation factor from the difference Δ (‰)
+    ///
+    /// α = exp(Δ / 1000), where Δ = 1000 · ln α ≈ δₚ - δᵣ.
+    #[must_use]
+    pub fn from_difference(difference: Uncertain) -> Self {
+        let alpha = (difference.value / PER_MIL).exp();
+        Self::new(Uncertain::new(
+            alpha,
+            alpha * difference.uncertainty / PER_MIL,
+        ))
+    }
+
+    /// Fractionation factor from the delta values (‰) of product and reactant
+    ///
+    /// α = (1000 + δₚ) / (1000 + δᵣ)
+    #[must_use]
+    pub fn from_deltas(product: Uncertain, reactant: Uncertain) -> Self {
+        let denominator = PER_MIL + reactant.value;
+        let alpha = (PER_MIL + product.value) / denominator;
+        Self::new(Uncertain::new(
+            alpha,
+            product.uncertainty / denominator + alpha * reactant.uncertainty / denominator,
+        ))
+    }
+
+    /// Fractionation factor α
+    #[must_use]
+    pub const fn alpha(&self) -> Uncertain {
+        self.alpha
+    }
+
+    /// Enrichment ε (‰)
+    ///
+    /// ε = (α - 1) · 1000
+    #[must_use]
+    pub fn epsilon(&self) -> Uncertain {
+        Uncertain::new(
+            (self.alpha.value - 1.0) * PER_MIL,
+            self.alpha.uncertainty * PER_MIL,
+        )
+    }
+
+    /// Difference Δ (‰)
+    ///
+    /// Δ = 1000 · ln α
+    #[must_use]
+    pub fn difference(&self) -> Uncertain {
+        Uncertain::new(
+            self.alpha.value.ln() * PER_MIL,
+            self.alpha.uncertainty / self.alpha.value * PER_MIL,
+        )
+    }
+
+    /// Rayleigh model
+    ///
+    /// Open system, the product is removed as soon as it forms:
+    /// Rᵣ = R₀ · f^(α - 1).
+    pub fn rayleigh(&self, initial: &IsotopeRatio, remainder: Uncertain) -> Result<Evolution> {
+        self.evolve(initial, remainder, &rayleigh)
+    }
+
+    /// Equilibrium model
+    ///
+    /// Closed system, the product stays in equilibrium with the residue:
+    /// Rᵣ = R₀ / (f + α · (1 - f)).
+    pub fn equilibrium(&self, initial: &IsotopeRatio, remainder: Uncertain) -> Result<Evolution> {
+        self.evolve(initial, remainder, &equilibrium)
+    }
+
+    /// Batch model
+    ///
+    /// The product is removed in `batches` equal batches, each in equilibrium
+    /// with the residue when it is removed. One batch is the equilibrium
+    /// model, and the Rayleigh model is the limit of infinitely many.
+    pub fn batch(
+        &self,
+        initial: &IsotopeRatio,
+        remainder: Uncertain,
+        batches: usize,
+    ) -> Result<Evolution> {
+        if batches == 0 {
+            return Err(Error::InvalidBatches { batches });
+        }
+        self.evolve(initial, remainder, &|alpha, remainder| {
+            batch(alpha, remainder, batches)
+        })
+    }
+
+    /// Rayleigh model of an isotopic composition
+    ///
+    /// α is the fractionation factor of `numerator` over `denominator`.
+    pub fn rayleigh_composition(
+        &self,
+        initial: &IsotopicComposition,
+        numerator: Isotope,
+        denominator: Isotope,
+        remainder: Uncertain,
+    ) -> Result<Evolution<IsotopicComposition>> {
+        self.evolve_composition(initial, numerator, denominator, remainder, &rayleigh)
+    }
+
+    /// Equilibrium model of an isotopic composition
+    ///
+    /// α is the fractionation factor of `numerator` over `denominator`.
+    pub fn equilibrium_composition(
+        &self,
+        initial: &IsotopicComposition,
+        numerator: Isotope,
+        denominator: Isotope,
+        remainder: Uncertain,
+    ) -> Result<Evolution<IsotopicComposition>> {
+        self.evolve_composition(initial, numerator, denominator, remainder, &equilibrium)
+    }
+
+    /// Batch model of an isotopic composition
+    ///
+    /// α is the fractionation factor of `numerator` over `denominator`.
+    pub fn batch_composition(
+        &self,
+        initial: &IsotopicComposition,
+        numerator: Isotope,
+        denominator: Isotope,
+        remainder: Uncertain,
+        batches: usize,
+    ) -> Result<Evolution<IsotopicComposition>> {
+        if batches == 0 {
+            return Err(Error::InvalidBatches { batches });
+        }
+        self.evolve_composition(
+            initial,
+            numerator,
+            denominator,
+            remainder,
+            &|alpha, remainder| batch(alpha, remainder, batches),
+        )
+    }
+
+    /// Evolves the initial ratio by `factor(α, f)` = Rᵣ / R₀
+    fn evolve(
+        &self,
+        initial: &IsotopeRatio,
+        remainder: Uncertain,
+        factor: &dyn Fn(f64, f64) -> f64,
+    ) -> Result<Evolution> {
+        if remainder.value <= 0.0 || remainder.value > 1.0 {
+            return Err(Error::InvalidRemainder { remainder });
+        }
+        let residual = |ratio: f64, alpha: f64, remainder: f64| ratio * factor(alpha, remainder);
+        let instantaneous =
+            |ratio: f64, alpha: f64, remainder: f64| alpha * residual(ratio, alpha, remainder);
+        // Mass balance R₀ = f · Rᵣ + (1 - f) · Rₐ
+        let accumulated = |ratio: f64, alpha: f64, remainder: f64| {
+            if (1.0 - remainder).abs() > f64::EPSILON {
+                (ratio - remainder * residual(ratio, alpha, remainder)) / (1.0 - remainder)
+            } else {
+                instantaneous(ratio, alpha, remainder)
+            }
+        };
+        let ratio = |function: &dyn Fn(f64, f64, f64) -> f64| {
+            IsotopeRatio::new(
+                initial.numerator(),
+                initial.denominator(),
+                propagate(function, initial.value(), self.alpha, remainder),
+            )
+        };
+        Ok(Evolution {
+            residual: ratio(&residual)?,
+            instantaneous: ratio(&instantaneous)?,
+            accumulated: ratio(&accumulated)?,
+        })
+    }
+
+    /// Evolves each isotope ratio of the composition to `denominator` with its
+    /// mass-dependent fractionation factor
+    fn evolve_composition(
+        &self,
+        initial: &IsotopicComposition,
+        numerator: Isotope,
+        denominator: Isotope,
+        remainder: Uncertain,
+        factor: &dyn Fn(f64, f64) -> f64,
+    ) -> Result<Evolution<IsotopicComposition>> {
+        let element = initial.element();
+        for isotope in [numerator, denominator] {
+            if isotope.element() != element {
+                return Err(Error::ForeignIsotope { element, isotope });
+            }
+        }
+        let reference = initial.fraction(denominator).unwrap_or_default();
+        if reference.value <= 0.0 {
+            return Err(Error::MissingIsotope {
+                element,
+                isotope: denominator,
+            });
+        }
+        let mass = |isotope: Isotope| isotope.relative_atomic_mass().value;
+        let scale = (mass(numerator) / mass(denominator)).ln();
+        let mut evolutions = Vec::new();
+        for (isotope, fraction) in initial
+            .iter()
+            .filter(|&(isotope, _)| isotope != denominator)
+        {
+            let ratio = IsotopeRatio::new(
+                isotope,
+                denominator,
+                Uncertain::new(
+                    fraction.value / reference.value,
+                    fraction.uncertainty / reference.value
+                        + fraction.value * reference.uncertainty / reference.value.powi(2),
+                ),
+            )?;
+            let exponent = (mass(isotope) / mass(denominator)).ln() / scale;
+            let alpha = Uncertain::new(
+                self.alpha.value.powf(exponent),
+                (exponent * self.alpha.value.powf(exponent - 1.0)).abs() * self.alpha.uncertainty,
+            );
+            evolutions.push(Self::new(alpha).evolve(&ratio, remainder, factor)?);
+        }
+        let composition = |ratio: fn(&Evolution) -> IsotopeRatio| {
+            let ratios: Vec<_> = evolutions.iter().map(ratio).collect();
+            composition(element, denominator, &ratios)
+        };
+        Ok(Evolution {
+            residual: composition(|evolution| evolution.residual)?,
+            instantaneous: composition(|evolution| evolution.instantaneous)?,
+            accumulated: composition(|evolution| evolution.accumulated)?,
+        })
+    }
+}
+
+/// Evolution
+///
+/// Isotope ratios (or isotopic compositions) of a system of which the
+/// fraction f of the reactant remains.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Evolution<T = IsotopeRatio> {
+    /// Remaining reactant
+    pub residual: T,
+    /// Product forming from the remaining reactant
+    pub instantaneous: T,
+    /// All product formed so far
+    pub accumulated: T,
+}
+
+impl<T: Copy> Copy for Evolution<T> {}
+
+/// Rayleigh factor Rᵣ / R₀
+fn rayleigh(alpha: f64, remainder: f64) -> f64 {
+    remainder.powf(alpha - 1.0)
+}
+
+/// Equilibrium factor Rᵣ / R₀
+fn equilibrium(alpha: f64, remainder: f64) -> f64 {
+    (remainder + alpha * (1.0 - remainder)).recip()
+}
+
+/// Batch factor Rᵣ / R₀
+fn batch(alpha: f64, remainder: f64, batches: usize) -> f64 {
+    let step = (1.0 - remainder) / batches as f64;
+    let mut factor = 1.0;
+    let mut amount = 1.0;
+    for _ in 0..batches {
+        let retained = (amount - step) / amount;
+        factor /= retained + alpha * (1.0 - retained);
+        amount -= step;
+    }
+    factor
+}
+
+/// Isotopic composition from the ratios of all other isotopes to `denominator`
+///
+/// Fᵢ = Rᵢ / (1 + ΣR), F_d = 1 / (1 + ΣR)
+fn composition(
+    element: Element,
+    denominator: Isotope,
+    ratios: &[IsotopeRatio],
+) -> Result<IsotopicComposition> {
+    let sum = 1.0 + ratios.iter().map(|ratio| ratio.value().value).sum::<f64>();
+    let uncertainty: f64 = ratios.iter().map(|ratio| ratio.value().uncertainty).sum();
+    let mut composition = IsotopicComposition::new(element);
+    composition.insert(
+        denominator,
+        Uncertain::new(sum.recip(), uncertainty / (sum * sum)),
+    )?;
+    for ratio in ratios {
+        let Uncertain {
+            value,
+            uncertainty: own,
+        } = ratio.value();
+        composition.insert(
+            ratio.numerator(),
+            Uncertain::new(
+                value / sum,
+                ((sum - value) * own + value * (uncertainty - own)) / (sum * sum),
+            ),
+        )?;
+    }
+    Ok(composition)
+}
+
+/// Propagates the uncertainties of the arguments through `function` with
+/// finite differences
+///
+/// Central differences, or one-sided ones where a step would leave the domain
+/// of an argument (ratio ≥ 0, α > 0, 0 < f ≤ 1). Arguments without
+/// uncertainty are skipped.
+fn propagate(
+    function: &dyn Fn(f64, f64, f64) -> f64,
+    ratio: Uncertain,
+    alpha: Uncertain,
+    remainder: Uncertain,
+) -> Uncertain {
+    const DOMAINS: [(f64, f64); 3] = [(0.0, f64::INFINITY), (0.0, f64::INFINITY), (0.0, 1.0)];
+    let arguments = [ratio, alpha, remainder];
+    let values = arguments.map(|argument| argument.value);
+    let evaluate = |values: [f64; 3]| function(values[0], values[1], values[2]);
+    let value = evaluate(values);
+    let uncertainty = (0..arguments.len())
+        .filter(|&index| arguments[index].uncertainty != 0.0)
+        .map(|index| {
+            let (lower, upper) = DOMAINS[index];
+            let step = if values[index] == 0.0 {
+                STEP
+            } else {
+                STEP * values[index].abs()
+            };
+            let mut forward = values;
+            let mut backward = values;
+            if values[index] + step <= upper {
+                forward[index] += step;
+            }
+            if values[index] - step > lower {
+                backward[index] -= step;
+            }
+            let width = forward[index] - backward[index];
+            if width == 0.0 {
+                return 0.0;
+            }
+            let derivative = (evaluate(forward) - evaluate(backward)) / width;
+            derivative.abs() * arguments[index].uncertainty
+        })
+        .sum();
+    Uncertain::new(value, uncertainty)
+}
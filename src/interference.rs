@@ -0,0 +1,52 @@
+use crate::{Isotope, Uncertain, ISOTOPES};
+
+/// Interferences
+///
+/// Related isotopes that can overlap in mass spectrometry, and the mass
+/// resolution needed to tell them apart.
+impl Isotope {
+    /// Isobars
+    ///
+    /// Other tabulated isotopes with the same mass number A.
+    pub fn isobars(&self) -> impl Iterator<Item = Isotope> {
+        let isotope = *self;
+        ISOTOPES
+            .into_iter()
+            .filter(move |other| *other != isotope && other.mass_number() == isotope.mass_number())
+    }
+
+    /// Isotones
+    ///
+    /// Other tabulated isotopes with the same neutron number N.
+    pub fn isotones(&self) -> impl Iterator<Item = Isotope> {
+        let isotope = *self;
+        ISOTOPES.into_iter().filter(move |other| {
+            *other != isotope && other.neutron_number() == isotope.neutron_number()
+        })
+    }
+
+    /// Sister isotopes
+    ///
+    /// Other tabulated isotopes of the same element.
+    pub fn sister_isotopes(&self) -> impl Iterator<Item = Isotope> {
+        let isotope = *self;
+        isotope
+            .element()
+            .isotopes()
+            .filter(move |other| *other != isotope)
+    }
+
+    /// Mass difference to `other` in u
+    #[must_use]
+    pub fn mass_difference(&self, other: &Isotope) -> Uncertain {
+        other.relative_atomic_mass() - self.relative_atomic_mass()
+    }
+
+    /// Resolving power m/Δm needed to separate `other`
+    ///
+    /// Infinite for the same isotope.
+    #[must_use]
+    pub fn resolving_power(&self, other: &Isotope) -> f64 {
+        self.relative_atomic_mass().value / self.mass_difference(other).value.abs()
+    }
+}
@@ -0,0 +1,646 @@
+use crate::{
+    isotopes::{days, hours, minutes, years, STABLE},
+    uncertain, Element, Error, Isotope, Nuclide, Parity, Result, SpinParity, Uncertain,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// Isomer data
+struct Data {
+    atomic_number: usize,
+    mass_number: usize,
+    state: u8,
+    excitation_energy: Uncertain,
+    half_life: Option<Uncertain>,
+    spin_parity: Option<SpinParity>,
+}
+
+// NUBASE2020 (Kondev et al., 2021)
+const DATA: [Data; 49] = [
+    // Al-26m
+    Data {
+        atomic_number: 13,
+        mass_number: 26,
+        state: 1,
+        excitation_energy: uncertain!(228.306, 0.013),
+        half_life: Some(uncertain!(6.346_0, 0.000_5)),
+        spin_parity: Some(SpinParity::new(0, Parity::Positive)),
+    },
+    // Sc-44m
+    Data {
+        atomic_number: 21,
+        mass_number: 44,
+        state: 1,
+        excitation_energy: uncertain!(270.95, 0.07),
+        half_life: Some(hours(uncertain!(58.61, 0.10))),
+        spin_parity: Some(SpinParity::new(12, Parity::Positive)),
+    },
+    // Mn-52m
+    Data {
+        atomic_number: 25,
+        mass_number: 52,
+        state: 1,
+        excitation_energy: uncertain!(377.749, 0.005),
+        half_life: Some(minutes(uncertain!(21.1, 0.2))),
+        spin_parity: Some(SpinParity::new(4, Parity::Positive)),
+    },
+    // Co-58m
+    Data {
+        atomic_number: 27,
+        mass_number: 58,
+        state: 1,
+        excitation_energy: uncertain!(24.95, 0.06),
+        half_life: Some(hours(uncertain!(9.10, 0.09))),
+        spin_parity: Some(SpinParity::new(10, Parity::Positive)),
+    },
+    // Co-60m
+    Data {
+        atomic_number: 27,
+        mass_number: 60,
+        state: 1,
+        excitation_energy: uncertain!(58.59, 0.01),
+        half_life: Some(minutes(uncertain!(10.467, 0.006))),
+        spin_parity: Some(SpinParity::new(4, Parity::Positive)),
+    },
+    // Kr-81m
+    Data {
+        atomic_number: 36,
+        mass_number: 81,
+        state: 1,
+        excitation_energy: uncertain!(190.64, 0.04),
+        half_life: Some(uncertain!(13.10, 0.03)),
+        spin_parity: Some(SpinParity::new(1, Parity::Negative)),
+    },
+    // Kr-83m
+    Data {
+        atomic_number: 36,
+        mass_number: 83,
+        state: 1,
+        excitation_energy: uncertain!(41.557_5, 0.000_7),
+        half_life: Some(hours(uncertain!(1.830, 0.013))),
+        spin_parity: Some(SpinParity::new(1, Parity::Negative)),
+    },
+    // Kr-85m
+    Data {
+        atomic_number: 36,
+        mass_number: 85,
+        state: 1,
+        excitation_energy: uncertain!(304.871, 0.020),
+        half_life: Some(hours(uncertain!(4.480, 0.008))),
+        spin_parity: Some(SpinParity::new(1, Parity::Negative)),
+    },
+    // Sr-87m
+    Data {
+        atomic_number: 38,
+        mass_number: 87,
+        state: 1,
+        excitation_energy: uncertain!(388.533, 0.003),
+        half_life: Some(hours(uncertain!(2.815, 0.012))),
+        spin_parity: Some(SpinParity::new(1, Parity::Negative)),
+    },
+    // Y-90m
+    Data {
+        atomic_number: 39,
+        mass_number: 90,
+        state: 1,
+        excitation_energy: uncertain!(682.04, 0.08),
+        half_life: Some(hours(uncertain!(3.19, 0.06))),
+        spin_parity: Some(SpinParity::new(14, Parity::Positive)),
+    },
+    // Nb-93m
+    Data {
+        atomic_number: 41,
+        mass_number: 93,
+        state: 1,
+        excitation_energy: uncertain!(30.77, 0.02),
+        half_life: Some(years(uncertain!(16.12, 0.12))),
+        spin_parity: Some(SpinParity::new(1, Parity::Negative)),
+    },
+    // Tc-95m
+    Data {
+        atomic_number: 43,
+        mass_number: 95,
+        state: 1,
+        excitation_energy: uncertain!(38.89, 0.05),
+        half_life: Some(days(uncertain!(61.96, 0.24))),
+        spin_parity: Some(SpinParity::new(1, Parity::Negative)),
+    },
+    // Tc-97m
+    Data {
+        atomic_number: 43,
+        mass_number: 97,
+        state: 1,
+        excitation_energy: uncertain!(96.57, 0.06),
+        half_life: Some(days(uncertain!(91.1, 0.6))),
+        spin_parity: Some(SpinParity::new(1, Parity::Negative)),
+    },
+    // Tc-99m
+    Data {
+        atomic_number: 43,
+        mass_number: 99,
+        state: 1,
+        excitation_energy: uncertain!(142.683_6, 0.001_1),
+        half_life: Some(hours(uncertain!(6.006_7, 0.001_0))),
+        spin_parity: Some(SpinParity::new(1, Parity::Negative)),
+    },
+    // Rh-103m
+    Data {
+        atomic_number: 45,
+        mass_number: 103,
+        state: 1,
+        excitation_energy: uncertain!(39.756, 0.006),
+        half_life: Some(minutes(uncertain!(56.114, 0.020))),
+        spin_parity: Some(SpinParity::new(7, Parity::Positive)),
+    },
+    // Ag-108m
+    Data {
+        atomic_number: 47,
+        mass_number: 108,
+        state: 1,
+        excitation_energy: uncertain!(109.466, 0.007),
+        half_life: Some(years(uncertain!(438.0, 9.0))),
+        spin_parity: Some(SpinParity::new(12, Parity::Positive)),
+    },
+    // Ag-109m
+    Data {
+        atomic_number: 47,
+        mass_number: 109,
+        state: 1,
+        excitation_energy: uncertain!(88.034_1, 0.001_1),
+        half_life: Some(uncertain!(39.6, 0.2)),
+        spin_parity: Some(SpinParity::new(7, Parity::Positive)),
+    },
+    // Ag-110m
+    Data {
+        atomic_number: 47,
+        mass_number: 110,
+        state: 1,
+        excitation_energy: uncertain!(117.59, 0.05),
+        half_life: Some(days(uncertain!(249.83, 0.04))),
+        spin_parity: Some(SpinParity::new(12, Parity::Positive)),
+    },
+    // Cd-111m
+    Data {
+        atomic_number: 48,
+        mass_number: 111,
+        state: 1,
+        excitation_energy: uncertain!(396.22, 0.03),
+        half_life: Some(minutes(uncertain!(48.50, 0.09))),
+        spin_parity: Some(SpinParity::new(11, Parity::Negative)),
+    },
+    // Cd-113m
+    Data {
+        atomic_number: 48,
+        mass_number: 113,
+        state: 1,
+        excitation_energy: uncertain!(263.54, 0.03),
+        half_life: Some(years(uncertain!(14.1, 0.5))),
+        spin_parity: Some(SpinParity::new(11, Parity::Negative)),
+    },
+    // In-113m
+    Data {
+        atomic_number: 49,
+        mass_number: 113,
+        state: 1,
+        excitation_energy: uncertain!(391.699, 0.003),
+        half_life: Some(minutes(uncertain!(99.476, 0.023))),
+        spin_parity: Some(SpinParity::new(1, Parity::Negative)),
+    },
+    // In-114m
+    Data {
+        atomic_number: 49,
+        mass_number: 114,
+        state: 1,
+        excitation_energy: uncertain!(190.268_2, 0.002_2),
+        half_life: Some(days(uncertain!(49.51, 0.01))),
+        spin_parity: Some(SpinParity::new(10, Parity::Positive)),
+    },
+    // In-115m
+    Data {
+        atomic_number: 49,
+        mass_number: 115,
+        state: 1,
+        excitation_energy: uncertain!(336.244, 0.017),
+        half_life: Some(hours(uncertain!(4.486, 0.004))),
+        spin_parity: Some(SpinParity::new(1, Parity::Negative)),
+    },
+    // Sn-117m
+    Data {
+        atomic_number: 50,
+        mass_number: 117,
+        state: 1,
+        excitation_energy: uncertain!(314.58, 0.04),
+        half_life: Some(days(uncertain!(14.00, 0.05))),
+        spin_parity: Some(SpinParity::new(11, Parity::Negative)),
+    },
+    // Sn-119m
+    Data {
+        atomic_number: 50,
+        mass_number: 119,
+        state: 1,
+        excitation_energy: uncertain!(89.531, 0.013),
+        half_life: Some(days(uncertain!(293.1, 0.7))),
+        spin_parity: Some(SpinParity::new(11, Parity::Negative)),
+    },
+    // Sn-121m
+    Data {
+        atomic_number: 50,
+        mass_number: 121,
+        state: 1,
+        excitation_energy: uncertain!(6.31, 0.06),
+        half_life: Some(years(uncertain!(43.9, 0.5))),
+        spin_parity: Some(SpinParity::new(11, Parity::Negative)),
+    },
+    // Te-123m
+    Data {
+        atomic_number: 52,
+        mass_number: 123,
+        state: 1,
+        excitation_energy: uncertain!(247.47, 0.04),
+        half_life: Some(days(uncertain!(119.2, 0.1))),
+        spin_parity: Some(SpinParity::new(11, Parity::Negative)),
+    },
+    // Te-125m
+    Data {
+        atomic_number: 52,
+        mass_number: 125,
+        state: 1,
+        excitation_energy: uncertain!(144.775, 0.008),
+        half_life: Some(days(uncertain!(57.40, 0.15))),
+        spin_parity: Some(SpinParity::new(11, Parity::Negative)),
+    },
+    // Te-127m
+    Data {
+        atomic_number: 52,
+        mass_number: 127,
+        state: 1,
+        excitation_energy: uncertain!(88.23, 0.07),
+        half_life: Some(days(uncertain!(106.1, 0.7))),
+        spin_parity: Some(SpinParity::new(11, Parity::Negative)),
+    },
+    // Te-129m
+    Data {
+        atomic_number: 52,
+        mass_number: 129,
+        state: 1,
+        excitation_energy: uncertain!(105.51, 0.03),
+        half_life: Some(days(uncertain!(33.6, 0.1))),
+        spin_parity: Some(SpinParity::new(11, Parity::Negative)),
+    },
+    // Xe-129m
+    Data {
+        atomic_number: 54,
+        mass_number: 129,
+        state: 1,
+        excitation_energy: uncertain!(236.14, 0.03),
+        half_life: Some(days(uncertain!(8.88, 0.02))),
+        spin_parity: Some(SpinParity::new(11, Parity::Negative)),
+    },
+    // Xe-131m
+    Data {
+        atomic_number: 54,
+        mass_number: 131,
+        state: 1,
+        excitation_energy: uncertain!(163.930, 0.008),
+        half_life: Some(days(uncertain!(11.84, 0.04))),
+        spin_parity: Some(SpinParity::new(11, Parity::Negative)),
+    },
+    // Xe-133m
+    Data {
+        atomic_number: 54,
+        mass_number: 133,
+        state: 1,
+        excitation_energy: uncertain!(233.221, 0.018),
+        half_life: Some(days(uncertain!(2.198, 0.013))),
+        spin_parity: Some(SpinParity::new(11, Parity::Negative)),
+    },
+    // Xe-135m
+    Data {
+        atomic_number: 54,
+        mass_number: 135,
+        state: 1,
+        excitation_energy: uncertain!(526.551, 0.013),
+        half_life: Some(minutes(uncertain!(15.29, 0.05))),
+        spin_parity: Some(SpinParity::new(11, Parity::Negative)),
+    },
+    // Ba-137m
+    Data {
+        atomic_number: 56,
+        mass_number: 137,
+        state: 1,
+        excitation_energy: uncertain!(661.659, 0.003),
+        half_life: Some(minutes(uncertain!(2.552, 0.001))),
+        spin_parity: Some(SpinParity::new(11, Parity::Negative)),
+    },
+    // Ho-166m
+    Data {
+        atomic_number: 67,
+        mass_number: 166,
+        state: 1,
+        excitation_energy: uncertain!(5.969, 0.012),
+        half_life: Some(years(uncertain!(1_200.0, 180.0))),
+        spin_parity: Some(SpinParity::new(14, Parity::Negative)),
+    },
+    // Lu-177m
+    Data {
+        atomic_number: 71,
+        mass_number: 177,
+        state: 1,
+        excitation_energy: uncertain!(970.175, 0.003),
+        half_life: Some(days(uncertain!(160.44, 0.06))),
+        spin_parity: Some(SpinParity::new(23, Parity::Negative)),
+    },
+    // Hf-178m1
+    Data {
+        atomic_number: 72,
+        mass_number: 178,
+        state: 1,
+        excitation_energy: uncertain!(1_147.416, 0.006),
+        half_life: Some(uncertain!(4.0, 0.2)),
+        spin_parity: Some(SpinParity::new(16, Parity::Negative)),
+    },
+    // Hf-178m2
+    Data {
+        atomic_number: 72,
+        mass_number: 178,
+        state: 2,
+        excitation_energy: uncertain!(2_446.09, 0.08),
+        half_life: Some(years(uncertain!(31.0, 1.0))),
+        spin_parity: Some(SpinParity::new(32, Parity::Positive)),
+    },
+    // Ta-180m
+    Data {
+        atomic_number: 73,
+        mass_number: 180,
+        state: 1,
+        excitation_energy: uncertain!(76.79, 0.55),
+        half_life: Some(STABLE),
+        spin_parity: Some(SpinParity::new(18, Parity::Negative)),
+    },
+    // Ir-192m1
+    Data {
+        atomic_number: 77,
+        mass_number: 192,
+        state: 1,
+        excitation_energy: uncertain!(56.720, 0.005),
+        half_life: Some(minutes(uncertain!(1.45, 0.05))),
+        spin_parity: Some(SpinParity::new(2, Parity::Negative)),
+    },
+    // Ir-192m2
+    Data {
+        atomic_number: 77,
+        mass_number: 192,
+        state: 2,
+        excitation_energy: uncertain!(168.14, 0.12),
+        half_life: Some(years(uncertain!(241.0, 9.0))),
+        spin_parity: Some(SpinParity::new(22, Parity::Negative)),
+    },
+    // Pt-195m
+    Data {
+        atomic_number: 78,
+        mass_number: 195,
+        state: 1,
+        excitation_energy: uncertain!(259.077, 0.023),
+        half_life: Some(days(uncertain!(4.010, 0.005))),
+        spin_parity: Some(SpinParity::new(13, Parity::Positive)),
+    },
+    // Hg-197m
+    Data {
+        atomic_number: 80,
+        mass_number: 197,
+        state: 1,
+        excitation_energy: uncertain!(298.93, 0.08),
+        half_life: Some(hours(uncertain!(23.8, 0.1))),
+        spin_parity: Some(SpinParity::new(13, Parity::Positive)),
+    },
+    // Hg-199m
+    Data {
+        atomic_number: 80,
+        mass_number: 199,
+        state: 1,
+        excitation_energy: uncertain!(532.48, 0.10),
+        half_life: Some(minutes(uncertain!(42.67, 0.09))),
+        spin_parity: Some(SpinParity::new(13, Parity::Positive)),
+    },
+    // Bi-210m
+    Data {
+        atomic_number: 83,
+        mass_number: 210,
+        state: 1,
+        excitation_energy: uncertain!(271.31, 0.11),
+        half_life: Some(years(uncertain!(3.04e6, 0.06e6))),
+        spin_parity: Some(SpinParity::new(18, Parity::Negative)),
+    },
+    // Pa-234m
+    Data {
+        atomic_number: 91,
+        mass_number: 234,
+        state: 1,
+        excitation_energy: uncertain!(73.92, 0.02),
+        half_life: Some(minutes(uncertain!(1.159, 0.011))),
+        spin_parity: Some(SpinParity::new(0, Parity::Negative)),
+    },
+    // U-235m
+    Data {
+        atomic_number: 92,
+        mass_number: 235,
+        state: 1,
+        excitation_energy: uncertain!(0.076_737, 0.000_018),
+        half_life: Some(minutes(uncertain!(25.7, 0.1))),
+        spin_parity: Some(SpinParity::new(1, Parity::Positive)),
+    },
+    // Am-242m
+    Data {
+        atomic_number: 95,
+        mass_number: 242,
+        state: 1,
+        excitation_energy: uncertain!(48.60, 0.05),
+        half_life: Some(years(uncertain!(141.0, 2.0))),
+        spin_parity: Some(SpinParity::new(10, Parity::Negative)),
+    },
+];
+
+/// Isomer
+///
+/// Long-lived excited (metastable) state of a nuclide, `99mTc` or `178m2Hf`.
+/// States are numbered by increasing excitation energy; a nuclide with a
+/// single isomer writes it as `m`, otherwise as `m1`, `m2`, ...
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Isomer {
+    nuclide: Nuclide,
+    state: u8,
+}
+
+impl Isomer {
+    /// Tabulated isomer of a nuclide
+    #[must_use]
+    pub fn new(nuclide: Nuclide, state: u8) -> Option<Self> {
+        let isomer = Self { nuclide, state };
+        isomer.data().map(|_| isomer)
+    }
+
+    /// Tabulated isomers
+    pub fn all() -> impl Iterator<Item = Self> {
+        DATA.iter().filter_map(|data| {
+            Some(Self {
+                nuclide: Nuclide::new(data.atomic_number, data.mass_number)?,
+                state: data.state,
+            })
+        })
+    }
+
+    /// Tabulated isomers of a nuclide
+    pub fn of(nuclide: Nuclide) -> impl Iterator<Item = Self> {
+        Self::all().filter(move |isomer| isomer.nuclide == nuclide)
+    }
+
+    /// Nuclide
+    #[must_use]
+    pub const fn nuclide(&self) -> Nuclide {
+        self.nuclide
+    }
+
+    /// Ground-state isotope, if it is tabulated
+    #[must_use]
+    pub fn isotope(&self) -> Option<Isotope> {
+        self.nuclide.isotope()
+    }
+
+    /// Isomeric state number (1 for `m1`, 2 for `m2`, ...)
+    #[must_use]
+    pub const fn state(&self) -> u8 {
+        self.state
+    }
+
+    /// Excitation energy above the ground state in keV
+    #[must_use]
+    pub fn excitation_energy(&self) -> Option<Uncertain> {
+        Some(self.data()?.excitation_energy)
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite if no decay has been observed, `None` if unknown.
+    #[must_use]
+    pub fn half_life(&self) -> Option<Uncertain> {
+        self.data()?.half_life
+    }
+
+    /// Spin and parity
+    #[must_use]
+    pub fn spin_parity(&self) -> Option<SpinParity> {
+        self.data()?.spin_parity
+    }
+
+    fn data(&self) -> Option<&'static Data> {
+        DATA.iter().find(|data| {
+            (data.atomic_number, data.mass_number, data.state)
+                == (
+                    self.nuclide.atomic_number(),
+                    self.nuclide.mass_number(),
+                    self.state,
+                )
+        })
+    }
+
+    /// Whether the isomer is written without its state number
+    fn is_single(&self) -> bool {
+        self.state == 1 && Self::of(self.nuclide).count() == 1
+    }
+}
+
+impl Display for Isomer {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let symbol = self
+            .nuclide
+            .element()
+            .map_or("", |element| element.symbol());
+        write!(f, "{}m", self.nuclide.mass_number())?;
+        if !self.is_single() {
+            write!(f, "{}", self.state)?;
+        }
+        f.write_str(symbol)
+    }
+}
+
+impl FromStr for Isomer {
+    type Err = Error;
+
+    /// Parses `99mTc`, `178m2Hf`, `Tc-99m` or `Hf178m2`
+    fn from_str(value: &str) -> Result<Self> {
+        let digits = |offset: usize| {
+            let length = value[offset..]
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(value.len() - offset);
+            (&value[offset..offset + length], offset + length)
+        };
+        let letters = |offset: usize| {
+            let length = value[offset..]
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(value.len() - offset);
+            (&value[offset..offset + length], offset + length)
+        };
+        let metastable = |offset: usize| value[offset..].starts_with('m');
+        let (symbol, mass_number, state, end) = if value.starts_with(|c: char| c.is_ascii_digit()) {
+            // Prefix: 178m2Hf
+            let mass_number = digits(0);
+            if !metastable(mass_number.1) {
+                return Err(unknown_isomer(value));
+            }
+            let state = digits(mass_number.1 + 1);
+            let symbol = letters(state.1);
+            (symbol, mass_number, state, symbol.1)
+        } else {
+            // Suffix: Hf-178m2, Hf178m2
+            let symbol = letters(0);
+            let offset = symbol.1 + usize::from(value[symbol.1..].starts_with('-'));
+            let mass_number = digits(offset);
+            if !metastable(mass_number.1) {
+                return Err(unknown_isomer(value));
+            }
+            let state = digits(mass_number.1 + 1);
+            (symbol, mass_number, state, state.1)
+        };
+        if end != value.len() {
+            return Err(unknown_isomer(value));
+        }
+        let element = symbol
+            .0
+            .parse::<Element>()
+            .map_err(|error| error.with_offset(symbol.1 - symbol.0.len()))?;
+        let invalid_mass_number = || {
+            let offset = mass_number.1 - mass_number.0.len();
+            Error::invalid_mass_number(mass_number.0, offset, None::<usize>)
+        };
+        let nuclide = mass_number
+            .0
+            .parse()
+            .ok()
+            .and_then(|mass_number| Nuclide::new(element.atomic_number(), mass_number))
+            .ok_or_else(invalid_mass_number)?;
+        let state = match state.0 {
+            "" => 1,
+            text => text
+                .parse()
+                .ok()
+                .filter(|&state| state > 0)
+                .ok_or_else(|| Error::InvalidCount {
+                    text: text.to_owned(),
+                    offset: state.1 - text.len(),
+                })?,
+        };
+        Self::new(nuclide, state).ok_or_else(|| unknown_isomer(value))
+    }
+}
+
+fn unknown_isomer(value: &str) -> Error {
+    Error::UnknownIsomer {
+        text: value.to_owned(),
+        offset: 0,
+    }
+}
@@ -7,17 +7,374 @@ use crate::{
         Pd, Pm, Po, Pr, Pt, Pu, Ra, Rb, Re, Rf, Rg, Rh, Rn, Ru, Sb, Sc, Se, Sg, Si, Sm, Sn, Sr, Ta,
         Tb, Tc, Te, Th, Ti, Tl, Tm, Ts, Xe, Yb, Zn, Zr, B, C, F, H, I, K, N, O, P, S, U, V, W, Y,
     },
-    Element, Error, Result, Uncertain,
+    DecayMode, Element, Error, Notes, Result, SpinParity, Uncertain,
 };
 use derive_more::From;
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
-use std::str::FromStr;
-use strum::IntoEnumIterator;
+
+/// Energy equivalent of the atomic mass constant mᵤc² in keV (CODATA 2018)
+pub(crate) const ATOMIC_MASS_ENERGY: f64 = 931_494.102_42;
+
+pub const ISOTOPES: [Isotope; COUNT] = [
+    H(H::One),
+    H(H::Two),
+    H(H::Three),
+    He(He::Three),
+    He(He::Four),
+    Li(Li::Six),
+    Li(Li::Seven),
+    Be(Be::Nine),
+    B(B::Ten),
+    B(B::Eleven),
+    C(C::Twelve),
+    C(C::Thirteen),
+    C(C::Fourteen),
+    N(N::Fourteen),
+    N(N::Fifteen),
+    O(O::Sixteen),
+    O(O::Seventeen),
+    O(O::Eighteen),
+    F(F::Nineteen),
+    Ne(Ne::Twenty),
+    Ne(Ne::TwentyOne),
+    Ne(Ne::TwentyTwo),
+    Na(Na::TwentyThree),
+    Mg(Mg::TwentyFour),
+    Mg(Mg::TwentyFive),
+    Mg(Mg::TwentySix),
+    Al(Al::TwentySeven),
+    Si(Si::TwentyEight),
+    Si(Si::TwentyNine),
+    Si(Si::Thirty),
+    P(P::ThirtyOne),
+    S(S::ThirtyTwo),
+    S(S::ThirtyThree),
+    S(S::ThirtyFour),
+    S(S::ThirtySix),
+    Cl(Cl::ThirtyFive),
+    Cl(Cl::ThirtySeven),
+    Ar(Ar::ThirtySix),
+    Ar(Ar::ThirtyEight),
+    Ar(Ar::Forty),
+    K(K::ThirtyNine),
+    K(K::Forty),
+    K(K::FortyOne),
+    Ca(Ca::Forty),
+    Ca(Ca::FortyTwo),
+    Ca(Ca::FortyThree),
+    Ca(Ca::FortyFour),
+    Ca(Ca::FortySix),
+    Ca(Ca::FortyEight),
+    Sc(Sc::FortyFive),
+    Ti(Ti::FortySix),
+    Ti(Ti::FortySeven),
+    Ti(Ti::FortyEight),
+    Ti(Ti::FortyNine),
+    Ti(Ti::Fifty),
+    V(V::Fifty),
+    V(V::FiftyOne),
+    Cr(Cr::Fifty),
+    Cr(Cr::FiftyTwo),
+    Cr(Cr::FiftyThree),
+    Cr(Cr::FiftyFour),
+    Mn(Mn::FiftyFive),
+    Fe(Fe::FiftyFour),
+    Fe(Fe::FiftySix),
+    Fe(Fe::FiftySeven),
+    Fe(Fe::FiftyEight),
+    Co(Co::FiftyNine),
+    Ni(Ni::FiftyEight),
+    Ni(Ni::Sixty),
+    Ni(Ni::SixtyOne),
+    Ni(Ni::SixtyTwo),
+    Ni(Ni::SixtyFour),
+    Cu(Cu::SixtyThree),
+    Cu(Cu::SixtyFive),
+    Zn(Zn::SixtyFour),
+    Zn(Zn::SixtySix),
+    Zn(Zn::SixtySeven),
+    Zn(Zn::SixtyEight),
+    Zn(Zn::Seventy),
+    Ga(Ga::SixtyNine),
+    Ga(Ga::SeventyOne),
+    Ge(Ge::Seventy),
+    Ge(Ge::SeventyTwo),
+    Ge(Ge::SeventyThree),
+    Ge(Ge::SeventyFour),
+    Ge(Ge::SeventySix),
+    As(As::SeventyFive),
+    Se(Se::SeventyFour),
+    Se(Se::SeventySix),
+    Se(Se::SeventySeven),
+    Se(Se::SeventyEight),
+    Se(Se::Eighty),
+    Se(Se::EightyTwo),
+    Br(Br::SeventyNine),
+    Br(Br::EightyOne),
+    Kr(Kr::SeventyEight),
+    Kr(Kr::Eighty),
+    Kr(Kr::EightyTwo),
+    Kr(Kr::EightyThree),
+    Kr(Kr::EightyFour),
+    Kr(Kr::EightySix),
+    Rb(Rb::EightyFive),
+    Rb(Rb::EightySeven),
+    Sr(Sr::EightyFour),
+    Sr(Sr::EightySix),
+    Sr(Sr::EightySeven),
+    Sr(Sr::EightyEight),
+    Y(Y::EightyNine),
+    Zr(Zr::Ninety),
+    Zr(Zr::NinetyOne),
+    Zr(Zr::NinetyTwo),
+    Zr(Zr::NinetyFour),
+    Zr(Zr::NinetySix),
+    Nb(Nb::NinetyThree),
+    Mo(Mo::NinetyTwo),
+    Mo(Mo::NinetyFour),
+    Mo(Mo::NinetyFive),
+    Mo(Mo::NinetySix),
+    Mo(Mo::NinetySeven),
+    Mo(Mo::NinetyEight),
+    Mo(Mo::OneHundred),
+    Tc(Tc::NinetySeven),
+    Tc(Tc::NinetyEight),
+    Tc(Tc::NinetyNine),
+    Ru(Ru::NinetySix),
+    Ru(Ru::NinetyEight),
+    Ru(Ru::NinetyNine),
+    Ru(Ru::OneHundred),
+    Ru(Ru::OneHundredOne),
+    Ru(Ru::OneHundredTwo),
+    Ru(Ru::OneHundredFour),
+    Rh(Rh::OneHundredThree),
+    Pd(Pd::OneHundredTwo),
+    Pd(Pd::OneHundredFour),
+    Pd(Pd::OneHundredFive),
+    Pd(Pd::OneHundredSix),
+    Pd(Pd::OneHundredEight),
+    Pd(Pd::OneHundredTen),
+    Ag(Ag::OneHundredSeven),
+    Ag(Ag::OneHundredNine),
+    Cd(Cd::OneHundredSix),
+    Cd(Cd::OneHundredEight),
+    Cd(Cd::OneHundredTen),
+    Cd(Cd::OneHundredEleven),
+    Cd(Cd::OneHundredTwelve),
+    Cd(Cd::OneHundredThirteen),
+    Cd(Cd::OneHundredFourteen),
+    Cd(Cd::OneHundredSixteen),
+    In(In::OneHundredThirteen),
+    In(In::OneHundredFifteen),
+    Sn(Sn::OneHundredTwelve),
+    Sn(Sn::OneHundredFourteen),
+    Sn(Sn::OneHundredFifteen),
+    Sn(Sn::OneHundredSixteen),
+    Sn(Sn::OneHundredSeventeen),
+    Sn(Sn::OneHundredEighteen),
+    Sn(Sn::OneHundredNineteen),
+    Sn(Sn::OneHundredTwenty),
+    Sn(Sn::OneHundredTwentyTwo),
+    Sn(Sn::OneHundredTwentyFour),
+    Sb(Sb::OneHundredTwentyOne),
+    Sb(Sb::OneHundredTwentyThree),
+    Te(Te::OneHundredTwenty),
+    Te(Te::OneHundredTwentyTwo),
+    Te(Te::OneHundredTwentyThree),
+    Te(Te::OneHundredTwentyFour),
+    Te(Te::OneHundredTwentyFive),
+    Te(Te::OneHundredTwentySix),
+    Te(Te::OneHundredTwentyEight),
+    Te(Te::OneHundredThirty),
+    I(I::OneHundredTwentySeven),
+    Xe(Xe::OneHundredTwentyFour),
+    Xe(Xe::OneHundredTwentySix),
+    Xe(Xe::OneHundredTwentyEight),
+    Xe(Xe::OneHundredTwentyNine),
+    Xe(Xe::OneHundredThirty),
+    Xe(Xe::OneHundredThirtyOne),
+    Xe(Xe::OneHundredThirtyTwo),
+    Xe(Xe::OneHundredThirtyFour),
+    Xe(Xe::OneHundredThirtySix),
+    Cs(Cs::OneHundredThirtyThree),
+    Ba(Ba::OneHundredThirty),
+    Ba(Ba::OneHundredThirtyTwo),
+    Ba(Ba::OneHundredThirtyFour),
+    Ba(Ba::OneHundredThirtyFive),
+    Ba(Ba::OneHundredThirtySix),
+    Ba(Ba::OneHundredThirtySeven),
+    Ba(Ba::OneHundredThirtyEight),
+    La(La::OneHundredThirtyEight),
+    La(La::OneHundredThirtyNine),
+    Ce(Ce::OneHundredThirtySix),
+    Ce(Ce::OneHundredThirtyEight),
+    Ce(Ce::OneHundredForty),
+    Ce(Ce::OneHundredFortyTwo),
+    Pr(Pr::OneHundredFortyOne),
+    Nd(Nd::OneHundredFortyTwo),
+    Nd(Nd::OneHundredFortyThree),
+    Nd(Nd::OneHundredFortyFour),
+    Nd(Nd::OneHundredFortyFive),
+    Nd(Nd::OneHundredFortySix),
+    Nd(Nd::OneHundredFortyEight),
+    Nd(Nd::OneHundredFifty),
+    Pm(Pm::OneHundredFortyFive),
+    Pm(Pm::OneHundredFortySeven),
+    Sm(Sm::OneHundredFortyFour),
+    Sm(Sm::OneHundredFortySeven),
+    Sm(Sm::OneHundredFortyEight),
+    Sm(Sm::OneHundredFortyNine),
+    Sm(Sm::OneHundredFifty),
+    Sm(Sm::OneHundredFiftyTwo),
+    Sm(Sm::OneHundredFiftyFour),
+    Eu(Eu::OneHundredFiftyOne),
+    Eu(Eu::OneHundredFiftyThree),
+    Gd(Gd::OneHundredFiftyTwo),
+    Gd(Gd::OneHundredFiftyFour),
+    Gd(Gd::OneHundredFiftyFive),
+    Gd(Gd::OneHundredFiftySix),
+    Gd(Gd::OneHundredFiftySeven),
+    Gd(Gd::OneHundredFiftyEight),
+    Gd(Gd::OneHundredSixty),
+    Tb(Tb::OneHundredFiftyNine),
+    Dy(Dy::OneHundredFiftySix),
+    Dy(Dy::OneHundredFiftyEight),
+    Dy(Dy::OneHundredSixty),
+    Dy(Dy::OneHundredSixtyOne),
+    Dy(Dy::OneHundredSixtyTwo),
+    Dy(Dy::OneHundredSixtyThree),
+    Dy(Dy::OneHundredSixtyFour),
+    Ho(Ho::OneHundredSixtyFive),
+    Er(Er::OneHundredSixtyTwo),
+    Er(Er::OneHundredSixtyFour),
+    Er(Er::OneHundredSixtySix),
+    Er(Er::OneHundredSixtySeven),
+    Er(Er::OneHundredSixtyEight),
+    Er(Er::OneHundredSeventy),
+    Tm(Tm::OneHundredSixtyNine),
+    Yb(Yb::OneHundredSixtyEight),
+    Yb(Yb::OneHundredSeventy),
+    Yb(Yb::OneHundredSeventyOne),
+    Yb(Yb::OneHundredSeventyTwo),
+    Yb(Yb::OneHundredSeventyThree),
+    Yb(Yb::OneHundredSeventyFour),
+    Yb(Yb::OneHundredSeventySix),
+    Lu(Lu::OneHundredSeventyFive),
+    Lu(Lu::OneHundredSeventySix),
+    Hf(Hf::OneHundredSeventyFour),
+    Hf(Hf::OneHundredSeventySix),
+    Hf(Hf::OneHundredSeventySeven),
+    Hf(Hf::OneHundredSeventyEight),
+    Hf(Hf::OneHundredSeventyNine),
+    Hf(Hf::OneHundredEighty),
+    Ta(Ta::OneHundredEighty),
+    Ta(Ta::OneHundredEightyOne),
+    W(W::OneHundredEighty),
+    W(W::OneHundredEightyTwo),
+    W(W::OneHundredEightyThree),
+    W(W::OneHundredEightyFour),
+    W(W::OneHundredEightySix),
+    Re(Re::OneHundredEightyFive),
+    Re(Re::OneHundredEightySeven),
+    Os(Os::OneHundredEightyFour),
+    Os(Os::OneHundredEightySix),
+    Os(Os::OneHundredEightySeven),
+    Os(Os::OneHundredEightyEight),
+    Os(Os::OneHundredEightyNine),
+    Os(Os::OneHundredNinety),
+    Os(Os::OneHundredNinetyTwo),
+    Ir(Ir::OneHundredNinetyOne),
+    Ir(Ir::OneHundredNinetyThree),
+    Pt(Pt::OneHundredNinety),
+    Pt(Pt::OneHundredNinetyTwo),
+    Pt(Pt::OneHundredNinetyFour),
+    Pt(Pt::OneHundredNinetyFive),
+    Pt(Pt::OneHundredNinetySix),
+    Pt(Pt::OneHundredNinetyEight),
+    Au(Au::OneHundredNinetySeven),
+    Hg(Hg::OneHundredNinetySix),
+    Hg(Hg::OneHundredNinetyEight),
+    Hg(Hg::OneHundredNinetyNine),
+    Hg(Hg::TwoHundred),
+    Hg(Hg::TwoHundredOne),
+    Hg(Hg::TwoHundredTwo),
+    Hg(Hg::TwoHundredFour),
+    Tl(Tl::TwoHundredThree),
+    Tl(Tl::TwoHundredFive),
+    Pb(Pb::TwoHundredFour),
+    Pb(Pb::TwoHundredSix),
+    Pb(Pb::TwoHundredSeven),
+    Pb(Pb::TwoHundredEight),
+    Bi(Bi::TwoHundredNine),
+    Po(Po::TwoHundredNine),
+    Po(Po::TwoHundredTen),
+    At(At::TwoHundredTen),
+    At(At::TwoHundredEleven),
+    Rn(Rn::TwoHundredEleven),
+    Rn(Rn::TwoHundredTwenty),
+    Rn(Rn::TwoHundredTwentyTwo),
+    Fr(Fr::TwoHundredTwentyThree),
+    Ra(Ra::TwoHundredTwentyThree),
+    Ra(Ra::TwoHundredTwentyFour),
+    Ra(Ra::TwoHundredTwentySix),
+    Ra(Ra::TwoHundredTwentyEight),
+    Ac(Ac::TwoHundredTwentySeven),
+    Th(Th::TwoHundredThirty),
+    Th(Th::TwoHundredThirtyTwo),
+    Pa(Pa::TwoHundredThirtyOne),
+    U(U::TwoHundredThirtyThree),
+    U(U::TwoHundredThirtyFour),
+    U(U::TwoHundredThirtyFive),
+    U(U::TwoHundredThirtySix),
+    U(U::TwoHundredThirtyEight),
+    Np(Np::TwoHundredThirtySix),
+    Np(Np::TwoHundredThirtySeven),
+    Pu(Pu::TwoHundredThirtyEight),
+    Pu(Pu::TwoHundredThirtyNine),
+    Pu(Pu::TwoHundredForty),
+    Pu(Pu::TwoHundredFortyOne),
+    Pu(Pu::TwoHundredFortyTwo),
+    Pu(Pu::TwoHundredFortyFour),
+    Am(Am::TwoHundredFortyOne),
+    Am(Am::TwoHundredFortyThree),
+    Cm(Cm::TwoHundredFortyThree),
+    Cm(Cm::TwoHundredFortyFour),
+    Cm(Cm::TwoHundredFortyFive),
+    Cm(Cm::TwoHundredFortySix),
+    Cm(Cm::TwoHundredFortySeven),
+    Cm(Cm::TwoHundredFortyEight),
+    Bk(Bk::TwoHundredFortySeven),
+    Bk(Bk::TwoHundredFortyNine),
+    Cf(Cf::TwoHundredFortyNine),
+    Cf(Cf::TwoHundredFifty),
+    Cf(Cf::TwoHundredFiftyOne),
+    Cf(Cf::TwoHundredFiftyTwo),
+    Es(Es::TwoHundredFiftyTwo),
+    Fm(Fm::TwoHundredFiftySeven),
+    Md(Md::TwoHundredFiftyEight),
+    Md(Md::TwoHundredSixty),
+    No(No::TwoHundredFiftyNine),
+    Lr(Lr::TwoHundredSixtyTwo),
+    Rf(Rf::TwoHundredSixtySeven),
+    Db(Db::TwoHundredSixtyEight),
+    Sg(Sg::TwoHundredSeventyOne),
+    Bh(Bh::TwoHundredSeventyTwo),
+    Hs(Hs::TwoHundredSeventy),
+    Mt(Mt::TwoHundredSeventySix),
+    Ds(Ds::TwoHundredEightyOne),
+    Rg(Rg::TwoHundredEighty),
+    Cn(Cn::TwoHundredEightyFive),
+    Nh(Nh::TwoHundredEightyFour),
+    Fl(Fl::TwoHundredEightyNine),
+    Mc(Mc::TwoHundredEightyEight),
+    Lv(Lv::TwoHundredNinetyThree),
+    Ts(Ts::TwoHundredNinetyTwo),
+    Og(Og::TwoHundredNinetyFour),
+];
+
+pub const COUNT: usize = 354;
 
 /// Isotope
 #[derive(Clone, Copy, Debug, Eq, From, Hash, Ord, PartialEq, PartialOrd)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Isotope {
     H(H),
     He(He),
@@ -140,6 +497,131 @@ pub enum Isotope {
 }
 
 impl Isotope {
+    /// Isotope of the element with the mass number
+    #[must_use]
+    pub fn new(element: Element, mass_number: usize) -> Option<Self> {
+        match element {
+            Element::H => H::from_mass_number(mass_number).map(H),
+            Element::He => He::from_mass_number(mass_number).map(He),
+            Element::Li => Li::from_mass_number(mass_number).map(Li),
+            Element::Be => Be::from_mass_number(mass_number).map(Be),
+            Element::B => B::from_mass_number(mass_number).map(B),
+            Element::C => C::from_mass_number(mass_number).map(C),
+            Element::N => N::from_mass_number(mass_number).map(N),
+            Element::O => O::from_mass_number(mass_number).map(O),
+            Element::F => F::from_mass_number(mass_number).map(F),
+            Element::Ne => Ne::from_mass_number(mass_number).map(Ne),
+            Element::Na => Na::from_mass_number(mass_number).map(Na),
+            Element::Mg => Mg::from_mass_number(mass_number).map(Mg),
+            Element::Al => Al::from_mass_number(mass_number).map(Al),
+            Element::Si => Si::from_mass_number(mass_number).map(Si),
+            Element::P => P::from_mass_number(mass_number).map(P),
+            Element::S => S::from_mass_number(mass_number).map(S),
+            Element::Cl => Cl::from_mass_number(mass_number).map(Cl),
+            Element::Ar => Ar::from_mass_number(mass_number).map(Ar),
+            Element::K => K::from_mass_number(mass_number).map(K),
+            Element::Ca => Ca::from_mass_number(mass_number).map(Ca),
+            Element::Sc => Sc::from_mass_number(mass_number).map(Sc),
+            Element::Ti => Ti::from_mass_number(mass_number).map(Ti),
+            Element::V => V::from_mass_number(mass_number).map(V),
+            Element::Cr => Cr::from_mass_number(mass_number).map(Cr),
+            Element::Mn => Mn::from_mass_number(mass_number).map(Mn),
+            Element::Fe => Fe::from_mass_number(mass_number).map(Fe),
+            Element::Co => Co::from_mass_number(mass_number).map(Co),
+            Element::Ni => Ni::from_mass_number(mass_number).map(Ni),
+            Element::Cu => Cu::from_mass_number(mass_number).map(Cu),
+            Element::Zn => Zn::from_mass_number(mass_number).map(Zn),
+            Element::Ga => Ga::from_mass_number(mass_number).map(Ga),
+            Element::Ge => Ge::from_mass_number(mass_number).map(Ge),
+            Element::As => As::from_mass_number(mass_number).map(As),
+            Element::Se => Se::from_mass_number(mass_number).map(Se),
+            Element::Br => Br::from_mass_number(mass_number).map(Br),
+            Element::Kr => Kr::from_mass_number(mass_number).map(Kr),
+            Element::Rb => Rb::from_mass_number(mass_number).map(Rb),
+            Element::Sr => Sr::from_mass_number(mass_number).map(Sr),
+            Element::Y => Y::from_mass_number(mass_number).map(Y),
+            Element::Zr => Zr::from_mass_number(mass_number).map(Zr),
+            Element::Nb => Nb::from_mass_number(mass_number).map(Nb),
+            Element::Mo => Mo::from_mass_number(mass_number).map(Mo),
+            Element::Tc => Tc::from_mass_number(mass_number).map(Tc),
+            Element::Ru => Ru::from_mass_number(mass_number).map(Ru),
+            Element::Rh => Rh::from_mass_number(mass_number).map(Rh),
+            Element::Pd => Pd::from_mass_number(mass_number).map(Pd),
+            Element::Ag => Ag::from_mass_number(mass_number).map(Ag),
+            Element::Cd => Cd::from_mass_number(mass_number).map(Cd),
+            Element::In => In::from_mass_number(mass_number).map(In),
+            Element::Sn => Sn::from_mass_number(mass_number).map(Sn),
+            Element::Sb => Sb::from_mass_number(mass_number).map(Sb),
+            Element::Te => Te::from_mass_number(mass_number).map(Te),
+            Element::I => I::from_mass_number(mass_number).map(I),
+            Element::Xe => Xe::from_mass_number(mass_number).map(Xe),
+            Element::Cs => Cs::from_mass_number(mass_number).map(Cs),
+            Element::Ba => Ba::from_mass_number(mass_number).map(Ba),
+            Element::La => La::from_mass_number(mass_number).map(La),
+            Element::Ce => Ce::from_mass_number(mass_number).map(Ce),
+            Element::Pr => Pr::from_mass_number(mass_number).map(Pr),
+            Element::Nd => Nd::from_mass_number(mass_number).map(Nd),
+            Element::Pm => Pm::from_mass_number(mass_number).map(Pm),
+            Element::Sm => Sm::from_mass_number(mass_number).map(Sm),
+            Element::Eu => Eu::from_mass_number(mass_number).map(Eu),
+            Element::Gd => Gd::from_mass_number(mass_number).map(Gd),
+            Element::Tb => Tb::from_mass_number(mass_number).map(Tb),
+            Element::Dy => Dy::from_mass_number(mass_number).map(Dy),
+            Element::Ho => Ho::from_mass_number(mass_number).map(Ho),
+            Element::Er => Er::from_mass_number(mass_number).map(Er),
+            Element::Tm => Tm::from_mass_number(mass_number).map(Tm),
+            Element::Yb => Yb::from_mass_number(mass_number).map(Yb),
+            Element::Lu => Lu::from_mass_number(mass_number).map(Lu),
+            Element::Hf => Hf::from_mass_number(mass_number).map(Hf),
+            Element::Ta => Ta::from_mass_number(mass_number).map(Ta),
+            Element::W => W::from_mass_number(mass_number).map(W),
+            Element::Re => Re::from_mass_number(mass_number).map(Re),
+            Element::Os => Os::from_mass_number(mass_number).map(Os),
+            Element::Ir => Ir::from_mass_number(mass_number).map(Ir),
+            Element::Pt => Pt::from_mass_number(mass_number).map(Pt),
+            Element::Au => Au::from_mass_number(mass_number).map(Au),
+            Element::Hg => Hg::from_mass_number(mass_number).map(Hg),
+            Element::Tl => Tl::from_mass_number(mass_number).map(Tl),
+            Element::Pb => Pb::from_mass_number(mass_number).map(Pb),
+            Element::Bi => Bi::from_mass_number(mass_number).map(Bi),
+            Element::Po => Po::from_mass_number(mass_number).map(Po),
+            Element::At => At::from_mass_number(mass_number).map(At),
+            Element::Rn => Rn::from_mass_number(mass_number).map(Rn),
+            Element::Fr => Fr::from_mass_number(mass_number).map(Fr),
+            Element::Ra => Ra::from_mass_number(mass_number).map(Ra),
+            Element::Ac => Ac::from_mass_number(mass_number).map(Ac),
+            Element::Th => Th::from_mass_number(mass_number).map(Th),
+            Element::Pa => Pa::from_mass_number(mass_number).map(Pa),
+            Element::U => U::from_mass_number(mass_number).map(U),
+            Element::Np => Np::from_mass_number(mass_number).map(Np),
+            Element::Pu => Pu::from_mass_number(mass_number).map(Pu),
+            Element::Am => Am::from_mass_number(mass_number).map(Am),
+            Element::Cm => Cm::from_mass_number(mass_number).map(Cm),
+            Element::Bk => Bk::from_mass_number(mass_number).map(Bk),
+            Element::Cf => Cf::from_mass_number(mass_number).map(Cf),
+            Element::Es => Es::from_mass_number(mass_number).map(Es),
+            Element::Fm => Fm::from_mass_number(mass_number).map(Fm),
+            Element::Md => Md::from_mass_number(mass_number).map(Md),
+            Element::No => No::from_mass_number(mass_number).map(No),
+            Element::Lr => Lr::from_mass_number(mass_number).map(Lr),
+            Element::Rf => Rf::from_mass_number(mass_number).map(Rf),
+            Element::Db => Db::from_mass_number(mass_number).map(Db),
+            Element::Sg => Sg::from_mass_number(mass_number).map(Sg),
+            Element::Bh => Bh::from_mass_number(mass_number).map(Bh),
+            Element::Hs => Hs::from_mass_number(mass_number).map(Hs),
+            Element::Mt => Mt::from_mass_number(mass_number).map(Mt),
+            Element::Ds => Ds::from_mass_number(mass_number).map(Ds),
+            Element::Rg => Rg::from_mass_number(mass_number).map(Rg),
+            Element::Cn => Cn::from_mass_number(mass_number).map(Cn),
+            Element::Nh => Nh::from_mass_number(mass_number).map(Nh),
+            Element::Fl => Fl::from_mass_number(mass_number).map(Fl),
+            Element::Mc => Mc::from_mass_number(mass_number).map(Mc),
+            Element::Lv => Lv::from_mass_number(mass_number).map(Lv),
+            Element::Ts => Ts::from_mass_number(mass_number).map(Ts),
+            Element::Og => Og::from_mass_number(mass_number).map(Og),
+        }
+    }
+
     /// Element
     #[must_use]
     pub const fn element(&self) -> Element {
@@ -265,6 +747,279 @@ impl Isotope {
         }
     }
 
+    /// Atomic number Z
+    #[must_use]
+    pub const fn atomic_number(&self) -> usize {
+        self.element().atomic_number()
+    }
+
+    /// Decay modes
+    ///
+    /// Decay modes of the ground state and their branching ratios, empty for
+    /// stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            H(h) => h.decay_modes(),
+            He(he) => he.decay_modes(),
+            Li(li) => li.decay_modes(),
+            Be(be) => be.decay_modes(),
+            B(b) => b.decay_modes(),
+            C(c) => c.decay_modes(),
+            N(n) => n.decay_modes(),
+            O(o) => o.decay_modes(),
+            F(f) => f.decay_modes(),
+            Ne(ne) => ne.decay_modes(),
+            Na(na) => na.decay_modes(),
+            Mg(mg) => mg.decay_modes(),
+            Al(al) => al.decay_modes(),
+            Si(si) => si.decay_modes(),
+            P(p) => p.decay_modes(),
+            S(s) => s.decay_modes(),
+            Cl(cl) => cl.decay_modes(),
+            Ar(ar) => ar.decay_modes(),
+            K(k) => k.decay_modes(),
+            Ca(ca) => ca.decay_modes(),
+            Sc(sc) => sc.decay_modes(),
+            Ti(ti) => ti.decay_modes(),
+            V(v) => v.decay_modes(),
+            Cr(cr) => cr.decay_modes(),
+            Mn(mn) => mn.decay_modes(),
+            Fe(fe) => fe.decay_modes(),
+            Co(co) => co.decay_modes(),
+            Ni(ni) => ni.decay_modes(),
+            Cu(cu) => cu.decay_modes(),
+            Zn(zn) => zn.decay_modes(),
+            Ga(ga) => ga.decay_modes(),
+            Ge(ge) => ge.decay_modes(),
+            As(r#as) => r#as.decay_modes(),
+            Se(se) => se.decay_modes(),
+            Br(br) => br.decay_modes(),
+            Kr(kr) => kr.decay_modes(),
+            Rb(rb) => rb.decay_modes(),
+            Sr(sr) => sr.decay_modes(),
+            Y(y) => y.decay_modes(),
+            Zr(zr) => zr.decay_modes(),
+            Nb(nb) => nb.decay_modes(),
+            Mo(mo) => mo.decay_modes(),
+            Tc(tc) => tc.decay_modes(),
+            Ru(ru) => ru.decay_modes(),
+            Rh(rh) => rh.decay_modes(),
+            Pd(pd) => pd.decay_modes(),
+            Ag(ag) => ag.decay_modes(),
+            Cd(cd) => cd.decay_modes(),
+            In(r#in) => r#in.decay_modes(),
+            Sn(sn) => sn.decay_modes(),
+            Sb(sb) => sb.decay_modes(),
+            Te(te) => te.decay_modes(),
+            I(i) => i.decay_modes(),
+            Xe(xe) => xe.decay_modes(),
+            Cs(cs) => cs.decay_modes(),
+            Ba(ba) => ba.decay_modes(),
+            La(la) => la.decay_modes(),
+            Ce(ce) => ce.decay_modes(),
+            Pr(pr) => pr.decay_modes(),
+            Nd(nd) => nd.decay_modes(),
+            Pm(pm) => pm.decay_modes(),
+            Sm(sm) => sm.decay_modes(),
+            Eu(eu) => eu.decay_modes(),
+            Gd(gd) => gd.decay_modes(),
+            Tb(tb) => tb.decay_modes(),
+            Dy(dy) => dy.decay_modes(),
+            Ho(ho) => ho.decay_modes(),
+            Er(er) => er.decay_modes(),
+            Tm(tm) => tm.decay_modes(),
+            Yb(yb) => yb.decay_modes(),
+            Lu(lu) => lu.decay_modes(),
+            Hf(hf) => hf.decay_modes(),
+            Ta(ta) => ta.decay_modes(),
+            W(w) => w.decay_modes(),
+            Re(re) => re.decay_modes(),
+            Os(os) => os.decay_modes(),
+            Ir(ir) => ir.decay_modes(),
+            Pt(pt) => pt.decay_modes(),
+            Au(au) => au.decay_modes(),
+            Hg(hg) => hg.decay_modes(),
+            Tl(tl) => tl.decay_modes(),
+            Pb(pb) => pb.decay_modes(),
+            Bi(bi) => bi.decay_modes(),
+            Po(po) => po.decay_modes(),
+            At(at) => at.decay_modes(),
+            Rn(rn) => rn.decay_modes(),
+            Fr(fr) => fr.decay_modes(),
+            Ra(ra) => ra.decay_modes(),
+            Ac(ac) => ac.decay_modes(),
+            Th(th) => th.decay_modes(),
+            Pa(pa) => pa.decay_modes(),
+            U(u) => u.decay_modes(),
+            Np(np) => np.decay_modes(),
+            Pu(pu) => pu.decay_modes(),
+            Am(am) => am.decay_modes(),
+            Cm(cm) => cm.decay_modes(),
+            Bk(bk) => bk.decay_modes(),
+            Cf(cf) => cf.decay_modes(),
+            Es(es) => es.decay_modes(),
+            Fm(fm) => fm.decay_modes(),
+            Md(md) => md.decay_modes(),
+            No(no) => no.decay_modes(),
+            Lr(lr) => lr.decay_modes(),
+            Rf(rf) => rf.decay_modes(),
+            Db(db) => db.decay_modes(),
+            Sg(sg) => sg.decay_modes(),
+            Bh(bh) => bh.decay_modes(),
+            Hs(hs) => hs.decay_modes(),
+            Mt(mt) => mt.decay_modes(),
+            Ds(ds) => ds.decay_modes(),
+            Rg(rg) => rg.decay_modes(),
+            Cn(cn) => cn.decay_modes(),
+            Nh(nh) => nh.decay_modes(),
+            Fl(fl) => fl.decay_modes(),
+            Mc(mc) => mc.decay_modes(),
+            Lv(lv) => lv.decay_modes(),
+            Ts(ts) => ts.decay_modes(),
+            Og(og) => og.decay_modes(),
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Half-life of the ground state, infinite for stable isotopes and `None`
+    /// if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            H(h) => h.half_life(),
+            He(he) => he.half_life(),
+            Li(li) => li.half_life(),
+            Be(be) => be.half_life(),
+            B(b) => b.half_life(),
+            C(c) => c.half_life(),
+            N(n) => n.half_life(),
+            O(o) => o.half_life(),
+            F(f) => f.half_life(),
+            Ne(ne) => ne.half_life(),
+            Na(na) => na.half_life(),
+            Mg(mg) => mg.half_life(),
+            Al(al) => al.half_life(),
+            Si(si) => si.half_life(),
+            P(p) => p.half_life(),
+            S(s) => s.half_life(),
+            Cl(cl) => cl.half_life(),
+            Ar(ar) => ar.half_life(),
+            K(k) => k.half_life(),
+            Ca(ca) => ca.half_life(),
+            Sc(sc) => sc.half_life(),
+            Ti(ti) => ti.half_life(),
+            V(v) => v.half_life(),
+            Cr(cr) => cr.half_life(),
+            Mn(mn) => mn.half_life(),
+            Fe(fe) => fe.half_life(),
+            Co(co) => co.half_life(),
+            Ni(ni) => ni.half_life(),
+            Cu(cu) => cu.half_life(),
+            Zn(zn) => zn.half_life(),
+            Ga(ga) => ga.half_life(),
+            Ge(ge) => ge.half_life(),
+            As(r#as) => r#as.half_life(),
+            Se(se) => se.half_life(),
+            Br(br) => br.half_life(),
+            Kr(kr) => kr.half_life(),
+            Rb(rb) => rb.half_life(),
+            Sr(sr) => sr.half_life(),
+            Y(y) => y.half_life(),
+            Zr(zr) => zr.half_life(),
+            Nb(nb) => nb.half_life(),
+            Mo(mo) => mo.half_life(),
+            Tc(tc) => tc.half_life(),
+            Ru(ru) => ru.half_life(),
+            Rh(rh) => rh.half_life(),
+            Pd(pd) => pd.half_life(),
+            Ag(ag) => ag.half_life(),
+            Cd(cd) => cd.half_life(),
+            In(r#in) => r#in.half_life(),
+            Sn(sn) => sn.half_life(),
+            Sb(sb) => sb.half_life(),
+            Te(te) => te.half_life(),
+            I(i) => i.half_life(),
+            Xe(xe) => xe.half_life(),
+            Cs(cs) => cs.half_life(),
+            Ba(ba) => ba.half_life(),
+            La(la) => la.half_life(),
+            Ce(ce) => ce.half_life(),
+            Pr(pr) => pr.half_life(),
+            Nd(nd) => nd.half_life(),
+            Pm(pm) => pm.half_life(),
+            Sm(sm) => sm.half_life(),
+            Eu(eu) => eu.half_life(),
+            Gd(gd) => gd.half_life(),
+            Tb(tb) => tb.half_life(),
+            Dy(dy) => dy.half_life(),
+            Ho(ho) => ho.half_life(),
+            Er(er) => er.half_life(),
+            Tm(tm) => tm.half_life(),
+            Yb(yb) => yb.half_life(),
+            Lu(lu) => lu.half_life(),
+            Hf(hf) => hf.half_life(),
+            Ta(ta) => ta.half_life(),
+            W(w) => w.half_life(),
+            Re(re) => re.half_life(),
+            Os(os) => os.half_life(),
+            Ir(ir) => ir.half_life(),
+            Pt(pt) => pt.half_life(),
+            Au(au) => au.half_life(),
+            Hg(hg) => hg.half_life(),
+            Tl(tl) => tl.half_life(),
+            Pb(pb) => pb.half_life(),
+            Bi(bi) => bi.half_life(),
+            Po(po) => po.half_life(),
+            At(at) => at.half_life(),
+            Rn(rn) => rn.half_life(),
+            Fr(fr) => fr.half_life(),
+            Ra(ra) => ra.half_life(),
+            Ac(ac) => ac.half_life(),
+            Th(th) => th.half_life(),
+            Pa(pa) => pa.half_life(),
+            U(u) => u.half_life(),
+            Np(np) => np.half_life(),
+            Pu(pu) => pu.half_life(),
+            Am(am) => am.half_life(),
+            Cm(cm) => cm.half_life(),
+            Bk(bk) => bk.half_life(),
+            Cf(cf) => cf.half_life(),
+            Es(es) => es.half_life(),
+            Fm(fm) => fm.half_life(),
+            Md(md) => md.half_life(),
+            No(no) => no.half_life(),
+            Lr(lr) => lr.half_life(),
+            Rf(rf) => rf.half_life(),
+            Db(db) => db.half_life(),
+            Sg(sg) => sg.half_life(),
+            Bh(bh) => bh.half_life(),
+            Hs(hs) => hs.half_life(),
+            Mt(mt) => mt.half_life(),
+            Ds(ds) => ds.half_life(),
+            Rg(rg) => rg.half_life(),
+            Cn(cn) => cn.half_life(),
+            Nh(nh) => nh.half_life(),
+            Fl(fl) => fl.half_life(),
+            Mc(mc) => mc.half_life(),
+            Lv(lv) => lv.half_life(),
+            Ts(ts) => ts.half_life(),
+            Og(og) => og.half_life(),
+        }
+    }
+
+    /// Whether the isotope is stable
+    ///
+    /// Isotopes whose decay has never been observed count as stable.
+    #[must_use]
+    pub const fn is_stable(&self) -> bool {
+        match self.half_life() {
+            Some(half_life) => half_life.value.is_infinite(),
+            None => false,
+        }
+    }
+
     /// Composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -515,6 +1270,24 @@ impl Isotope {
         }
     }
 
+    /// Neutron number N = A - Z
+    #[must_use]
+    pub const fn neutron_number(&self) -> usize {
+        self.mass_number() - self.atomic_number()
+    }
+
+    /// Mass excess in keV
+    ///
+    /// Δ = (Aᵣ - A) · mᵤc²
+    #[must_use]
+    pub const fn mass_excess(&self) -> Uncertain {
+        let relative_atomic_mass = self.relative_atomic_mass();
+        Uncertain::new(
+            (relative_atomic_mass.value - self.mass_number() as f64) * ATOMIC_MASS_ENERGY,
+            relative_atomic_mass.uncertainty * ATOMIC_MASS_ENERGY,
+        )
+    }
+
     /// Relative atomic mass
     #[must_use]
     pub const fn relative_atomic_mass(&self) -> Uncertain {
@@ -640,6 +1413,140 @@ impl Isotope {
         }
     }
 
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            H(h) => h.spin_parity(),
+            He(he) => he.spin_parity(),
+            Li(li) => li.spin_parity(),
+            Be(be) => be.spin_parity(),
+            B(b) => b.spin_parity(),
+            C(c) => c.spin_parity(),
+            N(n) => n.spin_parity(),
+            O(o) => o.spin_parity(),
+            F(f) => f.spin_parity(),
+            Ne(ne) => ne.spin_parity(),
+            Na(na) => na.spin_parity(),
+            Mg(mg) => mg.spin_parity(),
+            Al(al) => al.spin_parity(),
+            Si(si) => si.spin_parity(),
+            P(p) => p.spin_parity(),
+            S(s) => s.spin_parity(),
+            Cl(cl) => cl.spin_parity(),
+            Ar(ar) => ar.spin_parity(),
+            K(k) => k.spin_parity(),
+            Ca(ca) => ca.spin_parity(),
+            Sc(sc) => sc.spin_parity(),
+            Ti(ti) => ti.spin_parity(),
+            V(v) => v.spin_parity(),
+            Cr(cr) => cr.spin_parity(),
+            Mn(mn) => mn.spin_parity(),
+            Fe(fe) => fe.spin_parity(),
+            Co(co) => co.spin_parity(),
+            Ni(ni) => ni.spin_parity(),
+            Cu(cu) => cu.spin_parity(),
+            Zn(zn) => zn.spin_parity(),
+            Ga(ga) => ga.spin_parity(),
+            Ge(ge) => ge.spin_parity(),
+            As(r#as) => r#as.spin_parity(),
+            Se(se) => se.spin_parity(),
+            Br(br) => br.spin_parity(),
+            Kr(kr) => kr.spin_parity(),
+            Rb(rb) => rb.spin_parity(),
+            Sr(sr) => sr.spin_parity(),
+            Y(y) => y.spin_parity(),
+            Zr(zr) => zr.spin_parity(),
+            Nb(nb) => nb.spin_parity(),
+            Mo(mo) => mo.spin_parity(),
+            Tc(tc) => tc.spin_parity(),
+            Ru(ru) => ru.spin_parity(),
+            Rh(rh) => rh.spin_parity(),
+            Pd(pd) => pd.spin_parity(),
+            Ag(ag) => ag.spin_parity(),
+            Cd(cd) => cd.spin_parity(),
+            In(r#in) => r#in.spin_parity(),
+            Sn(sn) => sn.spin_parity(),
+            Sb(sb) => sb.spin_parity(),
+            Te(te) => te.spin_parity(),
+            I(i) => i.spin_parity(),
+            Xe(xe) => xe.spin_parity(),
+            Cs(cs) => cs.spin_parity(),
+            Ba(ba) => ba.spin_parity(),
+            La(la) => la.spin_parity(),
+            Ce(ce) => ce.spin_parity(),
+            Pr(pr) => pr.spin_parity(),
+            Nd(nd) => nd.spin_parity(),
+            Pm(pm) => pm.spin_parity(),
+            Sm(sm) => sm.spin_parity(),
+            Eu(eu) => eu.spin_parity(),
+            Gd(gd) => gd.spin_parity(),
+            Tb(tb) => tb.spin_parity(),
+            Dy(dy) => dy.spin_parity(),
+            Ho(ho) => ho.spin_parity(),
+            Er(er) => er.spin_parity(),
+            Tm(tm) => tm.spin_parity(),
+            Yb(yb) => yb.spin_parity(),
+            Lu(lu) => lu.spin_parity(),
+            Hf(hf) => hf.spin_parity(),
+            Ta(ta) => ta.spin_parity(),
+            W(w) => w.spin_parity(),
+            Re(re) => re.spin_parity(),
+            Os(os) => os.spin_parity(),
+            Ir(ir) => ir.spin_parity(),
+            Pt(pt) => pt.spin_parity(),
+            Au(au) => au.spin_parity(),
+            Hg(hg) => hg.spin_parity(),
+            Tl(tl) => tl.spin_parity(),
+            Pb(pb) => pb.spin_parity(),
+            Bi(bi) => bi.spin_parity(),
+            Po(po) => po.spin_parity(),
+            At(at) => at.spin_parity(),
+            Rn(rn) => rn.spin_parity(),
+            Fr(fr) => fr.spin_parity(),
+            Ra(ra) => ra.spin_parity(),
+            Ac(ac) => ac.spin_parity(),
+            Th(th) => th.spin_parity(),
+            Pa(pa) => pa.spin_parity(),
+            U(u) => u.spin_parity(),
+            Np(np) => np.spin_parity(),
+            Pu(pu) => pu.spin_parity(),
+            Am(am) => am.spin_parity(),
+            Cm(cm) => cm.spin_parity(),
+            Bk(bk) => bk.spin_parity(),
+            Cf(cf) => cf.spin_parity(),
+            Es(es) => es.spin_parity(),
+            Fm(fm) => fm.spin_parity(),
+            Md(md) => md.spin_parity(),
+            No(no) => no.spin_parity(),
+            Lr(lr) => lr.spin_parity(),
+            Rf(rf) => rf.spin_parity(),
+            Db(db) => db.spin_parity(),
+            Sg(sg) => sg.spin_parity(),
+            Bh(bh) => bh.spin_parity(),
+            Hs(hs) => hs.spin_parity(),
+            Mt(mt) => mt.spin_parity(),
+            Ds(ds) => ds.spin_parity(),
+            Rg(rg) => rg.spin_parity(),
+            Cn(cn) => cn.spin_parity(),
+            Nh(nh) => nh.spin_parity(),
+            Fl(fl) => fl.spin_parity(),
+            Mc(mc) => mc.spin_parity(),
+            Lv(lv) => lv.spin_parity(),
+            Ts(ts) => ts.spin_parity(),
+            Og(og) => og.spin_parity(),
+        }
+    }
+
+    /// Notes
+    ///
+    /// Notes for the representative isotopic composition of the element from
+    /// the [default source](crate::Source::DEFAULT).
+    #[must_use]
+    pub const fn notes(&self) -> &'static [Notes] {
+        self.element().notes()
+    }
+
     /// Symbol
     #[must_use]
     pub const fn symbol(&self) -> &'static str {
@@ -651,137 +1558,55 @@ impl Isotope {
     }
 }
 
+impl Element {
+    /// Isotopes
+    pub fn isotopes(&self) -> impl Iterator<Item = Isotope> {
+        let element = *self;
+        ISOTOPES
+            .into_iter()
+            .skip_while(move |isotope| isotope.element() != element)
+            .take_while(move |isotope| isotope.element() == element)
+    }
+
+    /// Stable isotopes
+    pub fn stable_isotopes(&self) -> impl Iterator<Item = Isotope> {
+        self.isotopes().filter(Isotope::is_stable)
+    }
+
+    /// Most abundant isotope
+    ///
+    /// Isotope with the largest amount fraction in the representative
+    /// isotopic composition, `None` for elements without one.
+    #[must_use]
+    pub fn most_abundant_isotope(&self) -> Option<Isotope> {
+        self.isotopes()
+            .filter(|isotope| isotope.isotopic_composition().is_some())
+            .max_by_key(Isotope::isotopic_composition)
+    }
+
+    /// Monoisotopic mass
+    ///
+    /// Relative atomic mass of the most abundant isotope.
+    #[must_use]
+    pub fn monoisotopic_mass(&self) -> Option<Uncertain> {
+        Some(self.most_abundant_isotope()?.relative_atomic_mass())
+    }
+}
+
 impl From<Isotope> for Element {
     fn from(value: Isotope) -> Self {
         value.element()
     }
 }
 
-impl FromStr for Isotope {
-    type Err = Error;
+impl TryFrom<(Element, usize)> for Isotope {
+    type Error = Error;
 
-    fn from_str(value: &str) -> Result<Self> {
-        match value {
-            "H" => Ok(H(H::iter().max_by_key(H::isotopic_composition).unwrap())),
-            "He" => Ok(He(He::iter().max_by_key(He::isotopic_composition).unwrap())),
-            "Li" => Ok(Li(Li::iter().max_by_key(Li::isotopic_composition).unwrap())),
-            "Be" => Ok(Be(Be::iter().next().unwrap())),
-            "B" => Ok(B(B::iter().max_by_key(B::isotopic_composition).unwrap())),
-            "C" => Ok(C(C::iter().max_by_key(C::isotopic_composition).unwrap())),
-            "N" => Ok(N(N::iter().max_by_key(N::isotopic_composition).unwrap())),
-            "O" => Ok(O(O::iter().max_by_key(O::isotopic_composition).unwrap())),
-            "F" => Ok(F(F::iter().next().unwrap())),
-            "Ne" => Ok(Ne(Ne::iter().max_by_key(Ne::isotopic_composition).unwrap())),
-            "Na" => Ok(Na(Na::iter().next().unwrap())),
-            "Mg" => Ok(Mg(Mg::iter().max_by_key(Mg::isotopic_composition).unwrap())),
-            "Al" => Ok(Al(Al::iter().next().unwrap())),
-            "Si" => Ok(Si(Si::iter().max_by_key(Si::isotopic_composition).unwrap())),
-            "P" => Ok(P(P::iter().next().unwrap())),
-            "S" => Ok(S(S::iter().max_by_key(S::isotopic_composition).unwrap())),
-            "Cl" => Ok(Cl(Cl::iter().max_by_key(Cl::isotopic_composition).unwrap())),
-            "Ar" => Ok(Ar(Ar::iter().max_by_key(Ar::isotopic_composition).unwrap())),
-            "K" => Ok(K(K::iter().max_by_key(K::isotopic_composition).unwrap())),
-            "Ca" => Ok(Ca(Ca::iter().max_by_key(Ca::isotopic_composition).unwrap())),
-            "Sc" => Ok(Sc(Sc::iter().next().unwrap())),
-            "Ti" => Ok(Ti(Ti::iter().max_by_key(Ti::isotopic_composition).unwrap())),
-            "V" => Ok(V(V::iter().max_by_key(V::isotopic_composition).unwrap())),
-            "Cr" => Ok(Cr(Cr::iter().max_by_key(Cr::isotopic_composition).unwrap())),
-            "Mn" => Ok(Mn(Mn::iter().next().unwrap())),
-            "Fe" => Ok(Fe(Fe::iter().max_by_key(Fe::isotopic_composition).unwrap())),
-            "Co" => Ok(Co(Co::iter().next().unwrap())),
-            "Ni" => Ok(Ni(Ni::iter().max_by_key(Ni::isotopic_composition).unwrap())),
-            "Cu" => Ok(Cu(Cu::iter().max_by_key(Cu::isotopic_composition).unwrap())),
-            "Zn" => Ok(Zn(Zn::iter().max_by_key(Zn::isotopic_composition).unwrap())),
-            "Ga" => Ok(Ga(Ga::iter().max_by_key(Ga::isotopic_composition).unwrap())),
-            "Ge" => Ok(Ge(Ge::iter().max_by_key(Ge::isotopic_composition).unwrap())),
-            "As" => Ok(As(As::iter().next().unwrap())),
-            "Se" => Ok(Se(Se::iter().max_by_key(Se::isotopic_composition).unwrap())),
-            "Br" => Ok(Br(Br::iter().max_by_key(Br::isotopic_composition).unwrap())),
-            "Kr" => Ok(Kr(Kr::iter().max_by_key(Kr::isotopic_composition).unwrap())),
-            "Rb" => Ok(Rb(Rb::iter().max_by_key(Rb::isotopic_composition).unwrap())),
-            "Sr" => Ok(Sr(Sr::iter().max_by_key(Sr::isotopic_composition).unwrap())),
-            "Y" => Ok(Y(Y::iter().next().unwrap())),
-            "Zr" => Ok(Zr(Zr::iter().max_by_key(Zr::isotopic_composition).unwrap())),
-            "Nb" => Ok(Nb(Nb::iter().next().unwrap())),
-            "Mo" => Ok(Mo(Mo::iter().max_by_key(Mo::isotopic_composition).unwrap())),
-            "Tc" => Ok(Tc(Tc::iter().max_by_key(Tc::isotopic_composition).unwrap())),
-            "Ru" => Ok(Ru(Ru::iter().max_by_key(Ru::isotopic_composition).unwrap())),
-            "Rh" => Ok(Rh(Rh::iter().next().unwrap())),
-            "Pd" => Ok(Pd(Pd::iter().max_by_key(Pd::isotopic_composition).unwrap())),
-            "Ag" => Ok(Ag(Ag::iter().max_by_key(Ag::isotopic_composition).unwrap())),
-            "Cd" => Ok(Cd(Cd::iter().max_by_key(Cd::isotopic_composition).unwrap())),
-            "In" => Ok(In(In::iter().max_by_key(In::isotopic_composition).unwrap())),
-            "Sn" => Ok(Sn(Sn::iter().max_by_key(Sn::isotopic_composition).unwrap())),
-            "Sb" => Ok(Sb(Sb::iter().max_by_key(Sb::isotopic_composition).unwrap())),
-            "Te" => Ok(Te(Te::iter().max_by_key(Te::isotopic_composition).unwrap())),
-            "I" => Ok(I(I::iter().next().unwrap())),
-            "Xe" => Ok(Xe(Xe::iter().max_by_key(Xe::isotopic_composition).unwrap())),
-            "Cs" => Ok(Cs(Cs::iter().next().unwrap())),
-            "Ba" => Ok(Ba(Ba::iter().max_by_key(Ba::isotopic_composition).unwrap())),
-            "La" => Ok(La(La::iter().max_by_key(La::isotopic_composition).unwrap())),
-            "Ce" => Ok(Ce(Ce::iter().max_by_key(Ce::isotopic_composition).unwrap())),
-            "Pr" => Ok(Pr(Pr::iter().next().unwrap())),
-            "Nd" => Ok(Nd(Nd::iter().max_by_key(Nd::isotopic_composition).unwrap())),
-            "Pm" => Ok(Pm(Pm::iter().max_by_key(Pm::isotopic_composition).unwrap())),
-            "Sm" => Ok(Sm(Sm::iter().max_by_key(Sm::isotopic_composition).unwrap())),
-            "Eu" => Ok(Eu(Eu::iter().max_by_key(Eu::isotopic_composition).unwrap())),
-            "Gd" => Ok(Gd(Gd::iter().max_by_key(Gd::isotopic_composition).unwrap())),
-            "Tb" => Ok(Tb(Tb::iter().next().unwrap())),
-            "Dy" => Ok(Dy(Dy::iter().max_by_key(Dy::isotopic_composition).unwrap())),
-            "Ho" => Ok(Ho(Ho::iter().next().unwrap())),
-            "Er" => Ok(Er(Er::iter().max_by_key(Er::isotopic_composition).unwrap())),
-            "Tm" => Ok(Tm(Tm::iter().next().unwrap())),
-            "Yb" => Ok(Yb(Yb::iter().max_by_key(Yb::isotopic_composition).unwrap())),
-            "Lu" => Ok(Lu(Lu::iter().max_by_key(Lu::isotopic_composition).unwrap())),
-            "Hf" => Ok(Hf(Hf::iter().max_by_key(Hf::isotopic_composition).unwrap())),
-            "Ta" => Ok(Ta(Ta::iter().max_by_key(Ta::isotopic_composition).unwrap())),
-            "W" => Ok(W(W::iter().max_by_key(W::isotopic_composition).unwrap())),
-            "Re" => Ok(Re(Re::iter().max_by_key(Re::isotopic_composition).unwrap())),
-            "Os" => Ok(Os(Os::iter().max_by_key(Os::isotopic_composition).unwrap())),
-            "Ir" => Ok(Ir(Ir::iter().max_by_key(Ir::isotopic_composition).unwrap())),
-            "Pt" => Ok(Pt(Pt::iter().max_by_key(Pt::isotopic_composition).unwrap())),
-            "Au" => Ok(Au(Au::iter().next().unwrap())),
-            "Hg" => Ok(Hg(Hg::iter().max_by_key(Hg::isotopic_composition).unwrap())),
-            "Tl" => Ok(Tl(Tl::iter().max_by_key(Tl::isotopic_composition).unwrap())),
-            "Pb" => Ok(Pb(Pb::iter().max_by_key(Pb::isotopic_composition).unwrap())),
-            "Bi" => Ok(Bi(Bi::iter().next().unwrap())),
-            "Po" => Ok(Po(Po::iter().max_by_key(Po::isotopic_composition).unwrap())),
-            "At" => Ok(At(At::iter().max_by_key(At::isotopic_composition).unwrap())),
-            "Rn" => Ok(Rn(Rn::iter().max_by_key(Rn::isotopic_composition).unwrap())),
-            "Fr" => Ok(Fr(Fr::iter().next().unwrap())),
-            "Ra" => Ok(Ra(Ra::iter().max_by_key(Ra::isotopic_composition).unwrap())),
-            "Ac" => Ok(Ac(Ac::iter().next().unwrap())),
-            "Th" => Ok(Th(Th::iter().max_by_key(Th::isotopic_composition).unwrap())),
-            "Pa" => Ok(Pa(Pa::iter().next().unwrap())),
-            "U" => Ok(U(U::iter().max_by_key(U::isotopic_composition).unwrap())),
-            "Np" => Ok(Np(Np::iter().max_by_key(Np::isotopic_composition).unwrap())),
-            "Pu" => Ok(Pu(Pu::iter().max_by_key(Pu::isotopic_composition).unwrap())),
-            "Am" => Ok(Am(Am::iter().max_by_key(Am::isotopic_composition).unwrap())),
-            "Cm" => Ok(Cm(Cm::iter().max_by_key(Cm::isotopic_composition).unwrap())),
-            "Bk" => Ok(Bk(Bk::iter().max_by_key(Bk::isotopic_composition).unwrap())),
-            "Cf" => Ok(Cf(Cf::iter().max_by_key(Cf::isotopic_composition).unwrap())),
-            "Es" => Ok(Es(Es::iter().next().unwrap())),
-            "Fm" => Ok(Fm(Fm::iter().next().unwrap())),
-            "Md" => Ok(Md(Md::iter().max_by_key(Md::isotopic_composition).unwrap())),
-            "No" => Ok(No(No::iter().next().unwrap())),
-            "Lr" => Ok(Lr(Lr::iter().next().unwrap())),
-            "Rf" => Ok(Rf(Rf::iter().next().unwrap())),
-            "Db" => Ok(Db(Db::iter().next().unwrap())),
-            "Sg" => Ok(Sg(Sg::iter().next().unwrap())),
-            "Bh" => Ok(Bh(Bh::iter().next().unwrap())),
-            "Hs" => Ok(Hs(Hs::iter().next().unwrap())),
-            "Mt" => Ok(Mt(Mt::iter().next().unwrap())),
-            "Ds" => Ok(Ds(Ds::iter().next().unwrap())),
-            "Rg" => Ok(Rg(Rg::iter().next().unwrap())),
-            "Cn" => Ok(Cn(Cn::iter().next().unwrap())),
-            "Nh" => Ok(Nh(Nh::iter().next().unwrap())),
-            "Fl" => Ok(Fl(Fl::iter().next().unwrap())),
-            "Mc" => Ok(Mc(Mc::iter().next().unwrap())),
-            "Lv" => Ok(Lv(Lv::iter().next().unwrap())),
-            "Ts" => Ok(Ts(Ts::iter().next().unwrap())),
-            "Og" => Ok(Og(Og::iter().next().unwrap())),
-            _ => Err(Error::Parse),
-        }
+    fn try_from((element, mass_number): (Element, usize)) -> Result<Self> {
+        Self::new(element, mass_number).ok_or(Error::UnknownIsotope {
+            element,
+            mass_number,
+        })
     }
 }
 
@@ -802,25 +1627,3 @@ impl FromStr for Isotope {
 //         self.maximum()
 //     }
 // }
-
-/// Notes
-///
-/// Notes for Representative Isotopic Composition and Standard Atomic Weight.
-///
-/// - g - Geological materials are known in which the element has an isotopic
-///   composition outside the limits for normal material. The difference between
-///   the atomic weight of the element in such materials and that given in the
-///   table may exceed the stated uncertainty.
-/// - m - Modified isotopic compositions may be found in commercially available
-///   material because the material has been subjected to an undisclosed or
-///   inadvertent isotopic fractionation. Substantial deviations in atomic
-///   weight of the element from that given in the table can occur.
-/// - r - Range in isotopic composition of normal terrestrial material prevents
-///   a more precise standard atomic weight being given; the tabulated
-///   atomic-weight value and uncertainty should be applicable to normal
-///   materials.
-pub enum Notes {
-    G,
-    M,
-    R,
-}
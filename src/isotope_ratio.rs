@@ -0,0 +1,240 @@
+use crate::{Error, Isotope, ReferenceMaterial, Result, Uncertain};
+
+/// Per mil
+const PER_MIL: f64 = 1000.0;
+
+/// Percent
+const PERCENT: f64 = 100.0;
+
+/// Isotope ratio
+///
+/// Amount ratio R of two isotopes of one element, together with its
+/// conversions to the delta (δ), fractional abundance (F), atom percent and
+/// atom percent excess (APE) notations. Uncertainties are propagated to first
+/// order.
+///
+/// F and the notations based on it need the ratios of all the other naturally
+/// occurring isotopes of the element to the same denominator, Rᵢ:
+/// F = R / (1 + R + ΣRᵢ). For two-isotope elements there are none.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct IsotopeRatio {
+    numerator: Isotope,
+    denominator: Isotope,
+    value: Uncertain,
+}
+
+impl IsotopeRatio {
+    /// Isotope ratio R of `numerator` to `denominator`
+    pub fn new(numerator: Isotope, denominator: Isotope, value: Uncertain) -> Result<Self> {
+        if numerator.element() != denominator.element() {
+            return Err(Error::ForeignIsotope {
+                element: denominator.element(),
+                isotope: numerator,
+            });
+        }
+        Ok(Self {
+            numerator,
+            denominator,
+            value,
+        })
+    }
+
+    /// Absolute isotope ratio of a reference material
+    pub fn reference(
+        numerator: Isotope,
+        denominator: Isotope,
+        reference: ReferenceMaterial,
+    ) -> Result<Self> {
+        let value = reference
+            .ratio(numerator, denominator)
+            .ok_or(Error::UnknownRatio {
+                reference,
+                numerator,
+                denominator,
+            })?;
+        Self::new(numerator, denominator, value)
+    }
+
+    /// Isotope ratio from a delta value (‰) relative to a reference material
+    ///
+    /// R = Rᵣₑ𝒻 · (1 + δ / 1000)
+    pub fn from_delta(
+        numerator: Isotope,
+        denominator: Isotope,
+        delta: Uncertain,
+        reference: ReferenceMaterial,
+    ) -> Result<Self> {
+        let reference = Self::reference(numerator, denominator, reference)?.value;
+        let factor = 1.0 + delta.value / PER_MIL;
+        let value = Uncertain::new(
+            reference.value * factor,
+            reference.uncertainty * factor.abs() + reference.value * delta.uncertainty / PER_MIL,
+        );
+        Self::new(numerator, denominator, value)
+    }
+
+    /// Isotope ratio from the fractional abundance F of `numerator`
+    ///
+    /// R = F · (1 + ΣRᵢ) / (1 - F), where `others` are the ratios Rᵢ of the
+    /// other isotopes of the element to `denominator`.
+    pub fn from_fraction(
+        numerator: Isotope,
+        denominator: Isotope,
+        fraction: Uncertain,
+        others: &[Self],
+    ) -> Result<Self> {
+        if !(0.0..1.0).contains(&fraction.value) {
+            return Err(Error::InvalidFraction {
+                isotope: numerator,
+                fraction,
+            });
+        }
+        let rest = Self::new(numerator, denominator, Uncertain::default())?.rest(others)?;
+        let complement = 1.0 - fraction.value;
+        let factor = 1.0 + rest.value;
+        let value = Uncertain::new(
+            fraction.value * factor / complement,
+            fraction.uncertainty * factor / (complement * complement)
+                + fraction.value * rest.uncertainty / complement,
+        );
+        Self::new(numerator, denominator, value)
+    }
+
+    /// Isotope ratio from the atom percent of `numerator`
+    ///
+    /// See [`from_fraction`](Self::from_fraction).
+    pub fn from_atom_percent(
+        numerator: Isotope,
+        denominator: Isotope,
+        atom_percent: Uncertain,
+        others: &[Self],
+    ) -> Result<Self> {
+        let fraction = Uncertain::new(
+            atom_percent.value / PERCENT,
+            atom_percent.uncertainty / PERCENT,
+        );
+        Self::from_fraction(numerator, denominator, fraction, others)
+    }
+
+    /// Numerator
+    #[must_use]
+    pub const fn numerator(&self) -> Isotope {
+        self.numerator
+    }
+
+    /// Denominator
+    #[must_use]
+    pub const fn denominator(&self) -> Isotope {
+        self.denominator
+    }
+
+    /// Isotope ratio R
+    #[must_use]
+    pub const fn value(&self) -> Uncertain {
+        self.value
+    }
+
+    /// Delta value (‰) relative to a reference material
+    ///
+    /// δ = (R / Rᵣₑ𝒻 - 1) · 1000
+    pub fn delta(&self, reference: ReferenceMaterial) -> Result<Uncertain> {
+        let reference = Self::reference(self.numerator, self.denominator, reference)?.value;
+        let quotient = self.value.value / reference.value;
+        Ok(Uncertain::new(
+            (quotient - 1.0) * PER_MIL,
+            (self.value.uncertainty / reference.value
+                + quotient * reference.uncertainty / reference.value)
+                * PER_MIL,
+        ))
+    }
+
+    /// Fractional abundance F of the numerator
+    ///
+    /// F = R / (1 + R + ΣRᵢ), where `others` are the ratios Rᵢ of the other
+    /// isotopes of the element to the same denominator.
+    pub fn fraction(&self, others: &[Self]) -> Result<Uncertain> {
+        let rest = self.rest(others)?;
+        let factor = 1.0 + rest.value;
+        let sum = factor + self.value.value;
+        Ok(Uncertain::new(
+            self.value.value / sum,
+            (self.value.uncertainty * factor + self.value.value * rest.uncertainty) / (sum * sum),
+        ))
+    }
+
+    /// Atom percent of the numerator
+    ///
+    /// See [`fraction`](Self::fraction).
+    pub fn atom_percent(&self, others: &[Self]) -> Result<Uncertain> {
+        let fraction = self.fraction(others)?;
+        Ok(Uncertain::new(
+            fraction.value * PERCENT,
+            fraction.uncertainty * PERCENT,
+        ))
+    }
+
+    /// Atom percent excess of the numerator over a background
+    ///
+    /// APE = atom % - atom %₀, where `others` and `background_others` are the
+    /// ratios of the other isotopes in the sample and in the background.
+    pub fn atom_percent_excess(
+        &self,
+        others: &[Self],
+        background: &Self,
+        background_others: &[Self],
+    ) -> Result<Uncertain> {
+        self.matches(background)?;
+        Ok(self.atom_percent(others)? - background.atom_percent(background_others)?)
+    }
+
+    /// Checks that `other` is a ratio of the same two isotopes
+    fn matches(&self, other: &Self) -> Result<()> {
+        if (self.numerator, self.denominator) != (other.numerator, other.denominator) {
+            return Err(Error::RatioMismatch {
+                expected_numerator: self.numerator,
+                expected_denominator: self.denominator,
+                numerator: other.numerator,
+                denominator: other.denominator,
+            });
+        }
+        Ok(())
+    }
+
+    /// Sum of the ratios of the other isotopes, ΣRᵢ
+    ///
+    /// `others` must hold one ratio to the same denominator for every other
+    /// naturally occurring isotope of the element.
+    fn rest(&self, others: &[Self]) -> Result<Uncertain> {
+        let mut rest = Uncertain::default();
+        for (index, other) in others.iter().enumerate() {
+            if other.denominator != self.denominator
+                || other.numerator == self.numerator
+                || other.numerator == self.denominator
+                || others[..index]
+                    .iter()
+                    .any(|previous| previous.numerator == other.numerator)
+            {
+                return Err(Error::RatioMismatch {
+                    expected_numerator: self.numerator,
+                    expected_denominator: self.denominator,
+                    numerator: other.numerator,
+                    denominator: other.denominator,
+                });
+            }
+            rest = rest + other.value;
+        }
+        let missing = self.denominator.element().isotopes().find(|isotope| {
+            *isotope != self.numerator
+                && *isotope != self.denominator
+                && isotope.isotopic_composition().is_some()
+                && others.iter().all(|other| other.numerator != *isotope)
+        });
+        if let Some(numerator) = missing {
+            return Err(Error::MissingRatio {
+                numerator,
+                denominator: self.denominator,
+            });
+        }
+        Ok(rest)
+    }
+}
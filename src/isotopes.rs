@@ -1,8 +1,39 @@
-use crate::{uncertain, Uncertain};
+use crate::{uncertain, DecayMode, Parity, SpinParity, Uncertain};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use strum::EnumIter;
 
+/// Half-life of a stable isotope
+pub(crate) const STABLE: Uncertain = Uncertain::new(f64::INFINITY, 0.0);
+
+/// Seconds per minute
+const MINUTE: f64 = 60.0;
+
+/// Seconds per hour
+const HOUR: f64 = 3_600.0;
+
+/// Seconds per day
+const DAY: f64 = 86_400.0;
+
+/// Seconds per year (365.2422 days, as in NUBASE)
+pub(crate) const YEAR: f64 = 31_556_926.0;
+
+pub(crate) const fn minutes(value: Uncertain) -> Uncertain {
+    Uncertain::new(value.value * MINUTE, value.uncertainty * MINUTE)
+}
+
+pub(crate) const fn hours(value: Uncertain) -> Uncertain {
+    Uncertain::new(value.value * HOUR, value.uncertainty * HOUR)
+}
+
+pub(crate) const fn days(value: Uncertain) -> Uncertain {
+    Uncertain::new(value.value * DAY, value.uncertainty * DAY)
+}
+
+pub(crate) const fn years(value: Uncertain) -> Uncertain {
+    Uncertain::new(value.value * YEAR, value.uncertainty * YEAR)
+}
+
 /// H isotopes
 #[derive(Clone, Copy, Debug, EnumIter, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -13,6 +44,41 @@ pub enum H {
 }
 
 impl H {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::One => &[],
+            Self::Two => &[],
+            Self::Three => &[(DecayMode::BetaMinus, 1.0)],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            1 => Some(Self::One),
+            2 => Some(Self::Two),
+            3 => Some(Self::Three),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::One => Some(STABLE),
+            Self::Two => Some(STABLE),
+            Self::Three => Some(years(uncertain!(12.32, 0.02))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -42,6 +108,16 @@ impl H {
             Self::Three => uncertain!(3.016_049_277_9, 0.000_000_002_4),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::One => Some(SpinParity::new(1, Parity::Positive)),
+            Self::Two => Some(SpinParity::new(2, Parity::Positive)),
+            Self::Three => Some(SpinParity::new(1, Parity::Positive)),
+        }
+    }
 }
 
 /// He isotopes
@@ -53,6 +129,38 @@ pub enum He {
 }
 
 impl He {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::Three => &[],
+            Self::Four => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            3 => Some(Self::Three),
+            4 => Some(Self::Four),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::Three => Some(STABLE),
+            Self::Four => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -79,6 +187,15 @@ impl He {
             Self::Four => uncertain!(4.002_603_254_13, 0.000_000_000_06),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::Three => Some(SpinParity::new(1, Parity::Positive)),
+            Self::Four => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Li isotopes
@@ -90,6 +207,38 @@ pub enum Li {
 }
 
 impl Li {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::Six => &[],
+            Self::Seven => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            6 => Some(Self::Six),
+            7 => Some(Self::Seven),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::Six => Some(STABLE),
+            Self::Seven => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -116,6 +265,15 @@ impl Li {
             Self::Seven => uncertain!(7.016_003_436_6, 0.000_000_004_5),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::Six => Some(SpinParity::new(2, Parity::Positive)),
+            Self::Seven => Some(SpinParity::new(3, Parity::Negative)),
+        }
+    }
 }
 
 /// Be isotope
@@ -126,6 +284,31 @@ pub enum Be {
 }
 
 impl Be {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            9 => Some(Self::Nine),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(STABLE)
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -143,6 +326,12 @@ impl Be {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(9.012_183_065, 0.000_000_082)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(3, Parity::Negative))
+    }
 }
 
 /// B isotopes
@@ -154,6 +343,38 @@ pub enum B {
 }
 
 impl B {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::Ten => &[],
+            Self::Eleven => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            10 => Some(Self::Ten),
+            11 => Some(Self::Eleven),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::Ten => Some(STABLE),
+            Self::Eleven => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -180,6 +401,15 @@ impl B {
             Self::Eleven => uncertain!(11.009_305_36, 0.000_000_45),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::Ten => Some(SpinParity::new(6, Parity::Positive)),
+            Self::Eleven => Some(SpinParity::new(3, Parity::Negative)),
+        }
+    }
 }
 
 /// C isotopes
@@ -192,6 +422,41 @@ pub enum C {
 }
 
 impl C {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::Twelve => &[],
+            Self::Thirteen => &[],
+            Self::Fourteen => &[(DecayMode::BetaMinus, 1.0)],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            12 => Some(Self::Twelve),
+            13 => Some(Self::Thirteen),
+            14 => Some(Self::Fourteen),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::Twelve => Some(STABLE),
+            Self::Thirteen => Some(STABLE),
+            Self::Fourteen => Some(years(uncertain!(5_700.0, 30.0))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -221,6 +486,16 @@ impl C {
             Self::Fourteen => uncertain!(14.003_241_988_4, 0.000_000_004_0),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::Twelve => Some(SpinParity::new(0, Parity::Positive)),
+            Self::Thirteen => Some(SpinParity::new(1, Parity::Negative)),
+            Self::Fourteen => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// N isotopes
@@ -232,6 +507,38 @@ pub enum N {
 }
 
 impl N {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::Fourteen => &[],
+            Self::Fifteen => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            14 => Some(Self::Fourteen),
+            15 => Some(Self::Fifteen),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::Fourteen => Some(STABLE),
+            Self::Fifteen => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -258,6 +565,15 @@ impl N {
             Self::Fifteen => uncertain!(15.000_108_898_88, 0.000_000_000_64),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::Fourteen => Some(SpinParity::new(2, Parity::Positive)),
+            Self::Fifteen => Some(SpinParity::new(1, Parity::Negative)),
+        }
+    }
 }
 
 /// O isotopes
@@ -270,6 +586,41 @@ pub enum O {
 }
 
 impl O {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::Sixteen => &[],
+            Self::Seventeen => &[],
+            Self::Eighteen => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            16 => Some(Self::Sixteen),
+            17 => Some(Self::Seventeen),
+            18 => Some(Self::Eighteen),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::Sixteen => Some(STABLE),
+            Self::Seventeen => Some(STABLE),
+            Self::Eighteen => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -299,6 +650,16 @@ impl O {
             Self::Eighteen => uncertain!(17.999_159_612_86, 0.000_000_000_76),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::Sixteen => Some(SpinParity::new(0, Parity::Positive)),
+            Self::Seventeen => Some(SpinParity::new(5, Parity::Positive)),
+            Self::Eighteen => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// F isotope
@@ -309,6 +670,31 @@ pub enum F {
 }
 
 impl F {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            19 => Some(Self::Nineteen),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(STABLE)
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -326,6 +712,12 @@ impl F {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(18.998_403_162_73, 0.000_000_000_92)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(1, Parity::Positive))
+    }
 }
 
 /// Ne isotopes
@@ -338,6 +730,41 @@ pub enum Ne {
 }
 
 impl Ne {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::Twenty => &[],
+            Self::TwentyOne => &[],
+            Self::TwentyTwo => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            20 => Some(Self::Twenty),
+            21 => Some(Self::TwentyOne),
+            22 => Some(Self::TwentyTwo),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::Twenty => Some(STABLE),
+            Self::TwentyOne => Some(STABLE),
+            Self::TwentyTwo => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -367,6 +794,16 @@ impl Ne {
             Self::TwentyTwo => uncertain!(21.991_385_114, 0.000_000_018),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::Twenty => Some(SpinParity::new(0, Parity::Positive)),
+            Self::TwentyOne => Some(SpinParity::new(3, Parity::Positive)),
+            Self::TwentyTwo => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Na isotope
@@ -377,6 +814,31 @@ pub enum Na {
 }
 
 impl Na {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            23 => Some(Self::TwentyThree),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(STABLE)
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -394,6 +856,12 @@ impl Na {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(22.989_769_282_0, 0.000_000_001_9)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(3, Parity::Positive))
+    }
 }
 
 /// Mg isotopes
@@ -406,6 +874,41 @@ pub enum Mg {
 }
 
 impl Mg {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::TwentyFour => &[],
+            Self::TwentyFive => &[],
+            Self::TwentySix => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            24 => Some(Self::TwentyFour),
+            25 => Some(Self::TwentyFive),
+            26 => Some(Self::TwentySix),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::TwentyFour => Some(STABLE),
+            Self::TwentyFive => Some(STABLE),
+            Self::TwentySix => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -435,6 +938,16 @@ impl Mg {
             Self::TwentySix => uncertain!(25.982_592_968, 0.000_000_031),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::TwentyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::TwentyFive => Some(SpinParity::new(5, Parity::Positive)),
+            Self::TwentySix => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Al isotope
@@ -445,6 +958,31 @@ pub enum Al {
 }
 
 impl Al {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            27 => Some(Self::TwentySeven),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(STABLE)
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -462,6 +1000,12 @@ impl Al {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(26.981_538_53, 0.000_000_11)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(5, Parity::Positive))
+    }
 }
 
 /// Si isotopes
@@ -474,6 +1018,41 @@ pub enum Si {
 }
 
 impl Si {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::TwentyEight => &[],
+            Self::TwentyNine => &[],
+            Self::Thirty => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            28 => Some(Self::TwentyEight),
+            29 => Some(Self::TwentyNine),
+            30 => Some(Self::Thirty),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::TwentyEight => Some(STABLE),
+            Self::TwentyNine => Some(STABLE),
+            Self::Thirty => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -503,6 +1082,16 @@ impl Si {
             Self::Thirty => uncertain!(29.973_770_136, 0.000_000_023),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::TwentyEight => Some(SpinParity::new(0, Parity::Positive)),
+            Self::TwentyNine => Some(SpinParity::new(1, Parity::Positive)),
+            Self::Thirty => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// P isotope
@@ -513,7 +1102,32 @@ pub enum P {
 }
 
 impl P {
-    /// Isotopic composition
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            31 => Some(Self::ThirtyOne),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(STABLE)
+    }
+
+    /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
         Some(uncertain!(1.0))
@@ -530,6 +1144,12 @@ impl P {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(30.973_761_998_42, 0.000_000_000_70)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(1, Parity::Positive))
+    }
 }
 
 /// S isotopes
@@ -543,6 +1163,44 @@ pub enum S {
 }
 
 impl S {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::ThirtyTwo => &[],
+            Self::ThirtyThree => &[],
+            Self::ThirtyFour => &[],
+            Self::ThirtySix => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            32 => Some(Self::ThirtyTwo),
+            33 => Some(Self::ThirtyThree),
+            34 => Some(Self::ThirtyFour),
+            36 => Some(Self::ThirtySix),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::ThirtyTwo => Some(STABLE),
+            Self::ThirtyThree => Some(STABLE),
+            Self::ThirtyFour => Some(STABLE),
+            Self::ThirtySix => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -575,6 +1233,17 @@ impl S {
             Self::ThirtySix => uncertain!(35.967_080_71, 0.000_000_20),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::ThirtyTwo => Some(SpinParity::new(0, Parity::Positive)),
+            Self::ThirtyThree => Some(SpinParity::new(3, Parity::Positive)),
+            Self::ThirtyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::ThirtySix => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Cl isotopes
@@ -586,6 +1255,38 @@ pub enum Cl {
 }
 
 impl Cl {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::ThirtyFive => &[],
+            Self::ThirtySeven => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            35 => Some(Self::ThirtyFive),
+            37 => Some(Self::ThirtySeven),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::ThirtyFive => Some(STABLE),
+            Self::ThirtySeven => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -612,6 +1313,15 @@ impl Cl {
             Self::ThirtySeven => uncertain!(36.965_902_602, 0.000_000_055),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::ThirtyFive => Some(SpinParity::new(3, Parity::Positive)),
+            Self::ThirtySeven => Some(SpinParity::new(3, Parity::Positive)),
+        }
+    }
 }
 
 /// Ar isotopes
@@ -624,6 +1334,41 @@ pub enum Ar {
 }
 
 impl Ar {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::ThirtySix => &[],
+            Self::ThirtyEight => &[],
+            Self::Forty => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            36 => Some(Self::ThirtySix),
+            38 => Some(Self::ThirtyEight),
+            40 => Some(Self::Forty),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::ThirtySix => Some(STABLE),
+            Self::ThirtyEight => Some(STABLE),
+            Self::Forty => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -653,6 +1398,16 @@ impl Ar {
             Self::Forty => uncertain!(39.962_383_123_7, 0.000_000_002_4),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::ThirtySix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::ThirtyEight => Some(SpinParity::new(0, Parity::Positive)),
+            Self::Forty => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// K isotopes
@@ -665,6 +1420,44 @@ pub enum K {
 }
 
 impl K {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::ThirtyNine => &[],
+            Self::Forty => &[
+                (DecayMode::BetaMinus, 0.892_8),
+                (DecayMode::ElectronCapture, 0.107_2),
+            ],
+            Self::FortyOne => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            39 => Some(Self::ThirtyNine),
+            40 => Some(Self::Forty),
+            41 => Some(Self::FortyOne),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::ThirtyNine => Some(STABLE),
+            Self::Forty => Some(years(uncertain!(1.248e9, 0.003e9))),
+            Self::FortyOne => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -694,6 +1487,16 @@ impl K {
             Self::FortyOne => uncertain!(40.961_825_257_9, 0.000_000_004_1),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::ThirtyNine => Some(SpinParity::new(3, Parity::Positive)),
+            Self::Forty => Some(SpinParity::new(8, Parity::Negative)),
+            Self::FortyOne => Some(SpinParity::new(3, Parity::Positive)),
+        }
+    }
 }
 
 /// Ca isotopes
@@ -709,6 +1512,50 @@ pub enum Ca {
 }
 
 impl Ca {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::Forty => &[],
+            Self::FortyTwo => &[],
+            Self::FortyThree => &[],
+            Self::FortyFour => &[],
+            Self::FortySix => &[],
+            Self::FortyEight => &[(DecayMode::DoubleBetaMinus, 1.0)],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            40 => Some(Self::Forty),
+            42 => Some(Self::FortyTwo),
+            43 => Some(Self::FortyThree),
+            44 => Some(Self::FortyFour),
+            46 => Some(Self::FortySix),
+            48 => Some(Self::FortyEight),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::Forty => Some(STABLE),
+            Self::FortyTwo => Some(STABLE),
+            Self::FortyThree => Some(STABLE),
+            Self::FortyFour => Some(STABLE),
+            Self::FortySix => Some(STABLE),
+            Self::FortyEight => Some(years(uncertain!(5.6e19, 1.0e19))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -747,6 +1594,19 @@ impl Ca {
             Self::FortyEight => uncertain!(47.952_522_76, 0.000_000_13),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::Forty => Some(SpinParity::new(0, Parity::Positive)),
+            Self::FortyTwo => Some(SpinParity::new(0, Parity::Positive)),
+            Self::FortyThree => Some(SpinParity::new(7, Parity::Negative)),
+            Self::FortyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::FortySix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::FortyEight => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Sc isotope
@@ -757,6 +1617,31 @@ pub enum Sc {
 }
 
 impl Sc {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            45 => Some(Self::FortyFive),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(STABLE)
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -774,6 +1659,12 @@ impl Sc {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(44.955_908_28, 0.000_000_77)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(7, Parity::Negative))
+    }
 }
 
 /// Ti isotopes
@@ -788,6 +1679,47 @@ pub enum Ti {
 }
 
 impl Ti {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::FortySix => &[],
+            Self::FortySeven => &[],
+            Self::FortyEight => &[],
+            Self::FortyNine => &[],
+            Self::Fifty => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            46 => Some(Self::FortySix),
+            47 => Some(Self::FortySeven),
+            48 => Some(Self::FortyEight),
+            49 => Some(Self::FortyNine),
+            50 => Some(Self::Fifty),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::FortySix => Some(STABLE),
+            Self::FortySeven => Some(STABLE),
+            Self::FortyEight => Some(STABLE),
+            Self::FortyNine => Some(STABLE),
+            Self::Fifty => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -823,6 +1755,18 @@ impl Ti {
             Self::Fifty => uncertain!(49.944_786_89, 0.000_000_39),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::FortySix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::FortySeven => Some(SpinParity::new(5, Parity::Negative)),
+            Self::FortyEight => Some(SpinParity::new(0, Parity::Positive)),
+            Self::FortyNine => Some(SpinParity::new(7, Parity::Negative)),
+            Self::Fifty => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// V isotopes
@@ -834,6 +1778,41 @@ pub enum V {
 }
 
 impl V {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::Fifty => &[
+                (DecayMode::ElectronCapture, 0.993),
+                (DecayMode::BetaMinus, 0.007),
+            ],
+            Self::FiftyOne => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            50 => Some(Self::Fifty),
+            51 => Some(Self::FiftyOne),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::Fifty => Some(years(uncertain!(2.67e17, 0.16e17))),
+            Self::FiftyOne => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -860,6 +1839,15 @@ impl V {
             Self::FiftyOne => uncertain!(50.943_957_04, 0.000_000_94),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::Fifty => Some(SpinParity::new(12, Parity::Positive)),
+            Self::FiftyOne => Some(SpinParity::new(7, Parity::Negative)),
+        }
+    }
 }
 
 /// Cr isotopes
@@ -873,6 +1861,44 @@ pub enum Cr {
 }
 
 impl Cr {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::Fifty => &[],
+            Self::FiftyTwo => &[],
+            Self::FiftyThree => &[],
+            Self::FiftyFour => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            50 => Some(Self::Fifty),
+            52 => Some(Self::FiftyTwo),
+            53 => Some(Self::FiftyThree),
+            54 => Some(Self::FiftyFour),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::Fifty => Some(STABLE),
+            Self::FiftyTwo => Some(STABLE),
+            Self::FiftyThree => Some(STABLE),
+            Self::FiftyFour => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -905,6 +1931,17 @@ impl Cr {
             Self::FiftyFour => uncertain!(53.938_879_16, 0.000_000_61),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::Fifty => Some(SpinParity::new(0, Parity::Positive)),
+            Self::FiftyTwo => Some(SpinParity::new(0, Parity::Positive)),
+            Self::FiftyThree => Some(SpinParity::new(3, Parity::Negative)),
+            Self::FiftyFour => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Mn isotope
@@ -915,6 +1952,31 @@ pub enum Mn {
 }
 
 impl Mn {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            55 => Some(Self::FiftyFive),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(STABLE)
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -932,6 +1994,12 @@ impl Mn {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(54.938_043_91, 0.000_000_48)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(5, Parity::Negative))
+    }
 }
 
 /// Fe isotopes
@@ -945,6 +2013,44 @@ pub enum Fe {
 }
 
 impl Fe {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::FiftyFour => &[],
+            Self::FiftySix => &[],
+            Self::FiftySeven => &[],
+            Self::FiftyEight => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            54 => Some(Self::FiftyFour),
+            56 => Some(Self::FiftySix),
+            57 => Some(Self::FiftySeven),
+            58 => Some(Self::FiftyEight),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::FiftyFour => Some(STABLE),
+            Self::FiftySix => Some(STABLE),
+            Self::FiftySeven => Some(STABLE),
+            Self::FiftyEight => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -977,6 +2083,17 @@ impl Fe {
             Self::FiftyEight => uncertain!(57.933_274_43, 0.000_000_53),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::FiftyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::FiftySix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::FiftySeven => Some(SpinParity::new(1, Parity::Negative)),
+            Self::FiftyEight => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Co isotope
@@ -987,6 +2104,31 @@ pub enum Co {
 }
 
 impl Co {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            59 => Some(Self::FiftyNine),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(STABLE)
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -1004,6 +2146,12 @@ impl Co {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(58.933_194_29, 0.000_000_56)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(7, Parity::Negative))
+    }
 }
 
 /// Ni isotopes
@@ -1018,6 +2166,47 @@ pub enum Ni {
 }
 
 impl Ni {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::FiftyEight => &[],
+            Self::Sixty => &[],
+            Self::SixtyOne => &[],
+            Self::SixtyTwo => &[],
+            Self::SixtyFour => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            58 => Some(Self::FiftyEight),
+            60 => Some(Self::Sixty),
+            61 => Some(Self::SixtyOne),
+            62 => Some(Self::SixtyTwo),
+            64 => Some(Self::SixtyFour),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::FiftyEight => Some(STABLE),
+            Self::Sixty => Some(STABLE),
+            Self::SixtyOne => Some(STABLE),
+            Self::SixtyTwo => Some(STABLE),
+            Self::SixtyFour => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -1053,6 +2242,18 @@ impl Ni {
             Self::SixtyFour => uncertain!(63.927_966_82, 0.000_000_58),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::FiftyEight => Some(SpinParity::new(0, Parity::Positive)),
+            Self::Sixty => Some(SpinParity::new(0, Parity::Positive)),
+            Self::SixtyOne => Some(SpinParity::new(3, Parity::Negative)),
+            Self::SixtyTwo => Some(SpinParity::new(0, Parity::Positive)),
+            Self::SixtyFour => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Cu isotopes
@@ -1064,30 +2265,71 @@ pub enum Cu {
 }
 
 impl Cu {
-    /// Isotopic composition
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
     #[must_use]
-    pub const fn isotopic_composition(&self) -> Option<Uncertain> {
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
         match self {
-            Self::SixtyThree => Some(uncertain!(0.691_5, 0.001_5)),
-            Self::SixtyFive => Some(uncertain!(0.308_5, 0.001_5)),
+            Self::SixtyThree => &[],
+            Self::SixtyFive => &[],
         }
     }
 
-    /// Mass number
+    /// Isotope with the mass number
     #[must_use]
-    pub const fn mass_number(&self) -> usize {
-        match self {
-            Self::SixtyThree => 63,
-            Self::SixtyFive => 65,
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            63 => Some(Self::SixtyThree),
+            65 => Some(Self::SixtyFive),
+            _ => None,
         }
     }
 
-    /// Relative Atomic Mass
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
     #[must_use]
-    pub const fn relative_atomic_mass(&self) -> Uncertain {
+    pub const fn half_life(&self) -> Option<Uncertain> {
         match self {
-            Self::SixtyThree => uncertain!(62.929_597_72, 0.000_000_56),
-            Self::SixtyFive => uncertain!(64.927_789_70, 0.000_000_71),
+            Self::SixtyThree => Some(STABLE),
+            Self::SixtyFive => Some(STABLE),
+        }
+    }
+
+    /// Isotopic composition
+    #[must_use]
+    pub const fn isotopic_composition(&self) -> Option<Uncertain> {
+        match self {
+            Self::SixtyThree => Some(uncertain!(0.691_5, 0.001_5)),
+            Self::SixtyFive => Some(uncertain!(0.308_5, 0.001_5)),
+        }
+    }
+
+    /// Mass number
+    #[must_use]
+    pub const fn mass_number(&self) -> usize {
+        match self {
+            Self::SixtyThree => 63,
+            Self::SixtyFive => 65,
+        }
+    }
+
+    /// Relative Atomic Mass
+    #[must_use]
+    pub const fn relative_atomic_mass(&self) -> Uncertain {
+        match self {
+            Self::SixtyThree => uncertain!(62.929_597_72, 0.000_000_56),
+            Self::SixtyFive => uncertain!(64.927_789_70, 0.000_000_71),
+        }
+    }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::SixtyThree => Some(SpinParity::new(3, Parity::Negative)),
+            Self::SixtyFive => Some(SpinParity::new(3, Parity::Negative)),
         }
     }
 }
@@ -1104,6 +2346,47 @@ pub enum Zn {
 }
 
 impl Zn {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::SixtyFour => &[],
+            Self::SixtySix => &[],
+            Self::SixtySeven => &[],
+            Self::SixtyEight => &[],
+            Self::Seventy => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            64 => Some(Self::SixtyFour),
+            66 => Some(Self::SixtySix),
+            67 => Some(Self::SixtySeven),
+            68 => Some(Self::SixtyEight),
+            70 => Some(Self::Seventy),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::SixtyFour => Some(STABLE),
+            Self::SixtySix => Some(STABLE),
+            Self::SixtySeven => Some(STABLE),
+            Self::SixtyEight => Some(STABLE),
+            Self::Seventy => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -1139,6 +2422,18 @@ impl Zn {
             Self::Seventy => uncertain!(69.925_319_2, 0.000_002_1),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::SixtyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::SixtySix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::SixtySeven => Some(SpinParity::new(5, Parity::Negative)),
+            Self::SixtyEight => Some(SpinParity::new(0, Parity::Positive)),
+            Self::Seventy => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Ga isotopes
@@ -1150,6 +2445,38 @@ pub enum Ga {
 }
 
 impl Ga {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::SixtyNine => &[],
+            Self::SeventyOne => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            69 => Some(Self::SixtyNine),
+            71 => Some(Self::SeventyOne),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::SixtyNine => Some(STABLE),
+            Self::SeventyOne => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -1176,6 +2503,15 @@ impl Ga {
             Self::SeventyOne => uncertain!(70.924_702_58, 0.000_000_87),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::SixtyNine => Some(SpinParity::new(3, Parity::Negative)),
+            Self::SeventyOne => Some(SpinParity::new(3, Parity::Negative)),
+        }
+    }
 }
 
 /// Ge isotopes
@@ -1190,6 +2526,47 @@ pub enum Ge {
 }
 
 impl Ge {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::Seventy => &[],
+            Self::SeventyTwo => &[],
+            Self::SeventyThree => &[],
+            Self::SeventyFour => &[],
+            Self::SeventySix => &[(DecayMode::DoubleBetaMinus, 1.0)],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            70 => Some(Self::Seventy),
+            72 => Some(Self::SeventyTwo),
+            73 => Some(Self::SeventyThree),
+            74 => Some(Self::SeventyFour),
+            76 => Some(Self::SeventySix),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::Seventy => Some(STABLE),
+            Self::SeventyTwo => Some(STABLE),
+            Self::SeventyThree => Some(STABLE),
+            Self::SeventyFour => Some(STABLE),
+            Self::SeventySix => Some(years(uncertain!(2.022e21, 0.018e21))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -1225,6 +2602,18 @@ impl Ge {
             Self::SeventySix => uncertain!(75.921_402_726, 0.000_000_019),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::Seventy => Some(SpinParity::new(0, Parity::Positive)),
+            Self::SeventyTwo => Some(SpinParity::new(0, Parity::Positive)),
+            Self::SeventyThree => Some(SpinParity::new(9, Parity::Positive)),
+            Self::SeventyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::SeventySix => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// As isotope
@@ -1235,6 +2624,31 @@ pub enum As {
 }
 
 impl As {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            75 => Some(Self::SeventyFive),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(STABLE)
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -1252,6 +2666,12 @@ impl As {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(74.921_594_57, 0.000_000_95)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(3, Parity::Negative))
+    }
 }
 
 /// Se isotopes
@@ -1267,6 +2687,50 @@ pub enum Se {
 }
 
 impl Se {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::SeventyFour => &[],
+            Self::SeventySix => &[],
+            Self::SeventySeven => &[],
+            Self::SeventyEight => &[],
+            Self::Eighty => &[],
+            Self::EightyTwo => &[(DecayMode::DoubleBetaMinus, 1.0)],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            74 => Some(Self::SeventyFour),
+            76 => Some(Self::SeventySix),
+            77 => Some(Self::SeventySeven),
+            78 => Some(Self::SeventyEight),
+            80 => Some(Self::Eighty),
+            82 => Some(Self::EightyTwo),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::SeventyFour => Some(STABLE),
+            Self::SeventySix => Some(STABLE),
+            Self::SeventySeven => Some(STABLE),
+            Self::SeventyEight => Some(STABLE),
+            Self::Eighty => Some(STABLE),
+            Self::EightyTwo => Some(years(uncertain!(8.76e19, 0.15e19))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -1305,6 +2769,19 @@ impl Se {
             Self::EightyTwo => uncertain!(81.916_699_5, 0.000_001_5),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::SeventyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::SeventySix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::SeventySeven => Some(SpinParity::new(1, Parity::Negative)),
+            Self::SeventyEight => Some(SpinParity::new(0, Parity::Positive)),
+            Self::Eighty => Some(SpinParity::new(0, Parity::Positive)),
+            Self::EightyTwo => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Br isotopes
@@ -1316,6 +2793,38 @@ pub enum Br {
 }
 
 impl Br {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::SeventyNine => &[],
+            Self::EightyOne => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            79 => Some(Self::SeventyNine),
+            81 => Some(Self::EightyOne),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::SeventyNine => Some(STABLE),
+            Self::EightyOne => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -1342,6 +2851,15 @@ impl Br {
             Self::EightyOne => uncertain!(80.916_289_7, 0.000_001_4),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::SeventyNine => Some(SpinParity::new(3, Parity::Negative)),
+            Self::EightyOne => Some(SpinParity::new(3, Parity::Negative)),
+        }
+    }
 }
 
 /// Kr isotopes
@@ -1357,6 +2875,50 @@ pub enum Kr {
 }
 
 impl Kr {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::SeventyEight => &[(DecayMode::DoubleElectronCapture, 1.0)],
+            Self::Eighty => &[],
+            Self::EightyTwo => &[],
+            Self::EightyThree => &[],
+            Self::EightyFour => &[],
+            Self::EightySix => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            78 => Some(Self::SeventyEight),
+            80 => Some(Self::Eighty),
+            82 => Some(Self::EightyTwo),
+            83 => Some(Self::EightyThree),
+            84 => Some(Self::EightyFour),
+            86 => Some(Self::EightySix),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::SeventyEight => Some(years(uncertain!(1.9e22, 1.3e22))),
+            Self::Eighty => Some(STABLE),
+            Self::EightyTwo => Some(STABLE),
+            Self::EightyThree => Some(STABLE),
+            Self::EightyFour => Some(STABLE),
+            Self::EightySix => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -1395,6 +2957,19 @@ impl Kr {
             Self::EightySix => uncertain!(85.910_610_626_9, 0.000_000_004_1),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::SeventyEight => Some(SpinParity::new(0, Parity::Positive)),
+            Self::Eighty => Some(SpinParity::new(0, Parity::Positive)),
+            Self::EightyTwo => Some(SpinParity::new(0, Parity::Positive)),
+            Self::EightyThree => Some(SpinParity::new(9, Parity::Positive)),
+            Self::EightyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::EightySix => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Rb isotopes
@@ -1406,6 +2981,38 @@ pub enum Rb {
 }
 
 impl Rb {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::EightyFive => &[],
+            Self::EightySeven => &[(DecayMode::BetaMinus, 1.0)],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            85 => Some(Self::EightyFive),
+            87 => Some(Self::EightySeven),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::EightyFive => Some(STABLE),
+            Self::EightySeven => Some(years(uncertain!(4.97e10, 0.03e10))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -1432,6 +3039,15 @@ impl Rb {
             Self::EightySeven => uncertain!(86.909_180_531_0, 0.000_000_006_0),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::EightyFive => Some(SpinParity::new(5, Parity::Negative)),
+            Self::EightySeven => Some(SpinParity::new(3, Parity::Negative)),
+        }
+    }
 }
 
 /// Sr isotopes
@@ -1445,6 +3061,44 @@ pub enum Sr {
 }
 
 impl Sr {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::EightyFour => &[],
+            Self::EightySix => &[],
+            Self::EightySeven => &[],
+            Self::EightyEight => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            84 => Some(Self::EightyFour),
+            86 => Some(Self::EightySix),
+            87 => Some(Self::EightySeven),
+            88 => Some(Self::EightyEight),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::EightyFour => Some(STABLE),
+            Self::EightySix => Some(STABLE),
+            Self::EightySeven => Some(STABLE),
+            Self::EightyEight => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -1477,6 +3131,17 @@ impl Sr {
             Self::EightyEight => uncertain!(87.905_612_5, 0.000_001_2),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::EightyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::EightySix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::EightySeven => Some(SpinParity::new(9, Parity::Positive)),
+            Self::EightyEight => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Y isotope
@@ -1487,6 +3152,31 @@ pub enum Y {
 }
 
 impl Y {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            89 => Some(Self::EightyNine),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(STABLE)
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -1504,6 +3194,12 @@ impl Y {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(88.905_840_3, 0.000_002_4)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(1, Parity::Negative))
+    }
 }
 
 /// Zr isotopes
@@ -1518,6 +3214,47 @@ pub enum Zr {
 }
 
 impl Zr {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::Ninety => &[],
+            Self::NinetyOne => &[],
+            Self::NinetyTwo => &[],
+            Self::NinetyFour => &[],
+            Self::NinetySix => &[(DecayMode::DoubleBetaMinus, 1.0)],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            90 => Some(Self::Ninety),
+            91 => Some(Self::NinetyOne),
+            92 => Some(Self::NinetyTwo),
+            94 => Some(Self::NinetyFour),
+            96 => Some(Self::NinetySix),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::Ninety => Some(STABLE),
+            Self::NinetyOne => Some(STABLE),
+            Self::NinetyTwo => Some(STABLE),
+            Self::NinetyFour => Some(STABLE),
+            Self::NinetySix => Some(years(uncertain!(2.35e19, 0.21e19))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -1553,6 +3290,18 @@ impl Zr {
             Self::NinetySix => uncertain!(95.908_271_4, 0.000_002_1),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::Ninety => Some(SpinParity::new(0, Parity::Positive)),
+            Self::NinetyOne => Some(SpinParity::new(5, Parity::Positive)),
+            Self::NinetyTwo => Some(SpinParity::new(0, Parity::Positive)),
+            Self::NinetyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::NinetySix => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Nb isotope
@@ -1563,6 +3312,31 @@ pub enum Nb {
 }
 
 impl Nb {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            93 => Some(Self::NinetyThree),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(STABLE)
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -1580,6 +3354,12 @@ impl Nb {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(92.906_373_0, 0.000_002_0)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(9, Parity::Positive))
+    }
 }
 
 /// Mo isotopes
@@ -1596,6 +3376,53 @@ pub enum Mo {
 }
 
 impl Mo {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::NinetyTwo => &[],
+            Self::NinetyFour => &[],
+            Self::NinetyFive => &[],
+            Self::NinetySix => &[],
+            Self::NinetySeven => &[],
+            Self::NinetyEight => &[],
+            Self::OneHundred => &[(DecayMode::DoubleBetaMinus, 1.0)],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            92 => Some(Self::NinetyTwo),
+            94 => Some(Self::NinetyFour),
+            95 => Some(Self::NinetyFive),
+            96 => Some(Self::NinetySix),
+            97 => Some(Self::NinetySeven),
+            98 => Some(Self::NinetyEight),
+            100 => Some(Self::OneHundred),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::NinetyTwo => Some(STABLE),
+            Self::NinetyFour => Some(STABLE),
+            Self::NinetyFive => Some(STABLE),
+            Self::NinetySix => Some(STABLE),
+            Self::NinetySeven => Some(STABLE),
+            Self::NinetyEight => Some(STABLE),
+            Self::OneHundred => Some(years(uncertain!(7.07e18, 0.14e18))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -1637,6 +3464,20 @@ impl Mo {
             Self::OneHundred => uncertain!(99.907_471_8, 0.000_001_1),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::NinetyTwo => Some(SpinParity::new(0, Parity::Positive)),
+            Self::NinetyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::NinetyFive => Some(SpinParity::new(5, Parity::Positive)),
+            Self::NinetySix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::NinetySeven => Some(SpinParity::new(5, Parity::Positive)),
+            Self::NinetyEight => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundred => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Tc isotopes
@@ -1649,6 +3490,41 @@ pub enum Tc {
 }
 
 impl Tc {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::NinetySeven => &[(DecayMode::ElectronCapture, 1.0)],
+            Self::NinetyEight => &[(DecayMode::BetaMinus, 1.0)],
+            Self::NinetyNine => &[(DecayMode::BetaMinus, 1.0)],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            97 => Some(Self::NinetySeven),
+            98 => Some(Self::NinetyEight),
+            99 => Some(Self::NinetyNine),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::NinetySeven => Some(years(uncertain!(4.21e6, 0.16e6))),
+            Self::NinetyEight => Some(years(uncertain!(4.2e6, 0.3e6))),
+            Self::NinetyNine => Some(years(uncertain!(2.111e5, 0.012e5))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -1678,6 +3554,16 @@ impl Tc {
             Self::NinetyNine => uncertain!(98.906_250_8, 0.000_001_0),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::NinetySeven => Some(SpinParity::new(9, Parity::Positive)),
+            Self::NinetyEight => Some(SpinParity::new(12, Parity::Positive)),
+            Self::NinetyNine => Some(SpinParity::new(9, Parity::Positive)),
+        }
+    }
 }
 
 /// Ru isotopes
@@ -1694,6 +3580,53 @@ pub enum Ru {
 }
 
 impl Ru {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::NinetySix => &[],
+            Self::NinetyEight => &[],
+            Self::NinetyNine => &[],
+            Self::OneHundred => &[],
+            Self::OneHundredOne => &[],
+            Self::OneHundredTwo => &[],
+            Self::OneHundredFour => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            96 => Some(Self::NinetySix),
+            98 => Some(Self::NinetyEight),
+            99 => Some(Self::NinetyNine),
+            100 => Some(Self::OneHundred),
+            101 => Some(Self::OneHundredOne),
+            102 => Some(Self::OneHundredTwo),
+            104 => Some(Self::OneHundredFour),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::NinetySix => Some(STABLE),
+            Self::NinetyEight => Some(STABLE),
+            Self::NinetyNine => Some(STABLE),
+            Self::OneHundred => Some(STABLE),
+            Self::OneHundredOne => Some(STABLE),
+            Self::OneHundredTwo => Some(STABLE),
+            Self::OneHundredFour => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -1735,6 +3668,20 @@ impl Ru {
             Self::OneHundredFour => uncertain!(103.905_427_5, 0.000_002_8),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::NinetySix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::NinetyEight => Some(SpinParity::new(0, Parity::Positive)),
+            Self::NinetyNine => Some(SpinParity::new(5, Parity::Positive)),
+            Self::OneHundred => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredOne => Some(SpinParity::new(5, Parity::Positive)),
+            Self::OneHundredTwo => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredFour => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Rh isotope
@@ -1745,6 +3692,31 @@ pub enum Rh {
 }
 
 impl Rh {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            103 => Some(Self::OneHundredThree),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(STABLE)
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -1762,6 +3734,12 @@ impl Rh {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(102.905_498_0, 0.000_002_6)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(1, Parity::Negative))
+    }
 }
 
 /// Pd isotopes
@@ -1777,6 +3755,50 @@ pub enum Pd {
 }
 
 impl Pd {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredTwo => &[],
+            Self::OneHundredFour => &[],
+            Self::OneHundredFive => &[],
+            Self::OneHundredSix => &[],
+            Self::OneHundredEight => &[],
+            Self::OneHundredTen => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            102 => Some(Self::OneHundredTwo),
+            104 => Some(Self::OneHundredFour),
+            105 => Some(Self::OneHundredFive),
+            106 => Some(Self::OneHundredSix),
+            108 => Some(Self::OneHundredEight),
+            110 => Some(Self::OneHundredTen),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredTwo => Some(STABLE),
+            Self::OneHundredFour => Some(STABLE),
+            Self::OneHundredFive => Some(STABLE),
+            Self::OneHundredSix => Some(STABLE),
+            Self::OneHundredEight => Some(STABLE),
+            Self::OneHundredTen => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -1815,6 +3837,19 @@ impl Pd {
             Self::OneHundredTen => uncertain!(109.905_172_20, 0.000_000_75),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredTwo => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredFive => Some(SpinParity::new(5, Parity::Positive)),
+            Self::OneHundredSix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredEight => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredTen => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Ag isotopes
@@ -1826,6 +3861,38 @@ pub enum Ag {
 }
 
 impl Ag {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredSeven => &[],
+            Self::OneHundredNine => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            107 => Some(Self::OneHundredSeven),
+            109 => Some(Self::OneHundredNine),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredSeven => Some(STABLE),
+            Self::OneHundredNine => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -1852,6 +3919,15 @@ impl Ag {
             Self::OneHundredNine => uncertain!(108.904_755_3, 0.000_001_4),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredSeven => Some(SpinParity::new(1, Parity::Negative)),
+            Self::OneHundredNine => Some(SpinParity::new(1, Parity::Negative)),
+        }
+    }
 }
 
 /// Cd isotopes
@@ -1869,6 +3945,56 @@ pub enum Cd {
 }
 
 impl Cd {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredSix => &[],
+            Self::OneHundredEight => &[],
+            Self::OneHundredTen => &[],
+            Self::OneHundredEleven => &[],
+            Self::OneHundredTwelve => &[],
+            Self::OneHundredThirteen => &[(DecayMode::BetaMinus, 1.0)],
+            Self::OneHundredFourteen => &[],
+            Self::OneHundredSixteen => &[(DecayMode::DoubleBetaMinus, 1.0)],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            106 => Some(Self::OneHundredSix),
+            108 => Some(Self::OneHundredEight),
+            110 => Some(Self::OneHundredTen),
+            111 => Some(Self::OneHundredEleven),
+            112 => Some(Self::OneHundredTwelve),
+            113 => Some(Self::OneHundredThirteen),
+            114 => Some(Self::OneHundredFourteen),
+            116 => Some(Self::OneHundredSixteen),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredSix => Some(STABLE),
+            Self::OneHundredEight => Some(STABLE),
+            Self::OneHundredTen => Some(STABLE),
+            Self::OneHundredEleven => Some(STABLE),
+            Self::OneHundredTwelve => Some(STABLE),
+            Self::OneHundredThirteen => Some(years(uncertain!(8.04e15, 0.05e15))),
+            Self::OneHundredFourteen => Some(STABLE),
+            Self::OneHundredSixteen => Some(years(uncertain!(2.69e19, 0.09e19))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -1913,6 +4039,21 @@ impl Cd {
             Self::OneHundredSixteen => uncertain!(115.904_763_15, 0.000_000_17),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredSix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredEight => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredTen => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredEleven => Some(SpinParity::new(1, Parity::Positive)),
+            Self::OneHundredTwelve => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredThirteen => Some(SpinParity::new(1, Parity::Positive)),
+            Self::OneHundredFourteen => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredSixteen => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// In isotopes
@@ -1924,6 +4065,38 @@ pub enum In {
 }
 
 impl In {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredThirteen => &[],
+            Self::OneHundredFifteen => &[(DecayMode::BetaMinus, 1.0)],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            113 => Some(Self::OneHundredThirteen),
+            115 => Some(Self::OneHundredFifteen),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredThirteen => Some(STABLE),
+            Self::OneHundredFifteen => Some(years(uncertain!(4.41e14, 0.22e14))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -1950,6 +4123,15 @@ impl In {
             Self::OneHundredFifteen => uncertain!(114.903_878_776, 0.000_000_012),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredThirteen => Some(SpinParity::new(9, Parity::Positive)),
+            Self::OneHundredFifteen => Some(SpinParity::new(9, Parity::Positive)),
+        }
+    }
 }
 
 /// Sn isotopes
@@ -1969,6 +4151,62 @@ pub enum Sn {
 }
 
 impl Sn {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredTwelve => &[],
+            Self::OneHundredFourteen => &[],
+            Self::OneHundredFifteen => &[],
+            Self::OneHundredSixteen => &[],
+            Self::OneHundredSeventeen => &[],
+            Self::OneHundredEighteen => &[],
+            Self::OneHundredNineteen => &[],
+            Self::OneHundredTwenty => &[],
+            Self::OneHundredTwentyTwo => &[],
+            Self::OneHundredTwentyFour => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            112 => Some(Self::OneHundredTwelve),
+            114 => Some(Self::OneHundredFourteen),
+            115 => Some(Self::OneHundredFifteen),
+            116 => Some(Self::OneHundredSixteen),
+            117 => Some(Self::OneHundredSeventeen),
+            118 => Some(Self::OneHundredEighteen),
+            119 => Some(Self::OneHundredNineteen),
+            120 => Some(Self::OneHundredTwenty),
+            122 => Some(Self::OneHundredTwentyTwo),
+            124 => Some(Self::OneHundredTwentyFour),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredTwelve => Some(STABLE),
+            Self::OneHundredFourteen => Some(STABLE),
+            Self::OneHundredFifteen => Some(STABLE),
+            Self::OneHundredSixteen => Some(STABLE),
+            Self::OneHundredSeventeen => Some(STABLE),
+            Self::OneHundredEighteen => Some(STABLE),
+            Self::OneHundredNineteen => Some(STABLE),
+            Self::OneHundredTwenty => Some(STABLE),
+            Self::OneHundredTwentyTwo => Some(STABLE),
+            Self::OneHundredTwentyFour => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -2019,6 +4257,23 @@ impl Sn {
             Self::OneHundredTwentyFour => uncertain!(123.905_276_6, 0.000_001_1),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredTwelve => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredFourteen => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredFifteen => Some(SpinParity::new(1, Parity::Positive)),
+            Self::OneHundredSixteen => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredSeventeen => Some(SpinParity::new(1, Parity::Positive)),
+            Self::OneHundredEighteen => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredNineteen => Some(SpinParity::new(1, Parity::Positive)),
+            Self::OneHundredTwenty => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredTwentyTwo => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredTwentyFour => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Sb isotopes
@@ -2030,6 +4285,38 @@ pub enum Sb {
 }
 
 impl Sb {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredTwentyOne => &[],
+            Self::OneHundredTwentyThree => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            121 => Some(Self::OneHundredTwentyOne),
+            123 => Some(Self::OneHundredTwentyThree),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredTwentyOne => Some(STABLE),
+            Self::OneHundredTwentyThree => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -2056,6 +4343,15 @@ impl Sb {
             Self::OneHundredTwentyThree => uncertain!(122.904_213_2, 0.000_002_3),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredTwentyOne => Some(SpinParity::new(5, Parity::Positive)),
+            Self::OneHundredTwentyThree => Some(SpinParity::new(7, Parity::Positive)),
+        }
+    }
 }
 
 /// Te isotopes
@@ -2073,6 +4369,56 @@ pub enum Te {
 }
 
 impl Te {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredTwenty => &[],
+            Self::OneHundredTwentyTwo => &[],
+            Self::OneHundredTwentyThree => &[],
+            Self::OneHundredTwentyFour => &[],
+            Self::OneHundredTwentyFive => &[],
+            Self::OneHundredTwentySix => &[],
+            Self::OneHundredTwentyEight => &[(DecayMode::DoubleBetaMinus, 1.0)],
+            Self::OneHundredThirty => &[(DecayMode::DoubleBetaMinus, 1.0)],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            120 => Some(Self::OneHundredTwenty),
+            122 => Some(Self::OneHundredTwentyTwo),
+            123 => Some(Self::OneHundredTwentyThree),
+            124 => Some(Self::OneHundredTwentyFour),
+            125 => Some(Self::OneHundredTwentyFive),
+            126 => Some(Self::OneHundredTwentySix),
+            128 => Some(Self::OneHundredTwentyEight),
+            130 => Some(Self::OneHundredThirty),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredTwenty => Some(STABLE),
+            Self::OneHundredTwentyTwo => Some(STABLE),
+            Self::OneHundredTwentyThree => Some(STABLE),
+            Self::OneHundredTwentyFour => Some(STABLE),
+            Self::OneHundredTwentyFive => Some(STABLE),
+            Self::OneHundredTwentySix => Some(STABLE),
+            Self::OneHundredTwentyEight => Some(years(uncertain!(2.25e24, 0.09e24))),
+            Self::OneHundredThirty => Some(years(uncertain!(7.91e20, 0.21e20))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -2117,6 +4463,21 @@ impl Te {
             Self::OneHundredThirty => uncertain!(129.906_222_748, 0.000_000_012),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredTwenty => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredTwentyTwo => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredTwentyThree => Some(SpinParity::new(1, Parity::Positive)),
+            Self::OneHundredTwentyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredTwentyFive => Some(SpinParity::new(1, Parity::Positive)),
+            Self::OneHundredTwentySix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredTwentyEight => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredThirty => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// I isotope
@@ -2127,6 +4488,31 @@ pub enum I {
 }
 
 impl I {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            127 => Some(Self::OneHundredTwentySeven),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(STABLE)
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -2144,6 +4530,12 @@ impl I {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(126.904_471_9, 0.000_003_9)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(5, Parity::Positive))
+    }
 }
 
 /// Xe isotopes
@@ -2162,6 +4554,59 @@ pub enum Xe {
 }
 
 impl Xe {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredTwentyFour => &[(DecayMode::DoubleElectronCapture, 1.0)],
+            Self::OneHundredTwentySix => &[],
+            Self::OneHundredTwentyEight => &[],
+            Self::OneHundredTwentyNine => &[],
+            Self::OneHundredThirty => &[],
+            Self::OneHundredThirtyOne => &[],
+            Self::OneHundredThirtyTwo => &[],
+            Self::OneHundredThirtyFour => &[],
+            Self::OneHundredThirtySix => &[(DecayMode::DoubleBetaMinus, 1.0)],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            124 => Some(Self::OneHundredTwentyFour),
+            126 => Some(Self::OneHundredTwentySix),
+            128 => Some(Self::OneHundredTwentyEight),
+            129 => Some(Self::OneHundredTwentyNine),
+            130 => Some(Self::OneHundredThirty),
+            131 => Some(Self::OneHundredThirtyOne),
+            132 => Some(Self::OneHundredThirtyTwo),
+            134 => Some(Self::OneHundredThirtyFour),
+            136 => Some(Self::OneHundredThirtySix),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredTwentyFour => Some(years(uncertain!(1.8e22, 0.5e22))),
+            Self::OneHundredTwentySix => Some(STABLE),
+            Self::OneHundredTwentyEight => Some(STABLE),
+            Self::OneHundredTwentyNine => Some(STABLE),
+            Self::OneHundredThirty => Some(STABLE),
+            Self::OneHundredThirtyOne => Some(STABLE),
+            Self::OneHundredThirtyTwo => Some(STABLE),
+            Self::OneHundredThirtyFour => Some(STABLE),
+            Self::OneHundredThirtySix => Some(years(uncertain!(2.18e21, 0.05e21))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -2209,6 +4654,22 @@ impl Xe {
             Self::OneHundredThirtySix => uncertain!(135.907_214_484, 0.000_000_011),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredTwentyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredTwentySix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredTwentyEight => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredTwentyNine => Some(SpinParity::new(1, Parity::Positive)),
+            Self::OneHundredThirty => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredThirtyOne => Some(SpinParity::new(3, Parity::Positive)),
+            Self::OneHundredThirtyTwo => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredThirtyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredThirtySix => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Cs isotope
@@ -2219,6 +4680,31 @@ pub enum Cs {
 }
 
 impl Cs {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            133 => Some(Self::OneHundredThirtyThree),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(STABLE)
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -2236,6 +4722,12 @@ impl Cs {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(132.905_451_961_0, 0.000_000_008_0)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(7, Parity::Positive))
+    }
 }
 
 /// Ba isotopes
@@ -2252,6 +4744,53 @@ pub enum Ba {
 }
 
 impl Ba {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredThirty => &[(DecayMode::DoubleElectronCapture, 1.0)],
+            Self::OneHundredThirtyTwo => &[],
+            Self::OneHundredThirtyFour => &[],
+            Self::OneHundredThirtyFive => &[],
+            Self::OneHundredThirtySix => &[],
+            Self::OneHundredThirtySeven => &[],
+            Self::OneHundredThirtyEight => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            130 => Some(Self::OneHundredThirty),
+            132 => Some(Self::OneHundredThirtyTwo),
+            134 => Some(Self::OneHundredThirtyFour),
+            135 => Some(Self::OneHundredThirtyFive),
+            136 => Some(Self::OneHundredThirtySix),
+            137 => Some(Self::OneHundredThirtySeven),
+            138 => Some(Self::OneHundredThirtyEight),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredThirty => Some(years(uncertain!(2.2e21, 0.5e21))),
+            Self::OneHundredThirtyTwo => Some(STABLE),
+            Self::OneHundredThirtyFour => Some(STABLE),
+            Self::OneHundredThirtyFive => Some(STABLE),
+            Self::OneHundredThirtySix => Some(STABLE),
+            Self::OneHundredThirtySeven => Some(STABLE),
+            Self::OneHundredThirtyEight => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -2293,6 +4832,20 @@ impl Ba {
             Self::OneHundredThirtyEight => uncertain!(137.905_247_00, 0.000_000_31),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredThirty => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredThirtyTwo => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredThirtyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredThirtyFive => Some(SpinParity::new(3, Parity::Positive)),
+            Self::OneHundredThirtySix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredThirtySeven => Some(SpinParity::new(3, Parity::Positive)),
+            Self::OneHundredThirtyEight => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// La isotopes
@@ -2304,11 +4857,46 @@ pub enum La {
 }
 
 impl La {
-    /// Isotopic composition
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
     #[must_use]
-    pub const fn isotopic_composition(&self) -> Option<Uncertain> {
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
         match self {
-            Self::OneHundredThirtyEight => Some(uncertain!(0.000_888_1, 0.000_007_1)),
+            Self::OneHundredThirtyEight => &[
+                (DecayMode::ElectronCapture, 0.656),
+                (DecayMode::BetaMinus, 0.344),
+            ],
+            Self::OneHundredThirtyNine => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            138 => Some(Self::OneHundredThirtyEight),
+            139 => Some(Self::OneHundredThirtyNine),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredThirtyEight => Some(years(uncertain!(1.02e11, 0.01e11))),
+            Self::OneHundredThirtyNine => Some(STABLE),
+        }
+    }
+
+    /// Isotopic composition
+    #[must_use]
+    pub const fn isotopic_composition(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredThirtyEight => Some(uncertain!(0.000_888_1, 0.000_007_1)),
             Self::OneHundredThirtyNine => Some(uncertain!(0.999_111_9, 0.000_007_1)),
         }
     }
@@ -2330,6 +4918,15 @@ impl La {
             Self::OneHundredThirtyNine => uncertain!(138.906_356_3, 0.000_002_4),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredThirtyEight => Some(SpinParity::new(10, Parity::Positive)),
+            Self::OneHundredThirtyNine => Some(SpinParity::new(7, Parity::Positive)),
+        }
+    }
 }
 
 /// Ce isotopes
@@ -2343,6 +4940,44 @@ pub enum Ce {
 }
 
 impl Ce {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredThirtySix => &[],
+            Self::OneHundredThirtyEight => &[],
+            Self::OneHundredForty => &[],
+            Self::OneHundredFortyTwo => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            136 => Some(Self::OneHundredThirtySix),
+            138 => Some(Self::OneHundredThirtyEight),
+            140 => Some(Self::OneHundredForty),
+            142 => Some(Self::OneHundredFortyTwo),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredThirtySix => Some(STABLE),
+            Self::OneHundredThirtyEight => Some(STABLE),
+            Self::OneHundredForty => Some(STABLE),
+            Self::OneHundredFortyTwo => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -2375,6 +5010,17 @@ impl Ce {
             Self::OneHundredFortyTwo => uncertain!(141.909_250_4, 0.000_002_9),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredThirtySix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredThirtyEight => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredForty => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredFortyTwo => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Pr isotope
@@ -2385,6 +5031,31 @@ pub enum Pr {
 }
 
 impl Pr {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            141 => Some(Self::OneHundredFortyOne),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(STABLE)
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -2402,6 +5073,12 @@ impl Pr {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(140.907_657_6, 0.000_002_3)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(5, Parity::Positive))
+    }
 }
 
 /// Nd isotopes
@@ -2418,6 +5095,53 @@ pub enum Nd {
 }
 
 impl Nd {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredFortyTwo => &[],
+            Self::OneHundredFortyThree => &[],
+            Self::OneHundredFortyFour => &[(DecayMode::Alpha, 1.0)],
+            Self::OneHundredFortyFive => &[],
+            Self::OneHundredFortySix => &[],
+            Self::OneHundredFortyEight => &[],
+            Self::OneHundredFifty => &[(DecayMode::DoubleBetaMinus, 1.0)],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            142 => Some(Self::OneHundredFortyTwo),
+            143 => Some(Self::OneHundredFortyThree),
+            144 => Some(Self::OneHundredFortyFour),
+            145 => Some(Self::OneHundredFortyFive),
+            146 => Some(Self::OneHundredFortySix),
+            148 => Some(Self::OneHundredFortyEight),
+            150 => Some(Self::OneHundredFifty),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredFortyTwo => Some(STABLE),
+            Self::OneHundredFortyThree => Some(STABLE),
+            Self::OneHundredFortyFour => Some(years(uncertain!(2.29e15, 0.16e15))),
+            Self::OneHundredFortyFive => Some(STABLE),
+            Self::OneHundredFortySix => Some(STABLE),
+            Self::OneHundredFortyEight => Some(STABLE),
+            Self::OneHundredFifty => Some(years(uncertain!(9.3e18, 0.7e18))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -2459,6 +5183,20 @@ impl Nd {
             Self::OneHundredFifty => uncertain!(149.920_902_2, 0.000_001_8),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredFortyTwo => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredFortyThree => Some(SpinParity::new(7, Parity::Negative)),
+            Self::OneHundredFortyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredFortyFive => Some(SpinParity::new(7, Parity::Negative)),
+            Self::OneHundredFortySix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredFortyEight => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredFifty => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Pm isotopes
@@ -2470,6 +5208,38 @@ pub enum Pm {
 }
 
 impl Pm {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredFortyFive => &[(DecayMode::ElectronCapture, 1.0)],
+            Self::OneHundredFortySeven => &[(DecayMode::BetaMinus, 1.0)],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            145 => Some(Self::OneHundredFortyFive),
+            147 => Some(Self::OneHundredFortySeven),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredFortyFive => Some(years(uncertain!(17.7, 0.4))),
+            Self::OneHundredFortySeven => Some(years(uncertain!(2.623_4, 0.000_2))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -2496,6 +5266,15 @@ impl Pm {
             Self::OneHundredFortySeven => uncertain!(146.915_145_0, 0.000_001_9),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredFortyFive => Some(SpinParity::new(5, Parity::Positive)),
+            Self::OneHundredFortySeven => Some(SpinParity::new(7, Parity::Positive)),
+        }
+    }
 }
 
 /// Sm isotopes
@@ -2512,6 +5291,53 @@ pub enum Sm {
 }
 
 impl Sm {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredFortyFour => &[],
+            Self::OneHundredFortySeven => &[(DecayMode::Alpha, 1.0)],
+            Self::OneHundredFortyEight => &[(DecayMode::Alpha, 1.0)],
+            Self::OneHundredFortyNine => &[],
+            Self::OneHundredFifty => &[],
+            Self::OneHundredFiftyTwo => &[],
+            Self::OneHundredFiftyFour => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            144 => Some(Self::OneHundredFortyFour),
+            147 => Some(Self::OneHundredFortySeven),
+            148 => Some(Self::OneHundredFortyEight),
+            149 => Some(Self::OneHundredFortyNine),
+            150 => Some(Self::OneHundredFifty),
+            152 => Some(Self::OneHundredFiftyTwo),
+            154 => Some(Self::OneHundredFiftyFour),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredFortyFour => Some(STABLE),
+            Self::OneHundredFortySeven => Some(years(uncertain!(1.06e11, 0.01e11))),
+            Self::OneHundredFortyEight => Some(years(uncertain!(6.3e15, 1.3e15))),
+            Self::OneHundredFortyNine => Some(STABLE),
+            Self::OneHundredFifty => Some(STABLE),
+            Self::OneHundredFiftyTwo => Some(STABLE),
+            Self::OneHundredFiftyFour => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -2553,6 +5379,20 @@ impl Sm {
             Self::OneHundredFiftyFour => uncertain!(153.922_216_9, 0.000_002_0),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredFortyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredFortySeven => Some(SpinParity::new(7, Parity::Negative)),
+            Self::OneHundredFortyEight => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredFortyNine => Some(SpinParity::new(7, Parity::Negative)),
+            Self::OneHundredFifty => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredFiftyTwo => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredFiftyFour => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Eu isotopes
@@ -2564,6 +5404,38 @@ pub enum Eu {
 }
 
 impl Eu {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredFiftyOne => &[(DecayMode::Alpha, 1.0)],
+            Self::OneHundredFiftyThree => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            151 => Some(Self::OneHundredFiftyOne),
+            153 => Some(Self::OneHundredFiftyThree),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredFiftyOne => Some(years(uncertain!(4.6e18, 1.2e18))),
+            Self::OneHundredFiftyThree => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -2590,6 +5462,15 @@ impl Eu {
             Self::OneHundredFiftyThree => uncertain!(152.921_238_0, 0.000_001_8),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredFiftyOne => Some(SpinParity::new(5, Parity::Positive)),
+            Self::OneHundredFiftyThree => Some(SpinParity::new(5, Parity::Positive)),
+        }
+    }
 }
 
 /// Gd isotopes
@@ -2606,6 +5487,53 @@ pub enum Gd {
 }
 
 impl Gd {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredFiftyTwo => &[(DecayMode::Alpha, 1.0)],
+            Self::OneHundredFiftyFour => &[],
+            Self::OneHundredFiftyFive => &[],
+            Self::OneHundredFiftySix => &[],
+            Self::OneHundredFiftySeven => &[],
+            Self::OneHundredFiftyEight => &[],
+            Self::OneHundredSixty => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            152 => Some(Self::OneHundredFiftyTwo),
+            154 => Some(Self::OneHundredFiftyFour),
+            155 => Some(Self::OneHundredFiftyFive),
+            156 => Some(Self::OneHundredFiftySix),
+            157 => Some(Self::OneHundredFiftySeven),
+            158 => Some(Self::OneHundredFiftyEight),
+            160 => Some(Self::OneHundredSixty),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredFiftyTwo => Some(years(uncertain!(1.08e14, 0.08e14))),
+            Self::OneHundredFiftyFour => Some(STABLE),
+            Self::OneHundredFiftyFive => Some(STABLE),
+            Self::OneHundredFiftySix => Some(STABLE),
+            Self::OneHundredFiftySeven => Some(STABLE),
+            Self::OneHundredFiftyEight => Some(STABLE),
+            Self::OneHundredSixty => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -2647,6 +5575,20 @@ impl Gd {
             Self::OneHundredSixty => uncertain!(159.927_062_4, 0.000_001_8),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredFiftyTwo => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredFiftyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredFiftyFive => Some(SpinParity::new(3, Parity::Negative)),
+            Self::OneHundredFiftySix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredFiftySeven => Some(SpinParity::new(3, Parity::Negative)),
+            Self::OneHundredFiftyEight => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredSixty => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Tb isotope
@@ -2657,6 +5599,31 @@ pub enum Tb {
 }
 
 impl Tb {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            159 => Some(Self::OneHundredFiftyNine),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(STABLE)
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -2674,6 +5641,12 @@ impl Tb {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(158.925_354_7, 0.000_001_9)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(3, Parity::Positive))
+    }
 }
 
 /// Dy isotopes
@@ -2690,6 +5663,53 @@ pub enum Dy {
 }
 
 impl Dy {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredFiftySix => &[],
+            Self::OneHundredFiftyEight => &[],
+            Self::OneHundredSixty => &[],
+            Self::OneHundredSixtyOne => &[],
+            Self::OneHundredSixtyTwo => &[],
+            Self::OneHundredSixtyThree => &[],
+            Self::OneHundredSixtyFour => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            156 => Some(Self::OneHundredFiftySix),
+            158 => Some(Self::OneHundredFiftyEight),
+            160 => Some(Self::OneHundredSixty),
+            161 => Some(Self::OneHundredSixtyOne),
+            162 => Some(Self::OneHundredSixtyTwo),
+            163 => Some(Self::OneHundredSixtyThree),
+            164 => Some(Self::OneHundredSixtyFour),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredFiftySix => Some(STABLE),
+            Self::OneHundredFiftyEight => Some(STABLE),
+            Self::OneHundredSixty => Some(STABLE),
+            Self::OneHundredSixtyOne => Some(STABLE),
+            Self::OneHundredSixtyTwo => Some(STABLE),
+            Self::OneHundredSixtyThree => Some(STABLE),
+            Self::OneHundredSixtyFour => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -2731,6 +5751,20 @@ impl Dy {
             Self::OneHundredSixtyFour => uncertain!(163.929_181_9, 0.000_002_0),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredFiftySix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredFiftyEight => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredSixty => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredSixtyOne => Some(SpinParity::new(5, Parity::Positive)),
+            Self::OneHundredSixtyTwo => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredSixtyThree => Some(SpinParity::new(5, Parity::Negative)),
+            Self::OneHundredSixtyFour => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Ho isotope
@@ -2741,6 +5775,31 @@ pub enum Ho {
 }
 
 impl Ho {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            165 => Some(Self::OneHundredSixtyFive),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(STABLE)
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -2758,6 +5817,12 @@ impl Ho {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(164.930_328_8, 0.000_002_1)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(7, Parity::Negative))
+    }
 }
 
 /// Er isotopes
@@ -2773,6 +5838,50 @@ pub enum Er {
 }
 
 impl Er {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredSixtyTwo => &[],
+            Self::OneHundredSixtyFour => &[],
+            Self::OneHundredSixtySix => &[],
+            Self::OneHundredSixtySeven => &[],
+            Self::OneHundredSixtyEight => &[],
+            Self::OneHundredSeventy => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            162 => Some(Self::OneHundredSixtyTwo),
+            164 => Some(Self::OneHundredSixtyFour),
+            166 => Some(Self::OneHundredSixtySix),
+            167 => Some(Self::OneHundredSixtySeven),
+            168 => Some(Self::OneHundredSixtyEight),
+            170 => Some(Self::OneHundredSeventy),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredSixtyTwo => Some(STABLE),
+            Self::OneHundredSixtyFour => Some(STABLE),
+            Self::OneHundredSixtySix => Some(STABLE),
+            Self::OneHundredSixtySeven => Some(STABLE),
+            Self::OneHundredSixtyEight => Some(STABLE),
+            Self::OneHundredSeventy => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -2811,6 +5920,19 @@ impl Er {
             Self::OneHundredSeventy => uncertain!(169.935_470_2, 0.000_002_6),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredSixtyTwo => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredSixtyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredSixtySix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredSixtySeven => Some(SpinParity::new(7, Parity::Positive)),
+            Self::OneHundredSixtyEight => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredSeventy => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Tm isotope
@@ -2821,6 +5943,31 @@ pub enum Tm {
 }
 
 impl Tm {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            169 => Some(Self::OneHundredSixtyNine),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(STABLE)
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -2838,6 +5985,12 @@ impl Tm {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(168.934_217_9, 0.000_002_2)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(1, Parity::Positive))
+    }
 }
 
 /// Yb isotopes
@@ -2854,6 +6007,53 @@ pub enum Yb {
 }
 
 impl Yb {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredSixtyEight => &[],
+            Self::OneHundredSeventy => &[],
+            Self::OneHundredSeventyOne => &[],
+            Self::OneHundredSeventyTwo => &[],
+            Self::OneHundredSeventyThree => &[],
+            Self::OneHundredSeventyFour => &[],
+            Self::OneHundredSeventySix => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            168 => Some(Self::OneHundredSixtyEight),
+            170 => Some(Self::OneHundredSeventy),
+            171 => Some(Self::OneHundredSeventyOne),
+            172 => Some(Self::OneHundredSeventyTwo),
+            173 => Some(Self::OneHundredSeventyThree),
+            174 => Some(Self::OneHundredSeventyFour),
+            176 => Some(Self::OneHundredSeventySix),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredSixtyEight => Some(STABLE),
+            Self::OneHundredSeventy => Some(STABLE),
+            Self::OneHundredSeventyOne => Some(STABLE),
+            Self::OneHundredSeventyTwo => Some(STABLE),
+            Self::OneHundredSeventyThree => Some(STABLE),
+            Self::OneHundredSeventyFour => Some(STABLE),
+            Self::OneHundredSeventySix => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -2895,6 +6095,20 @@ impl Yb {
             Self::OneHundredSeventySix => uncertain!(175.942_576_4, 0.000_002_4),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredSixtyEight => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredSeventy => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredSeventyOne => Some(SpinParity::new(1, Parity::Negative)),
+            Self::OneHundredSeventyTwo => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredSeventyThree => Some(SpinParity::new(5, Parity::Negative)),
+            Self::OneHundredSeventyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredSeventySix => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Lu isotopes
@@ -2906,32 +6120,73 @@ pub enum Lu {
 }
 
 impl Lu {
-    /// Isotopic composition
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
     #[must_use]
-    pub const fn isotopic_composition(&self) -> Option<Uncertain> {
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
         match self {
-            Self::OneHundredSeventyFive => Some(uncertain!(0.974_01, 0.000_13)),
-            Self::OneHundredSeventySix => Some(uncertain!(0.025_99, 0.000_13)),
+            Self::OneHundredSeventyFive => &[],
+            Self::OneHundredSeventySix => &[(DecayMode::BetaMinus, 1.0)],
         }
     }
 
-    /// Mass number
+    /// Isotope with the mass number
     #[must_use]
-    pub const fn mass_number(&self) -> usize {
-        match self {
-            Self::OneHundredSeventyFive => 175,
-            Self::OneHundredSeventySix => 176,
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            175 => Some(Self::OneHundredSeventyFive),
+            176 => Some(Self::OneHundredSeventySix),
+            _ => None,
         }
     }
 
-    /// Relative Atomic Mass
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
     #[must_use]
-    pub const fn relative_atomic_mass(&self) -> Uncertain {
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredSeventyFive => Some(STABLE),
+            Self::OneHundredSeventySix => Some(years(uncertain!(3.76e10, 0.07e10))),
+        }
+    }
+
+    /// Isotopic composition
+    #[must_use]
+    pub const fn isotopic_composition(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredSeventyFive => Some(uncertain!(0.974_01, 0.000_13)),
+            Self::OneHundredSeventySix => Some(uncertain!(0.025_99, 0.000_13)),
+        }
+    }
+
+    /// Mass number
+    #[must_use]
+    pub const fn mass_number(&self) -> usize {
+        match self {
+            Self::OneHundredSeventyFive => 175,
+            Self::OneHundredSeventySix => 176,
+        }
+    }
+
+    /// Relative Atomic Mass
+    #[must_use]
+    pub const fn relative_atomic_mass(&self) -> Uncertain {
         match self {
             Self::OneHundredSeventyFive => uncertain!(174.940_775_2, 0.000_002_0),
             Self::OneHundredSeventySix => uncertain!(175.942_689_7, 0.000_002_0),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredSeventyFive => Some(SpinParity::new(7, Parity::Positive)),
+            Self::OneHundredSeventySix => Some(SpinParity::new(14, Parity::Negative)),
+        }
+    }
 }
 
 /// Hf isotopes
@@ -2947,6 +6202,50 @@ pub enum Hf {
 }
 
 impl Hf {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredSeventyFour => &[(DecayMode::Alpha, 1.0)],
+            Self::OneHundredSeventySix => &[],
+            Self::OneHundredSeventySeven => &[],
+            Self::OneHundredSeventyEight => &[],
+            Self::OneHundredSeventyNine => &[],
+            Self::OneHundredEighty => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            174 => Some(Self::OneHundredSeventyFour),
+            176 => Some(Self::OneHundredSeventySix),
+            177 => Some(Self::OneHundredSeventySeven),
+            178 => Some(Self::OneHundredSeventyEight),
+            179 => Some(Self::OneHundredSeventyNine),
+            180 => Some(Self::OneHundredEighty),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredSeventyFour => Some(years(uncertain!(7.0e16, 1.2e16))),
+            Self::OneHundredSeventySix => Some(STABLE),
+            Self::OneHundredSeventySeven => Some(STABLE),
+            Self::OneHundredSeventyEight => Some(STABLE),
+            Self::OneHundredSeventyNine => Some(STABLE),
+            Self::OneHundredEighty => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -2985,6 +6284,19 @@ impl Hf {
             Self::OneHundredEighty => uncertain!(179.946_557_0, 0.000_002_0),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredSeventyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredSeventySix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredSeventySeven => Some(SpinParity::new(7, Parity::Negative)),
+            Self::OneHundredSeventyEight => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredSeventyNine => Some(SpinParity::new(9, Parity::Positive)),
+            Self::OneHundredEighty => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Ta isotopes
@@ -2996,6 +6308,41 @@ pub enum Ta {
 }
 
 impl Ta {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredEighty => &[
+                (DecayMode::ElectronCapture, 0.85),
+                (DecayMode::BetaMinus, 0.15),
+            ],
+            Self::OneHundredEightyOne => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            180 => Some(Self::OneHundredEighty),
+            181 => Some(Self::OneHundredEightyOne),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredEighty => Some(hours(uncertain!(8.152, 0.006))),
+            Self::OneHundredEightyOne => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -3022,6 +6369,15 @@ impl Ta {
             Self::OneHundredEightyOne => uncertain!(180.947_995_8, 0.000_002_0),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredEighty => Some(SpinParity::new(2, Parity::Positive)),
+            Self::OneHundredEightyOne => Some(SpinParity::new(7, Parity::Positive)),
+        }
+    }
 }
 
 /// W isotopes
@@ -3036,6 +6392,47 @@ pub enum W {
 }
 
 impl W {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredEighty => &[(DecayMode::Alpha, 1.0)],
+            Self::OneHundredEightyTwo => &[],
+            Self::OneHundredEightyThree => &[],
+            Self::OneHundredEightyFour => &[],
+            Self::OneHundredEightySix => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            180 => Some(Self::OneHundredEighty),
+            182 => Some(Self::OneHundredEightyTwo),
+            183 => Some(Self::OneHundredEightyThree),
+            184 => Some(Self::OneHundredEightyFour),
+            186 => Some(Self::OneHundredEightySix),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredEighty => Some(years(uncertain!(1.8e18, 0.2e18))),
+            Self::OneHundredEightyTwo => Some(STABLE),
+            Self::OneHundredEightyThree => Some(STABLE),
+            Self::OneHundredEightyFour => Some(STABLE),
+            Self::OneHundredEightySix => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -3071,6 +6468,18 @@ impl W {
             Self::OneHundredEightySix => uncertain!(185.954_362_8, 0.000_001_7),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredEighty => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredEightyTwo => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredEightyThree => Some(SpinParity::new(1, Parity::Negative)),
+            Self::OneHundredEightyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredEightySix => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Re isotopes
@@ -3082,6 +6491,38 @@ pub enum Re {
 }
 
 impl Re {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredEightyFive => &[],
+            Self::OneHundredEightySeven => &[(DecayMode::BetaMinus, 1.0)],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            185 => Some(Self::OneHundredEightyFive),
+            187 => Some(Self::OneHundredEightySeven),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredEightyFive => Some(STABLE),
+            Self::OneHundredEightySeven => Some(years(uncertain!(4.33e10, 0.07e10))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -3108,6 +6549,15 @@ impl Re {
             Self::OneHundredEightySeven => uncertain!(186.955_750_1, 0.000_001_6),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredEightyFive => Some(SpinParity::new(5, Parity::Positive)),
+            Self::OneHundredEightySeven => Some(SpinParity::new(5, Parity::Positive)),
+        }
+    }
 }
 
 /// Os isotopes
@@ -3124,6 +6574,53 @@ pub enum Os {
 }
 
 impl Os {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredEightyFour => &[],
+            Self::OneHundredEightySix => &[(DecayMode::Alpha, 1.0)],
+            Self::OneHundredEightySeven => &[],
+            Self::OneHundredEightyEight => &[],
+            Self::OneHundredEightyNine => &[],
+            Self::OneHundredNinety => &[],
+            Self::OneHundredNinetyTwo => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            184 => Some(Self::OneHundredEightyFour),
+            186 => Some(Self::OneHundredEightySix),
+            187 => Some(Self::OneHundredEightySeven),
+            188 => Some(Self::OneHundredEightyEight),
+            189 => Some(Self::OneHundredEightyNine),
+            190 => Some(Self::OneHundredNinety),
+            192 => Some(Self::OneHundredNinetyTwo),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredEightyFour => Some(STABLE),
+            Self::OneHundredEightySix => Some(years(uncertain!(2.0e15, 1.1e15))),
+            Self::OneHundredEightySeven => Some(STABLE),
+            Self::OneHundredEightyEight => Some(STABLE),
+            Self::OneHundredEightyNine => Some(STABLE),
+            Self::OneHundredNinety => Some(STABLE),
+            Self::OneHundredNinetyTwo => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -3165,6 +6662,20 @@ impl Os {
             Self::OneHundredNinetyTwo => uncertain!(191.961_477_0, 0.000_002_9),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredEightyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredEightySix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredEightySeven => Some(SpinParity::new(1, Parity::Negative)),
+            Self::OneHundredEightyEight => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredEightyNine => Some(SpinParity::new(3, Parity::Negative)),
+            Self::OneHundredNinety => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredNinetyTwo => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Ir isotopes
@@ -3176,6 +6687,38 @@ pub enum Ir {
 }
 
 impl Ir {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredNinetyOne => &[],
+            Self::OneHundredNinetyThree => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            191 => Some(Self::OneHundredNinetyOne),
+            193 => Some(Self::OneHundredNinetyThree),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredNinetyOne => Some(STABLE),
+            Self::OneHundredNinetyThree => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -3202,6 +6745,15 @@ impl Ir {
             Self::OneHundredNinetyThree => uncertain!(192.962_921_6, 0.000_002_1),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredNinetyOne => Some(SpinParity::new(3, Parity::Positive)),
+            Self::OneHundredNinetyThree => Some(SpinParity::new(3, Parity::Positive)),
+        }
+    }
 }
 
 /// Pt isotopes
@@ -3217,6 +6769,50 @@ pub enum Pt {
 }
 
 impl Pt {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredNinety => &[(DecayMode::Alpha, 1.0)],
+            Self::OneHundredNinetyTwo => &[],
+            Self::OneHundredNinetyFour => &[],
+            Self::OneHundredNinetyFive => &[],
+            Self::OneHundredNinetySix => &[],
+            Self::OneHundredNinetyEight => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            190 => Some(Self::OneHundredNinety),
+            192 => Some(Self::OneHundredNinetyTwo),
+            194 => Some(Self::OneHundredNinetyFour),
+            195 => Some(Self::OneHundredNinetyFive),
+            196 => Some(Self::OneHundredNinetySix),
+            198 => Some(Self::OneHundredNinetyEight),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredNinety => Some(years(uncertain!(4.83e11, 0.03e11))),
+            Self::OneHundredNinetyTwo => Some(STABLE),
+            Self::OneHundredNinetyFour => Some(STABLE),
+            Self::OneHundredNinetyFive => Some(STABLE),
+            Self::OneHundredNinetySix => Some(STABLE),
+            Self::OneHundredNinetyEight => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -3255,6 +6851,19 @@ impl Pt {
             Self::OneHundredNinetyEight => uncertain!(197.967_894_9, 0.000_002_3),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredNinety => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredNinetyTwo => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredNinetyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredNinetyFive => Some(SpinParity::new(1, Parity::Negative)),
+            Self::OneHundredNinetySix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredNinetyEight => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Au isotope
@@ -3265,6 +6874,31 @@ pub enum Au {
 }
 
 impl Au {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            197 => Some(Self::OneHundredNinetySeven),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(STABLE)
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -3282,6 +6916,12 @@ impl Au {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(196.966_568_79, 0.000_000_71)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(3, Parity::Positive))
+    }
 }
 
 /// Hg isotopes
@@ -3298,6 +6938,53 @@ pub enum Hg {
 }
 
 impl Hg {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::OneHundredNinetySix => &[],
+            Self::OneHundredNinetyEight => &[],
+            Self::OneHundredNinetyNine => &[],
+            Self::TwoHundred => &[],
+            Self::TwoHundredOne => &[],
+            Self::TwoHundredTwo => &[],
+            Self::TwoHundredFour => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            196 => Some(Self::OneHundredNinetySix),
+            198 => Some(Self::OneHundredNinetyEight),
+            199 => Some(Self::OneHundredNinetyNine),
+            200 => Some(Self::TwoHundred),
+            201 => Some(Self::TwoHundredOne),
+            202 => Some(Self::TwoHundredTwo),
+            204 => Some(Self::TwoHundredFour),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::OneHundredNinetySix => Some(STABLE),
+            Self::OneHundredNinetyEight => Some(STABLE),
+            Self::OneHundredNinetyNine => Some(STABLE),
+            Self::TwoHundred => Some(STABLE),
+            Self::TwoHundredOne => Some(STABLE),
+            Self::TwoHundredTwo => Some(STABLE),
+            Self::TwoHundredFour => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -3339,6 +7026,20 @@ impl Hg {
             Self::TwoHundredFour => uncertain!(203.973_493_98, 0.000_000_53),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::OneHundredNinetySix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredNinetyEight => Some(SpinParity::new(0, Parity::Positive)),
+            Self::OneHundredNinetyNine => Some(SpinParity::new(1, Parity::Negative)),
+            Self::TwoHundred => Some(SpinParity::new(0, Parity::Positive)),
+            Self::TwoHundredOne => Some(SpinParity::new(3, Parity::Negative)),
+            Self::TwoHundredTwo => Some(SpinParity::new(0, Parity::Positive)),
+            Self::TwoHundredFour => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Tl isotopes
@@ -3350,6 +7051,38 @@ pub enum Tl {
 }
 
 impl Tl {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::TwoHundredThree => &[],
+            Self::TwoHundredFive => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            203 => Some(Self::TwoHundredThree),
+            205 => Some(Self::TwoHundredFive),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::TwoHundredThree => Some(STABLE),
+            Self::TwoHundredFive => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -3376,6 +7109,15 @@ impl Tl {
             Self::TwoHundredFive => uncertain!(204.974_427_8, 0.000_001_4),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::TwoHundredThree => Some(SpinParity::new(1, Parity::Positive)),
+            Self::TwoHundredFive => Some(SpinParity::new(1, Parity::Positive)),
+        }
+    }
 }
 
 /// Pb isotopes
@@ -3389,6 +7131,44 @@ pub enum Pb {
 }
 
 impl Pb {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::TwoHundredFour => &[],
+            Self::TwoHundredSix => &[],
+            Self::TwoHundredSeven => &[],
+            Self::TwoHundredEight => &[],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            204 => Some(Self::TwoHundredFour),
+            206 => Some(Self::TwoHundredSix),
+            207 => Some(Self::TwoHundredSeven),
+            208 => Some(Self::TwoHundredEight),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::TwoHundredFour => Some(STABLE),
+            Self::TwoHundredSix => Some(STABLE),
+            Self::TwoHundredSeven => Some(STABLE),
+            Self::TwoHundredEight => Some(STABLE),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -3421,6 +7201,17 @@ impl Pb {
             Self::TwoHundredEight => uncertain!(207.976_652_5, 0.000_001_3),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::TwoHundredFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::TwoHundredSix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::TwoHundredSeven => Some(SpinParity::new(1, Parity::Negative)),
+            Self::TwoHundredEight => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Bi isotope
@@ -3431,6 +7222,31 @@ pub enum Bi {
 }
 
 impl Bi {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[(DecayMode::Alpha, 1.0)]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            209 => Some(Self::TwoHundredNine),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(years(uncertain!(2.01e19, 0.08e19)))
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -3448,6 +7264,12 @@ impl Bi {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(208.980_399_1, 0.000_001_6)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(9, Parity::Negative))
+    }
 }
 
 /// Po isotopes
@@ -3459,25 +7281,60 @@ pub enum Po {
 }
 
 impl Po {
-    /// Isotopic composition
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
     #[must_use]
-    pub const fn isotopic_composition(&self) -> Option<Uncertain> {
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
         match self {
-            Self::TwoHundredNine => None,
-            Self::TwoHundredTen => None,
+            Self::TwoHundredNine => &[
+                (DecayMode::Alpha, 0.995_46),
+                (DecayMode::ElectronCapture, 0.004_54),
+            ],
+            Self::TwoHundredTen => &[(DecayMode::Alpha, 1.0)],
         }
     }
 
-    /// Mass number
+    /// Isotope with the mass number
     #[must_use]
-    pub const fn mass_number(&self) -> usize {
-        match self {
-            Self::TwoHundredNine => 209,
-            Self::TwoHundredTen => 210,
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            209 => Some(Self::TwoHundredNine),
+            210 => Some(Self::TwoHundredTen),
+            _ => None,
         }
     }
 
-    /// Relative Atomic Mass
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::TwoHundredNine => Some(years(uncertain!(124.0, 3.0))),
+            Self::TwoHundredTen => Some(days(uncertain!(138.376, 0.002))),
+        }
+    }
+
+    /// Isotopic composition
+    #[must_use]
+    pub const fn isotopic_composition(&self) -> Option<Uncertain> {
+        match self {
+            Self::TwoHundredNine => None,
+            Self::TwoHundredTen => None,
+        }
+    }
+
+    /// Mass number
+    #[must_use]
+    pub const fn mass_number(&self) -> usize {
+        match self {
+            Self::TwoHundredNine => 209,
+            Self::TwoHundredTen => 210,
+        }
+    }
+
+    /// Relative Atomic Mass
     #[must_use]
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         match self {
@@ -3485,6 +7342,15 @@ impl Po {
             Self::TwoHundredTen => uncertain!(209.982_874_1, 0.000_001_3),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::TwoHundredNine => Some(SpinParity::new(1, Parity::Negative)),
+            Self::TwoHundredTen => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// At isotopes
@@ -3496,6 +7362,44 @@ pub enum At {
 }
 
 impl At {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::TwoHundredTen => &[
+                (DecayMode::ElectronCapture, 0.998_25),
+                (DecayMode::Alpha, 0.001_75),
+            ],
+            Self::TwoHundredEleven => &[
+                (DecayMode::ElectronCapture, 0.582),
+                (DecayMode::Alpha, 0.418),
+            ],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            210 => Some(Self::TwoHundredTen),
+            211 => Some(Self::TwoHundredEleven),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::TwoHundredTen => Some(hours(uncertain!(8.1, 0.4))),
+            Self::TwoHundredEleven => Some(hours(uncertain!(7.214, 0.007))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -3522,6 +7426,15 @@ impl At {
             Self::TwoHundredEleven => uncertain!(210.987_496_6, 0.000_003_0),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::TwoHundredTen => Some(SpinParity::new(10, Parity::Positive)),
+            Self::TwoHundredEleven => Some(SpinParity::new(9, Parity::Negative)),
+        }
+    }
 }
 
 /// Rn isotopes
@@ -3534,6 +7447,44 @@ pub enum Rn {
 }
 
 impl Rn {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::TwoHundredEleven => &[
+                (DecayMode::ElectronCapture, 0.726),
+                (DecayMode::Alpha, 0.274),
+            ],
+            Self::TwoHundredTwenty => &[(DecayMode::Alpha, 1.0)],
+            Self::TwoHundredTwentyTwo => &[(DecayMode::Alpha, 1.0)],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            211 => Some(Self::TwoHundredEleven),
+            220 => Some(Self::TwoHundredTwenty),
+            222 => Some(Self::TwoHundredTwentyTwo),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::TwoHundredEleven => Some(hours(uncertain!(14.6, 0.2))),
+            Self::TwoHundredTwenty => Some(uncertain!(55.6, 0.1)),
+            Self::TwoHundredTwentyTwo => Some(days(uncertain!(3.821_5, 0.000_2))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -3563,6 +7514,16 @@ impl Rn {
             Self::TwoHundredTwentyTwo => uncertain!(222.017_578_2, 0.000_002_5),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::TwoHundredEleven => Some(SpinParity::new(1, Parity::Negative)),
+            Self::TwoHundredTwenty => Some(SpinParity::new(0, Parity::Positive)),
+            Self::TwoHundredTwentyTwo => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Fr isotope
@@ -3573,6 +7534,34 @@ pub enum Fr {
 }
 
 impl Fr {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[
+            (DecayMode::BetaMinus, 0.999_94),
+            (DecayMode::Alpha, 0.000_06),
+        ]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            223 => Some(Self::TwoHundredTwentyThree),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(minutes(uncertain!(22.00, 0.07)))
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -3590,6 +7579,12 @@ impl Fr {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(223.019_736_0, 0.000_002_5)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(3, Parity::Negative))
+    }
 }
 
 /// Ra isotopes
@@ -3603,6 +7598,44 @@ pub enum Ra {
 }
 
 impl Ra {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::TwoHundredTwentyThree => &[(DecayMode::Alpha, 1.0)],
+            Self::TwoHundredTwentyFour => &[(DecayMode::Alpha, 1.0)],
+            Self::TwoHundredTwentySix => &[(DecayMode::Alpha, 1.0)],
+            Self::TwoHundredTwentyEight => &[(DecayMode::BetaMinus, 1.0)],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            223 => Some(Self::TwoHundredTwentyThree),
+            224 => Some(Self::TwoHundredTwentyFour),
+            226 => Some(Self::TwoHundredTwentySix),
+            228 => Some(Self::TwoHundredTwentyEight),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::TwoHundredTwentyThree => Some(days(uncertain!(11.43, 0.03))),
+            Self::TwoHundredTwentyFour => Some(days(uncertain!(3.631_6, 0.002_3))),
+            Self::TwoHundredTwentySix => Some(years(uncertain!(1_600.0, 7.0))),
+            Self::TwoHundredTwentyEight => Some(years(uncertain!(5.75, 0.03))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -3635,6 +7668,17 @@ impl Ra {
             Self::TwoHundredTwentyEight => uncertain!(228.031_070_7, 0.000_002_6),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::TwoHundredTwentyThree => Some(SpinParity::new(3, Parity::Positive)),
+            Self::TwoHundredTwentyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::TwoHundredTwentySix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::TwoHundredTwentyEight => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Ac isotope
@@ -3645,6 +7689,31 @@ pub enum Ac {
 }
 
 impl Ac {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[(DecayMode::BetaMinus, 0.986_2), (DecayMode::Alpha, 0.013_8)]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            227 => Some(Self::TwoHundredTwentySeven),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(years(uncertain!(21.772, 0.003)))
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -3662,6 +7731,12 @@ impl Ac {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(227.027_752_3, 0.000_002_5)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(3, Parity::Negative))
+    }
 }
 
 /// Th isotopes
@@ -3673,6 +7748,38 @@ pub enum Th {
 }
 
 impl Th {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::TwoHundredThirty => &[(DecayMode::Alpha, 1.0)],
+            Self::TwoHundredThirtyTwo => &[(DecayMode::Alpha, 1.0)],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            230 => Some(Self::TwoHundredThirty),
+            232 => Some(Self::TwoHundredThirtyTwo),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::TwoHundredThirty => Some(years(uncertain!(7.54e4, 0.03e4))),
+            Self::TwoHundredThirtyTwo => Some(years(uncertain!(1.40e10, 0.01e10))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -3699,6 +7806,15 @@ impl Th {
             Self::TwoHundredThirtyTwo => uncertain!(232.038_055_8, 0.000_002_1),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::TwoHundredThirty => Some(SpinParity::new(0, Parity::Positive)),
+            Self::TwoHundredThirtyTwo => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Pa isotope
@@ -3709,6 +7825,31 @@ pub enum Pa {
 }
 
 impl Pa {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[(DecayMode::Alpha, 1.0)]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            231 => Some(Self::TwoHundredThirtyOne),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(years(uncertain!(3.265e4, 0.011e4)))
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -3726,6 +7867,12 @@ impl Pa {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(231.035_884_2, 0.000_002_4)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(3, Parity::Negative))
+    }
 }
 
 /// U isotopes
@@ -3740,6 +7887,47 @@ pub enum U {
 }
 
 impl U {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::TwoHundredThirtyThree => &[(DecayMode::Alpha, 1.0)],
+            Self::TwoHundredThirtyFour => &[(DecayMode::Alpha, 1.0)],
+            Self::TwoHundredThirtyFive => &[(DecayMode::Alpha, 1.0)],
+            Self::TwoHundredThirtySix => &[(DecayMode::Alpha, 1.0)],
+            Self::TwoHundredThirtyEight => &[(DecayMode::Alpha, 1.0)],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            233 => Some(Self::TwoHundredThirtyThree),
+            234 => Some(Self::TwoHundredThirtyFour),
+            235 => Some(Self::TwoHundredThirtyFive),
+            236 => Some(Self::TwoHundredThirtySix),
+            238 => Some(Self::TwoHundredThirtyEight),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::TwoHundredThirtyThree => Some(years(uncertain!(1.592e5, 0.002e5))),
+            Self::TwoHundredThirtyFour => Some(years(uncertain!(2.455e5, 0.006e5))),
+            Self::TwoHundredThirtyFive => Some(years(uncertain!(7.04e8, 0.01e8))),
+            Self::TwoHundredThirtySix => Some(years(uncertain!(2.342e7, 0.004e7))),
+            Self::TwoHundredThirtyEight => Some(years(uncertain!(4.468e9, 0.003e9))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -3775,6 +7963,18 @@ impl U {
             Self::TwoHundredThirtyEight => uncertain!(238.050_788_4, 0.000_002_0),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::TwoHundredThirtyThree => Some(SpinParity::new(5, Parity::Positive)),
+            Self::TwoHundredThirtyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::TwoHundredThirtyFive => Some(SpinParity::new(7, Parity::Negative)),
+            Self::TwoHundredThirtySix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::TwoHundredThirtyEight => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Np isotopes
@@ -3786,6 +7986,42 @@ pub enum Np {
 }
 
 impl Np {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::TwoHundredThirtySix => &[
+                (DecayMode::ElectronCapture, 0.873),
+                (DecayMode::BetaMinus, 0.125),
+                (DecayMode::Alpha, 0.002),
+            ],
+            Self::TwoHundredThirtySeven => &[(DecayMode::Alpha, 1.0)],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            236 => Some(Self::TwoHundredThirtySix),
+            237 => Some(Self::TwoHundredThirtySeven),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::TwoHundredThirtySix => Some(years(uncertain!(1.54e5, 0.06e5))),
+            Self::TwoHundredThirtySeven => Some(years(uncertain!(2.144e6, 0.007e6))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -3812,6 +8048,15 @@ impl Np {
             Self::TwoHundredThirtySeven => uncertain!(237.048_173_6, 0.000_001_9),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::TwoHundredThirtySix => Some(SpinParity::new(12, Parity::Negative)),
+            Self::TwoHundredThirtySeven => Some(SpinParity::new(5, Parity::Positive)),
+        }
+    }
 }
 
 /// Pu isotopes
@@ -3827,6 +8072,56 @@ pub enum Pu {
 }
 
 impl Pu {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::TwoHundredThirtyEight => &[(DecayMode::Alpha, 1.0)],
+            Self::TwoHundredThirtyNine => &[(DecayMode::Alpha, 1.0)],
+            Self::TwoHundredForty => &[(DecayMode::Alpha, 1.0)],
+            Self::TwoHundredFortyOne => &[
+                (DecayMode::BetaMinus, 0.999_975),
+                (DecayMode::Alpha, 0.000_025),
+            ],
+            Self::TwoHundredFortyTwo => &[(DecayMode::Alpha, 1.0)],
+            Self::TwoHundredFortyFour => &[
+                (DecayMode::Alpha, 0.998_8),
+                (DecayMode::SpontaneousFission, 0.001_2),
+            ],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            238 => Some(Self::TwoHundredThirtyEight),
+            239 => Some(Self::TwoHundredThirtyNine),
+            240 => Some(Self::TwoHundredForty),
+            241 => Some(Self::TwoHundredFortyOne),
+            242 => Some(Self::TwoHundredFortyTwo),
+            244 => Some(Self::TwoHundredFortyFour),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::TwoHundredThirtyEight => Some(years(uncertain!(87.7, 0.1))),
+            Self::TwoHundredThirtyNine => Some(years(uncertain!(24_110.0, 30.0))),
+            Self::TwoHundredForty => Some(years(uncertain!(6_561.0, 7.0))),
+            Self::TwoHundredFortyOne => Some(years(uncertain!(14.290, 0.006))),
+            Self::TwoHundredFortyTwo => Some(years(uncertain!(3.75e5, 0.02e5))),
+            Self::TwoHundredFortyFour => Some(years(uncertain!(8.13e7, 0.03e7))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -3865,6 +8160,19 @@ impl Pu {
             Self::TwoHundredFortyFour => uncertain!(244.064_205_3, 0.000_005_6),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::TwoHundredThirtyEight => Some(SpinParity::new(0, Parity::Positive)),
+            Self::TwoHundredThirtyNine => Some(SpinParity::new(1, Parity::Positive)),
+            Self::TwoHundredForty => Some(SpinParity::new(0, Parity::Positive)),
+            Self::TwoHundredFortyOne => Some(SpinParity::new(5, Parity::Positive)),
+            Self::TwoHundredFortyTwo => Some(SpinParity::new(0, Parity::Positive)),
+            Self::TwoHundredFortyFour => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Am isotopes
@@ -3876,6 +8184,38 @@ pub enum Am {
 }
 
 impl Am {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::TwoHundredFortyOne => &[(DecayMode::Alpha, 1.0)],
+            Self::TwoHundredFortyThree => &[(DecayMode::Alpha, 1.0)],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            241 => Some(Self::TwoHundredFortyOne),
+            243 => Some(Self::TwoHundredFortyThree),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::TwoHundredFortyOne => Some(years(uncertain!(432.6, 0.6))),
+            Self::TwoHundredFortyThree => Some(years(uncertain!(7_350.0, 9.0))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -3902,6 +8242,15 @@ impl Am {
             Self::TwoHundredFortyThree => uncertain!(243.061_381_3, 0.000_002_4),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::TwoHundredFortyOne => Some(SpinParity::new(5, Parity::Negative)),
+            Self::TwoHundredFortyThree => Some(SpinParity::new(5, Parity::Negative)),
+        }
+    }
 }
 
 /// Cm isotopes
@@ -3917,6 +8266,59 @@ pub enum Cm {
 }
 
 impl Cm {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::TwoHundredFortyThree => &[
+                (DecayMode::Alpha, 0.997_1),
+                (DecayMode::ElectronCapture, 0.002_9),
+            ],
+            Self::TwoHundredFortyFour => &[(DecayMode::Alpha, 1.0)],
+            Self::TwoHundredFortyFive => &[(DecayMode::Alpha, 1.0)],
+            Self::TwoHundredFortySix => &[
+                (DecayMode::Alpha, 0.999_74),
+                (DecayMode::SpontaneousFission, 0.000_26),
+            ],
+            Self::TwoHundredFortySeven => &[(DecayMode::Alpha, 1.0)],
+            Self::TwoHundredFortyEight => &[
+                (DecayMode::Alpha, 0.916_1),
+                (DecayMode::SpontaneousFission, 0.083_9),
+            ],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            243 => Some(Self::TwoHundredFortyThree),
+            244 => Some(Self::TwoHundredFortyFour),
+            245 => Some(Self::TwoHundredFortyFive),
+            246 => Some(Self::TwoHundredFortySix),
+            247 => Some(Self::TwoHundredFortySeven),
+            248 => Some(Self::TwoHundredFortyEight),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::TwoHundredFortyThree => Some(years(uncertain!(29.1, 0.1))),
+            Self::TwoHundredFortyFour => Some(years(uncertain!(18.11, 0.03))),
+            Self::TwoHundredFortyFive => Some(years(uncertain!(8_250.0, 70.0))),
+            Self::TwoHundredFortySix => Some(years(uncertain!(4_706.0, 40.0))),
+            Self::TwoHundredFortySeven => Some(years(uncertain!(1.56e7, 0.05e7))),
+            Self::TwoHundredFortyEight => Some(years(uncertain!(3.48e5, 0.06e5))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -3955,6 +8357,19 @@ impl Cm {
             Self::TwoHundredFortyEight => uncertain!(248.072_349_9, 0.000_005_6),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::TwoHundredFortyThree => Some(SpinParity::new(5, Parity::Positive)),
+            Self::TwoHundredFortyFour => Some(SpinParity::new(0, Parity::Positive)),
+            Self::TwoHundredFortyFive => Some(SpinParity::new(7, Parity::Positive)),
+            Self::TwoHundredFortySix => Some(SpinParity::new(0, Parity::Positive)),
+            Self::TwoHundredFortySeven => Some(SpinParity::new(9, Parity::Negative)),
+            Self::TwoHundredFortyEight => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Bk isotopes
@@ -3966,6 +8381,38 @@ pub enum Bk {
 }
 
 impl Bk {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::TwoHundredFortySeven => &[(DecayMode::Alpha, 1.0)],
+            Self::TwoHundredFortyNine => &[(DecayMode::BetaMinus, 1.0)],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            247 => Some(Self::TwoHundredFortySeven),
+            249 => Some(Self::TwoHundredFortyNine),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::TwoHundredFortySeven => Some(years(uncertain!(1_380.0, 250.0))),
+            Self::TwoHundredFortyNine => Some(days(uncertain!(330.0, 4.0))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -3992,10 +8439,19 @@ impl Bk {
             Self::TwoHundredFortyNine => uncertain!(249.074_987_7, 0.000_002_7),
         }
     }
-}
 
-/// Cf isotopes
-#[derive(Clone, Copy, Debug, EnumIter, Eq, Hash, Ord, PartialEq, PartialOrd)]
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::TwoHundredFortySeven => Some(SpinParity::new(3, Parity::Negative)),
+            Self::TwoHundredFortyNine => Some(SpinParity::new(7, Parity::Positive)),
+        }
+    }
+}
+
+/// Cf isotopes
+#[derive(Clone, Copy, Debug, EnumIter, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Cf {
     TwoHundredFortyNine,
@@ -4005,6 +8461,50 @@ pub enum Cf {
 }
 
 impl Cf {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::TwoHundredFortyNine => &[(DecayMode::Alpha, 1.0)],
+            Self::TwoHundredFifty => &[
+                (DecayMode::Alpha, 0.999_23),
+                (DecayMode::SpontaneousFission, 0.000_77),
+            ],
+            Self::TwoHundredFiftyOne => &[(DecayMode::Alpha, 1.0)],
+            Self::TwoHundredFiftyTwo => &[
+                (DecayMode::Alpha, 0.969_08),
+                (DecayMode::SpontaneousFission, 0.030_92),
+            ],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            249 => Some(Self::TwoHundredFortyNine),
+            250 => Some(Self::TwoHundredFifty),
+            251 => Some(Self::TwoHundredFiftyOne),
+            252 => Some(Self::TwoHundredFiftyTwo),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::TwoHundredFortyNine => Some(years(uncertain!(351.0, 2.0))),
+            Self::TwoHundredFifty => Some(years(uncertain!(13.08, 0.09))),
+            Self::TwoHundredFiftyOne => Some(years(uncertain!(898.0, 44.0))),
+            Self::TwoHundredFiftyTwo => Some(years(uncertain!(2.645, 0.008))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -4037,6 +8537,17 @@ impl Cf {
             Self::TwoHundredFiftyTwo => uncertain!(252.081_627_2, 0.000_005_6),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::TwoHundredFortyNine => Some(SpinParity::new(9, Parity::Negative)),
+            Self::TwoHundredFifty => Some(SpinParity::new(0, Parity::Positive)),
+            Self::TwoHundredFiftyOne => Some(SpinParity::new(1, Parity::Positive)),
+            Self::TwoHundredFiftyTwo => Some(SpinParity::new(0, Parity::Positive)),
+        }
+    }
 }
 
 /// Es isotope
@@ -4047,6 +8558,31 @@ pub enum Es {
 }
 
 impl Es {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[(DecayMode::Alpha, 0.78), (DecayMode::ElectronCapture, 0.22)]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            252 => Some(Self::TwoHundredFiftyTwo),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(days(uncertain!(471.7, 1.9)))
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -4064,6 +8600,12 @@ impl Es {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(252.082_980, 0.000_054)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(8, Parity::Positive))
+    }
 }
 
 /// Fm isotope
@@ -4074,6 +8616,34 @@ pub enum Fm {
 }
 
 impl Fm {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[
+            (DecayMode::Alpha, 0.997_9),
+            (DecayMode::SpontaneousFission, 0.002_1),
+        ]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            257 => Some(Self::TwoHundredFiftySeven),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(days(uncertain!(100.5, 0.2)))
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -4091,6 +8661,12 @@ impl Fm {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(257.095_106_1, 0.000_006_9)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(9, Parity::Positive))
+    }
 }
 
 /// Md isotopes
@@ -4102,6 +8678,38 @@ pub enum Md {
 }
 
 impl Md {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        match self {
+            Self::TwoHundredFiftyEight => &[(DecayMode::Alpha, 1.0)],
+            Self::TwoHundredSixty => &[(DecayMode::SpontaneousFission, 1.0)],
+        }
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            258 => Some(Self::TwoHundredFiftyEight),
+            260 => Some(Self::TwoHundredSixty),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        match self {
+            Self::TwoHundredFiftyEight => Some(days(uncertain!(51.59, 0.29))),
+            Self::TwoHundredSixty => Some(days(uncertain!(27.8, 0.8))),
+        }
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -4128,6 +8736,15 @@ impl Md {
             Self::TwoHundredSixty => uncertain!(260.103_65, 0.000_34),
         }
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        match self {
+            Self::TwoHundredFiftyEight => Some(SpinParity::new(16, Parity::Negative)),
+            Self::TwoHundredSixty => None,
+        }
+    }
 }
 
 /// No isotope
@@ -4138,6 +8755,31 @@ pub enum No {
 }
 
 impl No {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[(DecayMode::Alpha, 0.75), (DecayMode::ElectronCapture, 0.25)]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            259 => Some(Self::TwoHundredFiftyNine),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(minutes(uncertain!(58.0, 5.0)))
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -4155,6 +8797,12 @@ impl No {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(259.101_03, 0.000_11)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(9, Parity::Positive))
+    }
 }
 
 /// Lr isotope
@@ -4165,6 +8813,31 @@ pub enum Lr {
 }
 
 impl Lr {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[(DecayMode::ElectronCapture, 1.0)]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            262 => Some(Self::TwoHundredSixtyTwo),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(hours(uncertain!(3.6, 0.3)))
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -4182,6 +8855,12 @@ impl Lr {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(262.109_61, 0.000_22)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        None
+    }
 }
 
 /// Rf isotope
@@ -4192,6 +8871,31 @@ pub enum Rf {
 }
 
 impl Rf {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[(DecayMode::SpontaneousFission, 1.0)]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            267 => Some(Self::TwoHundredSixtySeven),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(hours(uncertain!(1.3, 0.5)))
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -4209,6 +8913,12 @@ impl Rf {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(267.121_79, 0.000_62)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        None
+    }
 }
 
 /// Db isotope
@@ -4219,6 +8929,31 @@ pub enum Db {
 }
 
 impl Db {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[(DecayMode::SpontaneousFission, 1.0)]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            268 => Some(Self::TwoHundredSixtyEight),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(hours(uncertain!(16.0, 5.0)))
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -4236,6 +8971,12 @@ impl Db {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(268.125_67, 0.000_57)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        None
+    }
 }
 
 /// Sg isotope
@@ -4246,6 +8987,34 @@ pub enum Sg {
 }
 
 impl Sg {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[
+            (DecayMode::Alpha, 0.67),
+            (DecayMode::SpontaneousFission, 0.33),
+        ]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            271 => Some(Self::TwoHundredSeventyOne),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(minutes(uncertain!(1.9, 0.6)))
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -4263,6 +9032,12 @@ impl Sg {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(271.133_93, 0.000_63)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        None
+    }
 }
 
 /// Bh isotope
@@ -4273,6 +9048,31 @@ pub enum Bh {
 }
 
 impl Bh {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[(DecayMode::Alpha, 1.0)]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            272 => Some(Self::TwoHundredSeventyTwo),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(uncertain!(10.0, 4.0))
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -4290,6 +9090,12 @@ impl Bh {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(272.138_26, 0.000_58)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        None
+    }
 }
 
 /// Hs isotope
@@ -4300,6 +9106,31 @@ pub enum Hs {
 }
 
 impl Hs {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[(DecayMode::Alpha, 1.0)]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            270 => Some(Self::TwoHundredSeventy),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(uncertain!(9.0, 4.0))
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -4317,6 +9148,12 @@ impl Hs {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(270.134_29, 0.000_27)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(0, Parity::Positive))
+    }
 }
 
 /// Mt isotope
@@ -4327,6 +9164,31 @@ pub enum Mt {
 }
 
 impl Mt {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[(DecayMode::Alpha, 1.0)]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            276 => Some(Self::TwoHundredSeventySix),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(uncertain!(0.6, 0.3))
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -4344,6 +9206,12 @@ impl Mt {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(276.151_59, 0.000_59)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        None
+    }
 }
 
 /// Ds isotope
@@ -4354,6 +9222,34 @@ pub enum Ds {
 }
 
 impl Ds {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[
+            (DecayMode::SpontaneousFission, 0.94),
+            (DecayMode::Alpha, 0.06),
+        ]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            281 => Some(Self::TwoHundredEightyOne),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(uncertain!(13.0, 4.0))
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -4371,6 +9267,12 @@ impl Ds {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(281.164_51, 0.000_59)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        None
+    }
 }
 
 /// Rg isotope
@@ -4381,6 +9283,31 @@ pub enum Rg {
 }
 
 impl Rg {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[(DecayMode::Alpha, 1.0)]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            280 => Some(Self::TwoHundredEighty),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(uncertain!(3.6, 1.3))
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -4398,6 +9325,12 @@ impl Rg {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(280.165_14, 0.000_61)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        None
+    }
 }
 
 /// Cn isotope
@@ -4408,6 +9341,31 @@ pub enum Cn {
 }
 
 impl Cn {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[(DecayMode::Alpha, 1.0)]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            285 => Some(Self::TwoHundredEightyFive),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(uncertain!(28.0, 9.0))
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -4425,6 +9383,12 @@ impl Cn {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(285.177_12, 0.000_60)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        None
+    }
 }
 
 /// Nh isotope
@@ -4435,6 +9399,31 @@ pub enum Nh {
 }
 
 impl Nh {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[(DecayMode::Alpha, 1.0)]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            284 => Some(Self::TwoHundredEightyFour),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(uncertain!(0.91, 0.28))
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -4452,6 +9441,12 @@ impl Nh {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(284.178_73, 0.000_62)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        None
+    }
 }
 
 /// Fl isotope
@@ -4462,6 +9457,31 @@ pub enum Fl {
 }
 
 impl Fl {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[(DecayMode::Alpha, 1.0)]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            289 => Some(Self::TwoHundredEightyNine),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(uncertain!(1.9, 0.4))
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -4479,6 +9499,12 @@ impl Fl {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(289.190_42, 0.000_60)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        None
+    }
 }
 
 /// Mc isotope
@@ -4489,6 +9515,31 @@ pub enum Mc {
 }
 
 impl Mc {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[(DecayMode::Alpha, 1.0)]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            288 => Some(Self::TwoHundredEightyEight),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(uncertain!(0.19, 0.02))
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -4506,6 +9557,12 @@ impl Mc {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(288.192_74, 0.000_62)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        None
+    }
 }
 
 /// Lv isotope
@@ -4516,6 +9573,31 @@ pub enum Lv {
 }
 
 impl Lv {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[(DecayMode::Alpha, 1.0)]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            293 => Some(Self::TwoHundredNinetyThree),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(uncertain!(0.057, 0.020))
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -4533,6 +9615,12 @@ impl Lv {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(293.204_49, 0.000_60)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        None
+    }
 }
 
 /// Ts isotope
@@ -4543,6 +9631,31 @@ pub enum Ts {
 }
 
 impl Ts {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            292 => Some(Self::TwoHundredNinetyTwo),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        None
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -4560,6 +9673,12 @@ impl Ts {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(292.207_46, 0.000_75)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        None
+    }
 }
 
 /// Og isotope
@@ -4570,6 +9689,31 @@ pub enum Og {
 }
 
 impl Og {
+    /// Decay modes
+    ///
+    /// Decay modes and their branching ratios, empty for stable isotopes.
+    #[must_use]
+    pub const fn decay_modes(&self) -> &'static [(DecayMode, f64)] {
+        &[(DecayMode::Alpha, 1.0)]
+    }
+
+    /// Isotope with the mass number
+    #[must_use]
+    pub const fn from_mass_number(mass_number: usize) -> Option<Self> {
+        match mass_number {
+            294 => Some(Self::TwoHundredNinetyFour),
+            _ => None,
+        }
+    }
+
+    /// Half-life in seconds
+    ///
+    /// Infinite for stable isotopes, `None` if unknown.
+    #[must_use]
+    pub const fn half_life(&self) -> Option<Uncertain> {
+        Some(uncertain!(0.000_58, 0.000_30))
+    }
+
     /// Isotopic composition
     #[must_use]
     pub const fn isotopic_composition(&self) -> Option<Uncertain> {
@@ -4587,4 +9731,10 @@ impl Og {
     pub const fn relative_atomic_mass(&self) -> Uncertain {
         uncertain!(294.213_92, 0.000_71)
     }
+
+    /// Ground-state spin and parity
+    #[must_use]
+    pub const fn spin_parity(&self) -> Option<SpinParity> {
+        Some(SpinParity::new(0, Parity::Positive))
+    }
 }
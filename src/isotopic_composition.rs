@@ -0,0 +1,229 @@
+use crate::{Element, Error, Isotope, Result, Source, Uncertain, ELEMENTS};
+use indexmap::IndexMap;
+
+/// Tolerance of the sum of amount fractions beyond its uncertainty
+const TOLERANCE: f64 = 1e-9;
+
+impl Element {
+    /// Isotopic atomic weight
+    ///
+    /// Atomic weight derived from the representative isotopic composition,
+    /// Σ xᵢ·Aᵣ(ᵢ) over the isotopes of the element.
+    #[must_use]
+    pub fn isotopic_atomic_weight(&self) -> Option<Uncertain> {
+        self.isotopic_atomic_weight_with(Isotope::isotopic_composition)
+    }
+
+    /// Isotopic atomic weight with the given isotopic composition
+    ///
+    /// Atomic weight of a material in which the isotope amount fractions are
+    /// given by `composition`. Isotopes for which it returns `None` are
+    /// absent. The uncertainty is propagated to first order, Σ (xᵢ·u(Aᵣ(ᵢ)) +
+    /// u(xᵢ)·Aᵣ(ᵢ)).
+    #[must_use]
+    pub fn isotopic_atomic_weight_with(
+        &self,
+        composition: impl Fn(&Isotope) -> Option<Uncertain>,
+    ) -> Option<Uncertain> {
+        let mut atomic_weight = None;
+        for isotope in self.isotopes() {
+            let Some(fraction) = composition(&isotope) else {
+                continue;
+            };
+            let mass = isotope.relative_atomic_mass();
+            let Uncertain { value, uncertainty } = atomic_weight.get_or_insert_default();
+            *value += fraction.value * mass.value;
+            *uncertainty += fraction.value * mass.uncertainty + fraction.uncertainty * mass.value;
+        }
+        atomic_weight
+    }
+
+    /// Consistency
+    ///
+    /// Compares the standard atomic weight from the given source with the
+    /// isotopic atomic weight.
+    #[must_use]
+    pub fn consistency(&self, source: Source) -> Option<Consistency> {
+        Some(Consistency {
+            element: *self,
+            standard: self.standard_atomic_weight_from(source)?,
+            isotopic: self.isotopic_atomic_weight()?,
+        })
+    }
+}
+
+/// Consistency
+///
+/// Standard atomic weight of an element next to the atomic weight derived
+/// from its isotopic composition.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Consistency {
+    pub element: Element,
+    pub standard: Uncertain,
+    pub isotopic: Uncertain,
+}
+
+impl Consistency {
+    /// Checks all elements that have both a standard and an isotopic atomic
+    /// weight
+    pub fn check(source: Source) -> impl Iterator<Item = Self> {
+        ELEMENTS
+            .into_iter()
+            .filter_map(move |element| element.consistency(source))
+    }
+
+    /// Difference between the isotopic and the standard atomic weight
+    #[must_use]
+    pub fn difference(&self) -> f64 {
+        self.isotopic.value - self.standard.value
+    }
+
+    /// Whether the uncertainty intervals of both atomic weights overlap
+    #[must_use]
+    pub fn is_consistent(&self) -> bool {
+        self.difference().abs() <= self.isotopic.uncertainty + self.standard.uncertainty
+    }
+}
+
+/// Isotopic composition
+///
+/// Isotope amount fractions of one element in a particular material, for
+/// example an enriched or a depleted one. Isotopes that were not set are
+/// absent from the material.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IsotopicComposition {
+    element: Element,
+    fractions: IndexMap<Isotope, Uncertain>,
+}
+
+impl IsotopicComposition {
+    /// Empty composition of the element
+    #[must_use]
+    pub fn new(element: Element) -> Self {
+        Self {
+            element,
+            fractions: IndexMap::new(),
+        }
+    }
+
+    /// Representative (natural) composition of the element
+    #[must_use]
+    pub fn natural(element: Element) -> Self {
+        let fractions = element
+            .isotopes()
+            .filter_map(|isotope| Some((isotope, isotope.isotopic_composition()?)))
+            .collect();
+        Self { element, fractions }
+    }
+
+    /// Natural composition enriched (or depleted) in one isotope
+    ///
+    /// Sets the amount fraction of `isotope` and rescales the natural
+    /// fractions of the other isotopes so that the fractions sum to one. Fails
+    /// with [`Error::NotNormalized`] if there are no other natural isotopes to
+    /// make up the rest.
+    pub fn enriched(isotope: Isotope, fraction: Uncertain) -> Result<Self> {
+        let element = isotope.element();
+        let mut composition = Self::natural(element);
+        let rest: f64 = composition
+            .iter()
+            .filter(|&(other, _)| other != isotope)
+            .map(|(_, fraction)| fraction.value)
+            .sum();
+        let scale = if rest > 0.0 {
+            (1.0 - fraction.value) / rest
+        } else if (1.0 - fraction.value).abs() < f64::EPSILON {
+            0.0
+        } else {
+            return Err(Error::NotNormalized {
+                element,
+                sum: fraction,
+            });
+        };
+        for other in composition.fractions.values_mut() {
+            *other = Uncertain::new(other.value * scale, other.uncertainty * scale);
+        }
+        composition.insert(isotope, fraction)?;
+        composition
+            .fractions
+            .retain(|_, fraction| fraction.value > 0.0);
+        Ok(composition)
+    }
+
+    /// Sets the amount fraction of an isotope
+    pub fn with(mut self, isotope: Isotope, fraction: Uncertain) -> Result<Self> {
+        self.insert(isotope, fraction)?;
+        Ok(self)
+    }
+
+    /// Sets the amount fraction of an isotope
+    pub fn insert(&mut self, isotope: Isotope, fraction: Uncertain) -> Result<()> {
+        if isotope.element() != self.element {
+            return Err(Error::ForeignIsotope {
+                element: self.element,
+                isotope,
+            });
+        }
+        if !(0.0..=1.0).contains(&fraction.value) {
+            return Err(Error::InvalidFraction { isotope, fraction });
+        }
+        self.fractions.insert(isotope, fraction);
+        Ok(())
+    }
+
+    /// Element
+    #[must_use]
+    pub const fn element(&self) -> Element {
+        self.element
+    }
+
+    /// Amount fraction of an isotope
+    #[must_use]
+    pub fn fraction(&self, isotope: Isotope) -> Option<Uncertain> {
+        self.fractions.get(&isotope).copied()
+    }
+
+    /// Isotopes and their amount fractions
+    pub fn iter(&self) -> impl Iterator<Item = (Isotope, Uncertain)> + '_ {
+        self.fractions
+            .iter()
+            .map(|(isotope, fraction)| (*isotope, *fraction))
+    }
+
+    /// Sum of the amount fractions
+    #[must_use]
+    pub fn sum(&self) -> Uncertain {
+        self.fractions
+            .values()
+            .fold(Uncertain::default(), |sum, fraction| sum + *fraction)
+    }
+
+    /// Normalizes the amount fractions to sum to one
+    pub fn normalize(&mut self) {
+        let sum = self.sum().value;
+        if sum > 0.0 {
+            for fraction in self.fractions.values_mut() {
+                *fraction = Uncertain::new(fraction.value / sum, fraction.uncertainty / sum);
+            }
+        }
+    }
+
+    /// Checks that the amount fractions sum to one within their uncertainty
+    pub fn validate(&self) -> Result<()> {
+        let sum = self.sum();
+        if (sum.value - 1.0).abs() > sum.uncertainty + TOLERANCE {
+            return Err(Error::NotNormalized {
+                element: self.element,
+                sum,
+            });
+        }
+        Ok(())
+    }
+
+    /// Atomic weight of the material
+    #[must_use]
+    pub fn atomic_weight(&self) -> Option<Uncertain> {
+        self.element
+            .isotopic_atomic_weight_with(|isotope| self.fraction(*isotope))
+    }
+}
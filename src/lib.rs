@@ -1,32 +1,78 @@
 #![feature(decl_macro)]
 
-#[cfg(feature = "nist")]
-pub use self::isotope::Isotope;
+#[cfg(feature = "nuclides")]
+pub use self::nuclide_table::NuclideTable;
 pub use self::{
+    activity::Radionuclide,
+    correction::Correction,
+    decay_mode::DecayMode,
     element::{Element, ELEMENTS},
     error::{Error, Result},
+    fractionation::{Evolution, Fractionation},
+    isomer::Isomer,
+    isotope::{Isotope, ISOTOPES},
+    isotope_ratio::IsotopeRatio,
+    isotopic_composition::{Consistency, IsotopicComposition},
+    mass_formula::MassFormula,
+    nuclide::Nuclide,
+    reference_material::ReferenceMaterial,
+    spin_parity::{Parity, SpinParity},
+    standard_atomic_weight::{AtomicWeight, Notes, Source},
+    systematic::Systematic,
     uncertain::{uncertain, Uncertain},
 };
 
-#[cfg(feature = "nist")]
 pub mod isotopes;
+#[cfg(feature = "serde")]
+pub mod serialization;
 
 pub mod prelude {
-    #[cfg(feature = "nist")]
-    pub use crate::isotope::Isotope;
-    #[cfg(feature = "nist")]
-    pub use crate::isotopes;
+    #[cfg(feature = "nuclides")]
+    pub use crate::nuclide_table::NuclideTable;
     pub use crate::{
+        activity::Radionuclide,
+        correction::Correction,
+        decay_mode::DecayMode,
         element::{Element, ELEMENTS},
         error::{Error, Result},
+        fractionation::{Evolution, Fractionation},
+        isomer::Isomer,
+        isotope::{Isotope, ISOTOPES},
+        isotope_ratio::IsotopeRatio,
+        isotopes,
+        isotopic_composition::{Consistency, IsotopicComposition},
+        mass_formula::MassFormula,
+        nuclide::Nuclide,
+        reference_material::ReferenceMaterial,
+        spin_parity::{Parity, SpinParity},
+        standard_atomic_weight::{AtomicWeight, Notes, Source},
+        systematic::Systematic,
         uncertain::{uncertain, Uncertain},
     };
 }
 
+mod activity;
+mod binding;
+mod code;
+mod correction;
+mod decay_mode;
 mod element;
 mod error;
-#[cfg(feature = "nist")]
+mod fractionation;
+mod interference;
+mod isomer;
 mod isotope;
+mod isotope_ratio;
+mod isotopic_composition;
+mod mass_formula;
+mod nmr;
+mod notation;
+mod nuclide;
+#[cfg(feature = "nuclides")]
+mod nuclide_table;
 mod periodic_table;
+mod reference_material;
+mod spin_parity;
 mod standard_atomic_weight;
+mod systematic;
 mod uncertain;
@@ -0,0 +1,151 @@
+use crate::{
+    binding::NEUTRON_MASS, isotope::ATOMIC_MASS_ENERGY, isotopes::H, Isotope, Nuclide, Uncertain,
+    ISOTOPES,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum::EnumIter;
+
+/// Semi-empirical mass formula
+///
+/// Bethe–Weizsäcker liquid-drop estimate of the binding energy of any
+/// nuclide:
+///
+/// B = aᵥA - aₛA²ᐟ³ - a꜀Z(Z - 1)/A¹ᐟ³ - aₐ(N - Z)²/A + δ
+///
+/// with the pairing term δ = +aₚ/Aᵏ for even-even, -aₚ/Aᵏ for odd-odd and 0
+/// for odd-A nuclides. The variants select the coefficient set. All energies
+/// are in keV.
+#[derive(Clone, Copy, Debug, Default, EnumIter, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MassFormula {
+    /// Rohlf, Modern Physics from α to Z⁰ (1994)
+    #[default]
+    Rohlf,
+    /// Krane, Introductory Nuclear Physics (1988)
+    Krane,
+    /// Wapstra, Handbuch der Physik 38 (1958)
+    Wapstra,
+}
+
+impl MassFormula {
+    /// Volume coefficient aᵥ
+    #[must_use]
+    pub const fn volume(&self) -> f64 {
+        match self {
+            Self::Rohlf => 15_750.0,
+            Self::Krane => 15_500.0,
+            Self::Wapstra => 14_100.0,
+        }
+    }
+
+    /// Surface coefficient aₛ
+    #[must_use]
+    pub const fn surface(&self) -> f64 {
+        match self {
+            Self::Rohlf => 17_800.0,
+            Self::Krane => 16_800.0,
+            Self::Wapstra => 13_000.0,
+        }
+    }
+
+    /// Coulomb coefficient a꜀
+    #[must_use]
+    pub const fn coulomb(&self) -> f64 {
+        match self {
+            Self::Rohlf => 711.0,
+            Self::Krane => 720.0,
+            Self::Wapstra => 595.0,
+        }
+    }
+
+    /// Asymmetry coefficient aₐ
+    #[must_use]
+    pub const fn asymmetry(&self) -> f64 {
+        match self {
+            Self::Rohlf => 23_700.0,
+            Self::Krane => 23_000.0,
+            Self::Wapstra => 19_000.0,
+        }
+    }
+
+    /// Pairing coefficient aₚ
+    #[must_use]
+    pub const fn pairing(&self) -> f64 {
+        match self {
+            Self::Rohlf => 11_180.0,
+            Self::Krane => 34_000.0,
+            Self::Wapstra => 33_500.0,
+        }
+    }
+
+    /// Exponent k of the mass number in the pairing term
+    #[must_use]
+    pub const fn pairing_exponent(&self) -> f64 {
+        match self {
+            Self::Rohlf => 0.5,
+            Self::Krane | Self::Wapstra => 0.75,
+        }
+    }
+
+    /// Binding energy in keV
+    #[must_use]
+    pub fn binding_energy(&self, nuclide: Nuclide) -> f64 {
+        let atomic_number = nuclide.atomic_number() as f64;
+        let neutron_number = nuclide.neutron_number() as f64;
+        let mass_number = nuclide.mass_number() as f64;
+        let pairing = match (
+            nuclide.atomic_number().is_multiple_of(2),
+            nuclide.neutron_number().is_multiple_of(2),
+        ) {
+            (true, true) => self.pairing(),
+            (false, false) => -self.pairing(),
+            _ => 0.0,
+        } / mass_number.powf(self.pairing_exponent());
+        self.volume() * mass_number
+            - self.surface() * mass_number.powf(2.0 / 3.0)
+            - self.coulomb() * atomic_number * (atomic_number - 1.0) / mass_number.cbrt()
+            - self.asymmetry() * (neutron_number - atomic_number).powi(2) / mass_number
+            + pairing
+    }
+
+    /// Relative atomic mass
+    ///
+    /// Mass of the neutral atom, M = Z · M(¹H) + N · mₙ - B/mᵤc².
+    #[must_use]
+    pub fn relative_atomic_mass(&self, nuclide: Nuclide) -> f64 {
+        let hydrogen = Isotope::H(H::One).relative_atomic_mass().value;
+        nuclide.atomic_number() as f64 * hydrogen
+            + nuclide.neutron_number() as f64 * NEUTRON_MASS.value
+            - self.binding_energy(nuclide) / ATOMIC_MASS_ENERGY
+    }
+
+    /// Residuals
+    ///
+    /// Difference between the tabulated and the predicted atomic mass of every
+    /// isotope, M - M(SEMF), in keV. The uncertainty is that of the tabulated
+    /// mass.
+    pub fn residuals(&self) -> impl Iterator<Item = (Isotope, Uncertain)> {
+        let formula = *self;
+        ISOTOPES.into_iter().map(move |isotope| {
+            let tabulated = isotope.relative_atomic_mass();
+            let predicted = formula.relative_atomic_mass(isotope.into());
+            let residual = Uncertain::new(
+                (tabulated.value - predicted) * ATOMIC_MASS_ENERGY,
+                tabulated.uncertainty * ATOMIC_MASS_ENERGY,
+            );
+            (isotope, residual)
+        })
+    }
+
+    /// Root-mean-square residual in keV
+    #[must_use]
+    pub fn rms_residual(&self) -> f64 {
+        let (sum, count) = self
+            .residuals()
+            .fold((0.0, 0), |(sum, count), (_, residual)| {
+                (sum + residual.value.powi(2), count + 1)
+            });
+        (sum / count as f64).sqrt()
+    }
+}
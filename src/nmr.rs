@@ -0,0 +1,191 @@
+use crate::{
+    isotopes::{
+        Ag, Al, Be, Ca, Cd, Cl, Co, Cr, Cs, Cu, Fe, He, Hg, Li, Mg, Mn, Na, Ne, Ni, Pb, Pt, Rh, Se,
+        Si, Sn, Te, Tl, Xe, Zn, B, C, F, H, K, N, O, P, S, V, W, Y,
+    },
+    Isotope,
+};
+use std::f64::consts::TAU;
+
+/// Nuclear magneton over the Planck constant μₙ/h in MHz/T (CODATA 2018)
+const NUCLEAR_MAGNETON: f64 = 7.622_593_229;
+
+/// NMR properties
+///
+/// Magnetic and quadrupole moments of the ground states of NMR-active
+/// isotopes (IUPAC 2001, Harris et al.), and the quantities derived from them.
+impl Isotope {
+    /// Magnetic moment μ in nuclear magnetons
+    ///
+    /// `None` for isotopes that are not tabulated as NMR-active.
+    #[must_use]
+    pub const fn magnetic_moment(&self) -> Option<f64> {
+        match self {
+            Isotope::H(H::One) => Some(2.792_847_34),
+            Isotope::H(H::Two) => Some(0.857_438_23),
+            Isotope::H(H::Three) => Some(2.978_962_5),
+            Isotope::He(He::Three) => Some(-2.127_497_7),
+            Isotope::Li(Li::Six) => Some(0.822_047_3),
+            Isotope::Li(Li::Seven) => Some(3.256_427),
+            Isotope::Be(Be::Nine) => Some(-1.177_432),
+            Isotope::B(B::Ten) => Some(1.800_644_8),
+            Isotope::B(B::Eleven) => Some(2.688_648_9),
+            Isotope::C(C::Thirteen) => Some(0.702_411_8),
+            Isotope::N(N::Fourteen) => Some(0.403_761_0),
+            Isotope::N(N::Fifteen) => Some(-0.283_188_8),
+            Isotope::O(O::Seventeen) => Some(-1.893_79),
+            Isotope::F(F::Nineteen) => Some(2.628_868),
+            Isotope::Ne(Ne::TwentyOne) => Some(-0.661_797),
+            Isotope::Na(Na::TwentyThree) => Some(2.217_522),
+            Isotope::Mg(Mg::TwentyFive) => Some(-0.855_45),
+            Isotope::Al(Al::TwentySeven) => Some(3.641_506_9),
+            Isotope::Si(Si::TwentyNine) => Some(-0.555_29),
+            Isotope::P(P::ThirtyOne) => Some(1.131_60),
+            Isotope::S(S::ThirtyThree) => Some(0.643_821_2),
+            Isotope::Cl(Cl::ThirtyFive) => Some(0.821_874_3),
+            Isotope::Cl(Cl::ThirtySeven) => Some(0.684_123_6),
+            Isotope::K(K::ThirtyNine) => Some(0.391_470_7),
+            Isotope::Ca(Ca::FortyThree) => Some(-1.317_643),
+            Isotope::V(V::FiftyOne) => Some(5.148_706),
+            Isotope::Cr(Cr::FiftyThree) => Some(-0.474_54),
+            Isotope::Mn(Mn::FiftyFive) => Some(3.468_72),
+            Isotope::Fe(Fe::FiftySeven) => Some(0.090_623_0),
+            Isotope::Co(Co::FiftyNine) => Some(4.627),
+            Isotope::Ni(Ni::SixtyOne) => Some(-0.750_02),
+            Isotope::Cu(Cu::SixtyThree) => Some(2.227_345_6),
+            Isotope::Cu(Cu::SixtyFive) => Some(2.381_67),
+            Isotope::Zn(Zn::SixtySeven) => Some(0.875_479),
+            Isotope::Se(Se::SeventySeven) => Some(0.535_06),
+            Isotope::Y(Y::EightyNine) => Some(-0.137_415_4),
+            Isotope::Rh(Rh::OneHundredThree) => Some(-0.088_4),
+            Isotope::Ag(Ag::OneHundredSeven) => Some(-0.113_679_65),
+            Isotope::Ag(Ag::OneHundredNine) => Some(-0.130_690_6),
+            Isotope::Cd(Cd::OneHundredEleven) => Some(-0.594_886_1),
+            Isotope::Cd(Cd::OneHundredThirteen) => Some(-0.622_300_9),
+            Isotope::Sn(Sn::OneHundredSeventeen) => Some(-1.001_04),
+            Isotope::Sn(Sn::OneHundredNineteen) => Some(-1.047_28),
+            Isotope::Te(Te::OneHundredTwentyFive) => Some(-0.888_505_1),
+            Isotope::Xe(Xe::OneHundredTwentyNine) => Some(-0.777_976),
+            Isotope::Cs(Cs::OneHundredThirtyThree) => Some(2.582_025),
+            Isotope::W(W::OneHundredEightyThree) => Some(0.117_784_76),
+            Isotope::Pt(Pt::OneHundredNinetyFive) => Some(0.609_52),
+            Isotope::Hg(Hg::OneHundredNinetyNine) => Some(0.505_885_49),
+            Isotope::Tl(Tl::TwoHundredThree) => Some(1.622_257_87),
+            Isotope::Tl(Tl::TwoHundredFive) => Some(1.638_214_61),
+            Isotope::Pb(Pb::TwoHundredSeven) => Some(0.592_58),
+            _ => None,
+        }
+    }
+
+    /// Electric quadrupole moment Q in fm²
+    ///
+    /// Zero for spin-1/2 isotopes, `None` for isotopes that are not tabulated
+    /// as NMR-active.
+    #[must_use]
+    pub const fn quadrupole_moment(&self) -> Option<f64> {
+        match self {
+            Isotope::H(H::One) => Some(0.0),
+            Isotope::H(H::Two) => Some(0.286),
+            Isotope::H(H::Three) => Some(0.0),
+            Isotope::He(He::Three) => Some(0.0),
+            Isotope::Li(Li::Six) => Some(-0.080_8),
+            Isotope::Li(Li::Seven) => Some(-4.01),
+            Isotope::Be(Be::Nine) => Some(5.288),
+            Isotope::B(B::Ten) => Some(8.459),
+            Isotope::B(B::Eleven) => Some(4.059),
+            Isotope::C(C::Thirteen) => Some(0.0),
+            Isotope::N(N::Fourteen) => Some(2.044),
+            Isotope::N(N::Fifteen) => Some(0.0),
+            Isotope::O(O::Seventeen) => Some(-2.558),
+            Isotope::F(F::Nineteen) => Some(0.0),
+            Isotope::Ne(Ne::TwentyOne) => Some(10.155),
+            Isotope::Na(Na::TwentyThree) => Some(10.4),
+            Isotope::Mg(Mg::TwentyFive) => Some(19.94),
+            Isotope::Al(Al::TwentySeven) => Some(14.66),
+            Isotope::Si(Si::TwentyNine) => Some(0.0),
+            Isotope::P(P::ThirtyOne) => Some(0.0),
+            Isotope::S(S::ThirtyThree) => Some(-6.78),
+            Isotope::Cl(Cl::ThirtyFive) => Some(-8.165),
+            Isotope::Cl(Cl::ThirtySeven) => Some(-6.435),
+            Isotope::K(K::ThirtyNine) => Some(5.85),
+            Isotope::Ca(Ca::FortyThree) => Some(-4.08),
+            Isotope::V(V::FiftyOne) => Some(-5.2),
+            Isotope::Cr(Cr::FiftyThree) => Some(-15.0),
+            Isotope::Mn(Mn::FiftyFive) => Some(33.0),
+            Isotope::Fe(Fe::FiftySeven) => Some(0.0),
+            Isotope::Co(Co::FiftyNine) => Some(42.0),
+            Isotope::Ni(Ni::SixtyOne) => Some(16.2),
+            Isotope::Cu(Cu::SixtyThree) => Some(-22.0),
+            Isotope::Cu(Cu::SixtyFive) => Some(-20.4),
+            Isotope::Zn(Zn::SixtySeven) => Some(15.0),
+            Isotope::Se(Se::SeventySeven) => Some(0.0),
+            Isotope::Y(Y::EightyNine) => Some(0.0),
+            Isotope::Rh(Rh::OneHundredThree) => Some(0.0),
+            Isotope::Ag(Ag::OneHundredSeven) => Some(0.0),
+            Isotope::Ag(Ag::OneHundredNine) => Some(0.0),
+            Isotope::Cd(Cd::OneHundredEleven) => Some(0.0),
+            Isotope::Cd(Cd::OneHundredThirteen) => Some(0.0),
+            Isotope::Sn(Sn::OneHundredSeventeen) => Some(0.0),
+            Isotope::Sn(Sn::OneHundredNineteen) => Some(0.0),
+            Isotope::Te(Te::OneHundredTwentyFive) => Some(0.0),
+            Isotope::Xe(Xe::OneHundredTwentyNine) => Some(0.0),
+            Isotope::Cs(Cs::OneHundredThirtyThree) => Some(-0.343),
+            Isotope::W(W::OneHundredEightyThree) => Some(0.0),
+            Isotope::Pt(Pt::OneHundredNinetyFive) => Some(0.0),
+            Isotope::Hg(Hg::OneHundredNinetyNine) => Some(0.0),
+            Isotope::Tl(Tl::TwoHundredThree) => Some(0.0),
+            Isotope::Tl(Tl::TwoHundredFive) => Some(0.0),
+            Isotope::Pb(Pb::TwoHundredSeven) => Some(0.0),
+            _ => None,
+        }
+    }
+
+    /// Gyromagnetic ratio γ in rad s⁻¹ T⁻¹
+    ///
+    /// γ = 2π · μ · (μₙ/h) / I
+    #[must_use]
+    pub fn gyromagnetic_ratio(&self) -> Option<f64> {
+        let spin = self.spin_parity()?.spin();
+        let magnetic_moment = self.magnetic_moment()?;
+        Some(TAU * magnetic_moment * NUCLEAR_MAGNETON * 1e6 / spin)
+    }
+
+    /// Larmor frequency in MHz at a magnetic field in T
+    ///
+    /// ν = |γ| · B / 2π
+    #[must_use]
+    pub fn larmor_frequency(&self, field: f64) -> Option<f64> {
+        Some(self.gyromagnetic_ratio()?.abs() * field / TAU / 1e6)
+    }
+
+    /// Larmor frequency in MHz on a spectrometer with the given ¹H frequency
+    /// in MHz
+    ///
+    /// ν = ν(¹H) · |γ / γ(¹H)|, for example 100.6 MHz for ¹³C at 400 MHz.
+    /// Being derived from the bare nuclear moments, it differs from the Ξ
+    /// scale, which refers to standard compounds, by up to about 0.1 %.
+    #[must_use]
+    pub fn frequency(&self, proton: f64) -> Option<f64> {
+        let reference = Self::H(H::One).gyromagnetic_ratio()?;
+        Some(proton * (self.gyromagnetic_ratio()? / reference).abs())
+    }
+
+    /// NMR receptivity relative to ¹H
+    ///
+    /// R = |γ|³ · x · I(I + 1), at the natural isotopic composition x,
+    /// relative to the same quantity for ¹H. `None` for isotopes without a
+    /// natural isotopic composition.
+    #[must_use]
+    pub fn receptivity(&self) -> Option<f64> {
+        let receptivity = |isotope: Self| {
+            let spin = isotope.spin_parity()?.spin();
+            Some(
+                isotope.gyromagnetic_ratio()?.abs().powi(3)
+                    * isotope.isotopic_composition()?.value
+                    * spin
+                    * (spin + 1.0),
+            )
+        };
+        Some(receptivity(*self)? / receptivity(Self::H(H::One))?)
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{isotopes::H, Element, Error, Isotope, Result, ELEMENTS};
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+const SUPERSCRIPTS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+impl Display for Isotope {
+    /// - `{}`: superscript, `¹³C`
+    /// - `{:#}`: name, `Carbon-13`
+    /// - `{:+}`: plain prefix, `13C`
+    /// - `{:-}`: hyphen, `C-13`
+    /// - `{:+#}`: LaTeX, `^{13}\mathrm{C}`
+    ///
+    /// Width, fill and alignment apply to the whole notation.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mass_number = self.mass_number();
+        let symbol = self.element().symbol();
+        let notation = match (f.alternate(), f.sign_plus(), f.sign_minus()) {
+            (true, true, _) => format!("^{{{mass_number}}}\\mathrm{{{symbol}}}"),
+            (true, false, _) => format!("{}-{mass_number}", self.element().name()),
+            (false, true, _) => format!("{mass_number}{symbol}"),
+            (false, false, true) => format!("{symbol}-{mass_number}"),
+            (false, false, false) => {
+                let mut notation: String = mass_number
+                    .to_string()
+                    .bytes()
+                    .map(|digit| SUPERSCRIPTS[(digit - b'0') as usize])
+                    .collect();
+                notation.push_str(symbol);
+                notation
+            }
+        };
+        f.pad(&notation)
+    }
+}
+
+impl FromStr for Isotope {
+    type Err = Error;
+
+    /// Parses `13C`, `[13C]`, `¹³C`, `C-13`, `C13`, `Carbon-13`, `D` and `T`
+    ///
+    /// A bare element symbol gives its most abundant isotope. Elements without
+    /// a natural isotopic composition give their last tabulated isotope.
+    fn from_str(value: &str) -> Result<Self> {
+        if let Some(inner) = value.strip_prefix('[') {
+            let inner = inner
+                .strip_suffix(']')
+                .ok_or_else(|| Error::unbalanced_bracket("[", 0))?;
+            return parse(inner).map_err(|error| error.with_offset(1));
+        }
+        if value.ends_with(']') {
+            return Err(Error::unbalanced_bracket("]", value.len() - 1));
+        }
+        parse(value)
+    }
+}
+
+fn parse(value: &str) -> Result<Isotope> {
+    match value {
+        "D" => return Ok(Isotope::H(H::Two)),
+        "T" => return Ok(Isotope::H(H::Three)),
+        _ => {}
+    }
+    // Prefix: 13C, ¹³C
+    let length = value
+        .chars()
+        .take_while(|&character| digit(character).is_some())
+        .map(char::len_utf8)
+        .sum();
+    if length > 0 {
+        let (prefix, symbol) = value.split_at(length);
+        let digits: String = prefix.chars().filter_map(digit).collect();
+        let element = symbol
+            .parse::<Element>()
+            .map_err(|error| error.with_offset(length))?;
+        return isotope(element, prefix, &digits, 0);
+    }
+    // Suffix: C13, C-13, Carbon-13
+    let length = value
+        .find(|character: char| !character.is_ascii_alphabetic())
+        .unwrap_or(value.len());
+    let (letters, rest) = value.split_at(length);
+    if rest.is_empty() {
+        let element = letters.parse::<Element>()?;
+        return element
+            .most_abundant_isotope()
+            .or_else(|| element.isotopes().last())
+            .ok_or_else(|| Error::unknown_symbol(value, 0, None::<&str>));
+    }
+    let element = letters.parse::<Element>().or_else(|error| {
+        ELEMENTS
+            .into_iter()
+            .find(|element| element.name().eq_ignore_ascii_case(letters))
+            .ok_or(error)
+    })?;
+    let (offset, digits) = match rest.strip_prefix('-') {
+        Some(digits) => (length + 1, digits),
+        None => (length, rest),
+    };
+    isotope(element, digits, digits, offset)
+}
+
+/// Isotope of the element with the mass number in `digits`
+///
+/// `text` is the original spelling of the mass number at `offset`.
+fn isotope(element: Element, text: &str, digits: &str, offset: usize) -> Result<Isotope> {
+    let mass_number = digits
+        .bytes()
+        .all(|byte| byte.is_ascii_digit())
+        .then(|| digits.parse::<usize>().ok())
+        .flatten();
+    if let Some(isotope) = mass_number.and_then(|mass_number| Isotope::new(element, mass_number)) {
+        return Ok(isotope);
+    }
+    // Nearest tabulated mass number
+    let suggestion = mass_number.and_then(|mass_number| {
+        element
+            .isotopes()
+            .map(|isotope| isotope.mass_number())
+            .min_by_key(|other| other.abs_diff(mass_number))
+    });
+    Err(Error::invalid_mass_number(text, offset, suggestion))
+}
+
+/// ASCII digit of an ASCII or superscript digit
+fn digit(character: char) -> Option<char> {
+    if character.is_ascii_digit() {
+        return Some(character);
+    }
+    let index = SUPERSCRIPTS.iter().position(|&other| other == character)?;
+    Some((b'0' + index as u8) as char)
+}
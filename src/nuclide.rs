@@ -0,0 +1,95 @@
+use crate::{Element, Isotope, Systematic, Uncertain, ELEMENTS};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+
+/// Nuclide
+///
+/// Ground state of any nucleus, identified by its atomic number Z and mass
+/// number A. Unlike [`Isotope`] it is not limited to the tabulated isotopes;
+/// Z = 0 is the free neutron.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Nuclide {
+    atomic_number: usize,
+    mass_number: usize,
+}
+
+impl Nuclide {
+    #[must_use]
+    pub const fn new(atomic_number: usize, mass_number: usize) -> Option<Self> {
+        if mass_number < atomic_number || mass_number == 0 {
+            return None;
+        }
+        Some(Self {
+            atomic_number,
+            mass_number,
+        })
+    }
+
+    /// Atomic number Z
+    #[must_use]
+    pub const fn atomic_number(&self) -> usize {
+        self.atomic_number
+    }
+
+    /// Mass number A
+    #[must_use]
+    pub const fn mass_number(&self) -> usize {
+        self.mass_number
+    }
+
+    /// Neutron number N
+    #[must_use]
+    pub const fn neutron_number(&self) -> usize {
+        self.mass_number - self.atomic_number
+    }
+
+    /// Element
+    #[must_use]
+    pub const fn element(&self) -> Option<Element> {
+        if self.atomic_number == 0 || self.atomic_number > ELEMENTS.len() {
+            return None;
+        }
+        Some(ELEMENTS[self.atomic_number - 1])
+    }
+
+    /// Isotope
+    ///
+    /// Tabulated isotope with the same atomic and mass number, if it exists.
+    #[must_use]
+    pub fn isotope(&self) -> Option<Isotope> {
+        Isotope::new(self.element()?, self.mass_number)
+    }
+
+    /// Relative atomic mass
+    ///
+    /// Of the tabulated isotope. A `NuclideTable` (`nuclides` feature) parsed
+    /// from an AME mass table covers the other nuclides.
+    #[must_use]
+    pub fn relative_atomic_mass(&self) -> Option<Uncertain> {
+        Some(self.isotope()?.relative_atomic_mass())
+    }
+}
+
+impl Display for Nuclide {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match Systematic::new(self.atomic_number) {
+            Some(systematic) => match systematic.element() {
+                Some(element) => write!(f, "{}{}", self.mass_number, element.symbol()),
+                None => write!(f, "{}{systematic}", self.mass_number),
+            },
+            None if self.mass_number == 1 => f.write_str("n"),
+            None => write!(f, "{}n", self.mass_number),
+        }
+    }
+}
+
+impl From<Isotope> for Nuclide {
+    fn from(value: Isotope) -> Self {
+        Self {
+            atomic_number: value.element().atomic_number(),
+            mass_number: value.mass_number(),
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{Error, Nuclide, Result, Uncertain, ISOTOPES};
+use std::{collections::BTreeMap, ops::Range};
+
+/// Columns of the AME mass table (`mass_1.mas20` format)
+const ATOMIC_NUMBER: Range<usize> = 9..14;
+const MASS_NUMBER: Range<usize> = 14..19;
+const INTEGER: Range<usize> = 106..109;
+const MICRO: Range<usize> = 110..123;
+const UNCERTAINTY: Range<usize> = 123..135;
+
+/// Micro atomic mass units per atomic mass unit
+const MICRO_PER_UNIT: f64 = 1e6;
+
+/// Nuclide table
+///
+/// Relative atomic masses of nuclides by (Z, A). A new table holds the
+/// isotopes of the [`isotopes`](crate::isotopes) module; a mass table of the
+/// Atomic Mass Evaluation (AME, distributed by the Atomic Mass Data Center)
+/// extends it with the nuclides it lists.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NuclideTable {
+    masses: BTreeMap<Nuclide, Mass>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Mass {
+    value: Uncertain,
+    estimated: bool,
+}
+
+impl NuclideTable {
+    /// Table of the tabulated isotopes
+    #[must_use]
+    pub fn new() -> Self {
+        let masses = ISOTOPES
+            .into_iter()
+            .map(|isotope| {
+                let mass = Mass {
+                    value: isotope.relative_atomic_mass(),
+                    estimated: false,
+                };
+                (Nuclide::from(isotope), mass)
+            })
+            .collect();
+        Self { masses }
+    }
+
+    /// Table of the tabulated isotopes extended with an AME mass table
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut table = Self::new();
+        table.extend_from_ame(text)?;
+        Ok(table)
+    }
+
+    /// Adds the nuclides of an AME mass table (`mass_1.mas20` format)
+    ///
+    /// Lines without a nuclide, like the header, are skipped. Masses from the
+    /// table replace the tabulated ones. Values that AME marks with `#` are
+    /// estimated rather than measured.
+    pub fn extend_from_ame(&mut self, text: &str) -> Result<()> {
+        for (index, line) in text.lines().enumerate() {
+            let integer = |range: Range<usize>| line.get(range)?.trim().parse::<usize>().ok();
+            let (Some(atomic_number), Some(mass_number)) =
+                (integer(ATOMIC_NUMBER), integer(MASS_NUMBER))
+            else {
+                continue;
+            };
+            let invalid = || Error::InvalidRecord {
+                text: line.to_owned(),
+                line: index + 1,
+            };
+            let nuclide = Nuclide::new(atomic_number, mass_number).ok_or_else(invalid)?;
+            let estimated = line.get(MICRO).is_some_and(|micro| micro.contains('#'));
+            let decimal = |range: Range<usize>| {
+                line.get(range)?
+                    .trim()
+                    .replace('#', ".")
+                    .parse::<f64>()
+                    .ok()
+            };
+            let (Some(integer), Some(micro), Some(uncertainty)) =
+                (integer(INTEGER), decimal(MICRO), decimal(UNCERTAINTY))
+            else {
+                return Err(invalid());
+            };
+            let value = Uncertain::new(
+                integer as f64 + micro / MICRO_PER_UNIT,
+                uncertainty / MICRO_PER_UNIT,
+            );
+            self.masses.insert(nuclide, Mass { value, estimated });
+        }
+        Ok(())
+    }
+
+    /// Relative atomic mass
+    #[must_use]
+    pub fn relative_atomic_mass(&self, nuclide: Nuclide) -> Option<Uncertain> {
+        Some(self.masses.get(&nuclide)?.value)
+    }
+
+    /// Whether the mass is estimated from systematic trends rather than
+    /// measured
+    #[must_use]
+    pub fn is_estimated(&self, nuclide: Nuclide) -> Option<bool> {
+        Some(self.masses.get(&nuclide)?.estimated)
+    }
+
+    /// Nuclides ordered by atomic and mass number
+    pub fn nuclides(&self) -> impl Iterator<Item = Nuclide> + '_ {
+        self.masses.keys().copied()
+    }
+
+    /// Isotopes of an element (nuclides with the atomic number)
+    pub fn isotopes(&self, atomic_number: usize) -> impl Iterator<Item = Nuclide> + '_ {
+        self.nuclides()
+            .filter(move |nuclide| nuclide.atomic_number() == atomic_number)
+    }
+
+    /// Number of nuclides
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.masses.len()
+    }
+
+    /// Whether the table is empty
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.masses.is_empty()
+    }
+}
+
+impl Default for NuclideTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{
+    isotopes::{B, C, H, N, O, S},
+    uncertain, Element, Isotope, Uncertain,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum::{EnumIter, IntoEnumIterator};
+
+// Hagemann et al. (1970), Li et al. (1988), Baertschi (1976)
+const VSMOW: &[(Isotope, Isotope, Uncertain)] = &[
+    (
+        Isotope::H(H::Two),
+        Isotope::H(H::One),
+        uncertain!(0.000_155_76, 0.000_000_05),
+    ),
+    (
+        Isotope::O(O::Seventeen),
+        Isotope::O(O::Sixteen),
+        uncertain!(0.000_379_9, 0.000_000_8),
+    ),
+    (
+        Isotope::O(O::Eighteen),
+        Isotope::O(O::Sixteen),
+        uncertain!(0.002_005_20, 0.000_000_45),
+    ),
+];
+
+// Hagemann et al. (1970), δ¹⁸O = -55.5 ‰ VSMOW
+const SLAP: &[(Isotope, Isotope, Uncertain)] = &[
+    (
+        Isotope::H(H::Two),
+        Isotope::H(H::One),
+        uncertain!(0.000_089_02, 0.000_000_05),
+    ),
+    (
+        Isotope::O(O::Eighteen),
+        Isotope::O(O::Sixteen),
+        uncertain!(0.001_893_91, 0.000_000_45),
+    ),
+];
+
+// Werner & Brand (2001)
+const VPDB: &[(Isotope, Isotope, Uncertain)] = &[
+    (
+        Isotope::C(C::Thirteen),
+        Isotope::C(C::Twelve),
+        uncertain!(0.011_180_2, 0.000_002_8),
+    ),
+    (
+        Isotope::O(O::Eighteen),
+        Isotope::O(O::Sixteen),
+        uncertain!(0.002_067_2, 0.000_002_1),
+    ),
+];
+
+// Werner & Brand (2001)
+const AIR: &[(Isotope, Isotope, Uncertain)] = &[(
+    Isotope::N(N::Fifteen),
+    Isotope::N(N::Fourteen),
+    uncertain!(0.003_678_2, 0.000_001_5),
+)];
+
+// Ding et al. (2001)
+const VCDT: &[(Isotope, Isotope, Uncertain)] = &[
+    (
+        Isotope::S(S::ThirtyThree),
+        Isotope::S(S::ThirtyTwo),
+        uncertain!(0.007_877_3, 0.000_001_5),
+    ),
+    (
+        Isotope::S(S::ThirtyFour),
+        Isotope::S(S::ThirtyTwo),
+        uncertain!(0.044_162_6, 0.000_007_8),
+    ),
+    (
+        Isotope::S(S::ThirtySix),
+        Isotope::S(S::ThirtyTwo),
+        uncertain!(0.000_153_3, 0.000_000_3),
+    ),
+];
+
+// Certificate of NIST SRM 951
+const SRM_951: &[(Isotope, Isotope, Uncertain)] = &[(
+    Isotope::B(B::Eleven),
+    Isotope::B(B::Ten),
+    uncertain!(4.043_62, 0.001_37),
+)];
+
+/// Reference material
+///
+/// International isotope reference material that anchors the delta scale of
+/// one or more elements.
+#[derive(Clone, Copy, Debug, EnumIter, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ReferenceMaterial {
+    /// Vienna Standard Mean Ocean Water
+    Vsmow,
+    /// Standard Light Antarctic Precipitation
+    Slap,
+    /// Vienna Pee Dee Belemnite
+    Vpdb,
+    /// Atmospheric nitrogen
+    Air,
+    /// Vienna Cañon Diablo Troilite
+    Vcdt,
+    /// NIST SRM 951 boric acid
+    Srm951,
+}
+
+impl ReferenceMaterial {
+    /// Name
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Vsmow => "VSMOW",
+            Self::Slap => "SLAP",
+            Self::Vpdb => "VPDB",
+            Self::Air => "AIR",
+            Self::Vcdt => "VCDT",
+            Self::Srm951 => "SRM 951",
+        }
+    }
+
+    /// Elements
+    #[must_use]
+    pub const fn elements(&self) -> &'static [Element] {
+        match self {
+            Self::Vsmow | Self::Slap => &[Element::H, Element::O],
+            Self::Vpdb => &[Element::C, Element::O],
+            Self::Air => &[Element::N],
+            Self::Vcdt => &[Element::S],
+            Self::Srm951 => &[Element::B],
+        }
+    }
+
+    /// Absolute isotope ratios
+    ///
+    /// Tabulated `(numerator, denominator, ratio)` triples.
+    #[must_use]
+    pub const fn ratios(&self) -> &'static [(Isotope, Isotope, Uncertain)] {
+        match self {
+            Self::Vsmow => VSMOW,
+            Self::Slap => SLAP,
+            Self::Vpdb => VPDB,
+            Self::Air => AIR,
+            Self::Vcdt => VCDT,
+            Self::Srm951 => SRM_951,
+        }
+    }
+
+    /// Absolute isotope ratio of `numerator` to `denominator`
+    ///
+    /// Inverse ratios of the tabulated ones are derived with the same
+    /// relative uncertainty.
+    #[must_use]
+    pub fn ratio(&self, numerator: Isotope, denominator: Isotope) -> Option<Uncertain> {
+        self.ratios()
+            .iter()
+            .find_map(|&(tabulated, reference, ratio)| {
+                if (tabulated, reference) == (numerator, denominator) {
+                    Some(ratio)
+                } else if (tabulated, reference) == (denominator, numerator) {
+                    let value = ratio.value.recip();
+                    Some(Uncertain::new(
+                        value,
+                        ratio.uncertainty * value / ratio.value,
+                    ))
+                } else {
+                    None
+                }
+            })
+    }
+
+    /// Reference materials of an element
+    pub fn of(element: Element) -> impl Iterator<Item = Self> {
+        Self::iter().filter(move |reference| reference.elements().contains(&element))
+    }
+}
@@ -0,0 +1,309 @@
+//! Serde representations
+//!
+//! In human-readable formats [`Element`] serializes as its symbol and
+//! [`Isotope`] as its plain notation (`"13C"`). Both deserialize leniently: an
+//! element from its symbol, name or atomic number, an isotope from any
+//! notation accepted by its `FromStr` or from its ZAID. [`Uncertain`]
+//! serializes as `{value, uncertainty}` and deserializes from that, from a
+//! concise string (`"12.011(1)"`, `"12.011 ± 0.001"`) or from a bare number.
+//!
+//! Binary formats, which need not be self-describing, get fixed
+//! representations instead: an element as its atomic number (`u8`), an
+//! isotope as its [ZAID](Isotope::zaid) (`u32`) and an uncertain value as the
+//! `(value, uncertainty)` struct.
+//!
+//! The [`atomic_number`] and [`concise`] modules are meant for
+//! `#[serde(with = "...")]` to pick the alternative representations.
+
+use crate::{Element, Isotope, Uncertain, ELEMENTS};
+use serde::{
+    de::{self, MapAccess, SeqAccess, Unexpected, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::fmt::{self, Formatter};
+
+impl Serialize for Element {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(self.symbol())
+        } else {
+            serializer.serialize_u8(*self as _)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Element {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ElementVisitor)
+        } else {
+            deserializer.deserialize_u8(ElementVisitor)
+        }
+    }
+}
+
+/// Element visitor
+struct ElementVisitor;
+
+impl Visitor<'_> for ElementVisitor {
+    type Value = Element;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("an element symbol, name or atomic number")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        usize::try_from(value)
+            .ok()
+            .and_then(|atomic_number| ELEMENTS.get(atomic_number.checked_sub(1)?))
+            .copied()
+            .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        u64::try_from(value)
+            .map_err(|_| E::invalid_value(Unexpected::Signed(value), &self))
+            .and_then(|value| self.visit_u64(value))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        value
+            .parse()
+            .or_else(|error| Element::suggest(value).ok_or(error))
+            .map_err(E::custom)
+    }
+}
+
+impl Serialize for Isotope {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&format_args!("{self:+}"))
+        } else {
+            serializer.serialize_u32(self.zaid())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Isotope {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(IsotopeVisitor)
+        } else {
+            deserializer.deserialize_u32(IsotopeVisitor)
+        }
+    }
+}
+
+/// Isotope visitor
+struct IsotopeVisitor;
+
+impl Visitor<'_> for IsotopeVisitor {
+    type Value = Isotope;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("an isotope notation or ZAID")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        u32::try_from(value)
+            .ok()
+            .and_then(Isotope::from_zaid)
+            .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        u64::try_from(value)
+            .map_err(|_| E::invalid_value(Unexpected::Signed(value), &self))
+            .and_then(|value| self.visit_u64(value))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        value.parse().map_err(E::custom)
+    }
+}
+
+/// Fields of [`Uncertain`]
+const FIELDS: &[&str] = &["value", "uncertainty"];
+
+impl Serialize for Uncertain {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Uncertain", FIELDS.len())?;
+        state.serialize_field("value", &self.value)?;
+        state.serialize_field("uncertainty", &self.uncertainty)?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Uncertain {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(UncertainVisitor)
+        } else {
+            deserializer.deserialize_struct("Uncertain", FIELDS, UncertainVisitor)
+        }
+    }
+}
+
+/// Uncertain visitor
+struct UncertainVisitor;
+
+impl<'de> Visitor<'de> for UncertainVisitor {
+    type Value = Uncertain;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("a number, a `value(uncertainty)` string or a {value, uncertainty} map")
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        Ok(Uncertain::new(value, 0.0))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(Uncertain::new(value as _, 0.0))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(Uncertain::new(value as _, 0.0))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        parse(value).ok_or_else(|| E::invalid_value(Unexpected::Str(value), &self))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let value = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let uncertainty = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Ok(Uncertain::new(value, uncertainty))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut value = None;
+        let mut uncertainty = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match &*key {
+                "value" if value.is_some() => return Err(de::Error::duplicate_field("value")),
+                "value" => value = Some(map.next_value()?),
+                "uncertainty" if uncertainty.is_some() => {
+                    return Err(de::Error::duplicate_field("uncertainty"));
+                }
+                "uncertainty" => uncertainty = Some(map.next_value()?),
+                _ => {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(Uncertain::new(
+            value.ok_or_else(|| de::Error::missing_field("value"))?,
+            uncertainty.unwrap_or_default(),
+        ))
+    }
+}
+
+/// Element as its atomic number
+///
+/// Deserializes as leniently as [`Element`] itself.
+pub mod atomic_number {
+    use crate::Element;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(element: &Element, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(*element as _)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Element, D::Error> {
+        Element::deserialize(deserializer)
+    }
+}
+
+/// Uncertain in concise notation
+///
+/// The uncertainty is written in units of the last digit of the value:
+/// `12.011(1)` is `12.011 ± 0.001`. In human-readable formats deserializes as
+/// leniently as [`Uncertain`] itself.
+pub mod concise {
+    use crate::Uncertain;
+    use serde::{
+        de::{Error, Unexpected},
+        Deserialize, Deserializer, Serializer,
+    };
+
+    pub fn serialize<S: Serializer>(
+        uncertain: &Uncertain,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&super::format(uncertain))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Uncertain, D::Error> {
+        if deserializer.is_human_readable() {
+            return Uncertain::deserialize(deserializer);
+        }
+        let text = String::deserialize(deserializer)?;
+        super::parse(&text).ok_or_else(|| {
+            D::Error::invalid_value(Unexpected::Str(&text), &"a concise uncertain value")
+        })
+    }
+}
+
+/// Format in concise notation
+///
+/// Uses as many decimals as needed to represent both the value and the
+/// uncertainty exactly.
+fn format(uncertain: &Uncertain) -> String {
+    let Uncertain { value, uncertainty } = *uncertain;
+    if uncertainty == 0.0 || !value.is_finite() || !uncertainty.is_finite() {
+        return value.to_string();
+    }
+    let decimals = decimals(&value.to_string())
+        .max(decimals(&uncertainty.to_string()))
+        .max(0) as usize;
+    let digits = (uncertainty * 10f64.powi(decimals as _)).round();
+    format!("{value:.decimals$}({digits})")
+}
+
+/// Parse concise or `±` notation
+fn parse(text: &str) -> Option<Uncertain> {
+    let text = text.trim();
+    if let Some((value, uncertainty)) = text.split_once('±').or_else(|| text.split_once("+-")) {
+        return Some(Uncertain::new(
+            value.trim().parse().ok()?,
+            uncertainty.trim().parse().ok()?,
+        ));
+    }
+    let Some((value, uncertainty)) = text.split_once('(') else {
+        return Some(Uncertain::new(text.parse().ok()?, 0.0));
+    };
+    let uncertainty = uncertainty.strip_suffix(')')?.trim();
+    let value = value.trim();
+    if uncertainty.contains('.') {
+        return Some(Uncertain::new(
+            value.parse().ok()?,
+            uncertainty.parse().ok()?,
+        ));
+    }
+    if uncertainty.is_empty() || !uncertainty.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    let digits = uncertainty.parse::<f64>().ok()?;
+    let decimals = decimals(value);
+    let uncertainty = if decimals < 0 {
+        digits * 10f64.powi(-decimals)
+    } else {
+        digits / 10f64.powi(decimals)
+    };
+    Some(Uncertain::new(value.parse().ok()?, uncertainty))
+}
+
+/// Number of decimals of a number literal, negative for positive exponents
+fn decimals(text: &str) -> i32 {
+    let (mantissa, exponent) = text.split_once(['e', 'E']).unwrap_or((text, "0"));
+    let fraction = mantissa
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len());
+    fraction as i32 - exponent.parse::<i32>().unwrap_or_default()
+}
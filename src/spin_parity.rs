@@ -0,0 +1,68 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+
+/// Parity
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Parity {
+    Positive,
+    Negative,
+}
+
+impl Display for Parity {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Positive => f.write_str("+"),
+            Self::Negative => f.write_str("-"),
+        }
+    }
+}
+
+/// Spin and parity
+///
+/// Total angular momentum J and parity π of a nuclear state, `7/2-` for
+/// J = 7/2 and negative parity. The spin is stored doubled to keep half-integer
+/// values exact.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SpinParity {
+    doubled: u8,
+    parity: Parity,
+}
+
+impl SpinParity {
+    /// Spin and parity from twice the spin
+    #[must_use]
+    pub const fn new(doubled: u8, parity: Parity) -> Self {
+        Self { doubled, parity }
+    }
+
+    /// Spin J
+    #[must_use]
+    pub const fn spin(&self) -> f64 {
+        self.doubled as f64 / 2.0
+    }
+
+    /// Twice the spin, 2J
+    #[must_use]
+    pub const fn doubled(&self) -> u8 {
+        self.doubled
+    }
+
+    /// Parity π
+    #[must_use]
+    pub const fn parity(&self) -> Parity {
+        self.parity
+    }
+}
+
+impl Display for SpinParity {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.doubled.is_multiple_of(2) {
+            write!(f, "{}{}", self.doubled / 2, self.parity)
+        } else {
+            write!(f, "{}/2{}", self.doubled, self.parity)
+        }
+    }
+}
@@ -1,20 +1,88 @@
 use crate::{
-    uncertain, Element,
+    isotopes, uncertain, Element,
     Element::{
-        Ag, Al, Ar, As, Au, Ba, Be, Bi, Br, Ca, Cd, Ce, Cl, Co, Cr, Cs, Cu, Dy, Er, Eu, Fe, Ga, Gd,
-        Ge, He, Hf, Hg, Ho, In, Ir, Kr, La, Li, Lu, Mg, Mn, Mo, Na, Nb, Nd, Ne, Ni, Os, Pa, Pb, Pd,
-        Pr, Pt, Rb, Re, Rh, Ru, Sb, Sc, Se, Si, Sm, Sn, Sr, Ta, Tb, Te, Th, Ti, Tl, Tm, Xe, Yb, Zn,
-        Zr, B, C, F, H, I, K, N, O, P, S, U, V, W, Y,
+        Ac, Ag, Al, Am, Ar, As, At, Au, Ba, Be, Bh, Bi, Bk, Br, Ca, Cd, Ce, Cf, Cl, Cm, Cn, Co, Cr,
+        Cs, Cu, Db, Ds, Dy, Er, Es, Eu, Fe, Fl, Fm, Fr, Ga, Gd, Ge, He, Hf, Hg, Ho, Hs, In, Ir, Kr,
+        La, Li, Lr, Lu, Lv, Mc, Md, Mg, Mn, Mo, Mt, Na, Nb, Nd, Ne, Nh, Ni, No, Np, Og, Os, Pa, Pb,
+        Pd, Pm, Po, Pr, Pt, Pu, Ra, Rb, Re, Rf, Rg, Rh, Rn, Ru, Sb, Sc, Se, Sg, Si, Sm, Sn, Sr, Ta,
+        Tb, Tc, Te, Th, Ti, Tl, Tm, Ts, Xe, Yb, Zn, Zr, B, C, F, H, I, K, N, O, P, S, U, V, W, Y,
     },
-    Uncertain,
+    Isotope, Uncertain, ELEMENTS,
 };
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+use strum::EnumIter;
+
+/// Source
+///
+/// Data source of the standard atomic weights. The IUPAC variants are the
+/// editions of the CIAAW table, each in effect until the next one.
+#[derive(Clone, Copy, Debug, Default, EnumIter, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Source {
+    /// NIST Atomic Weights and Isotopic Compositions
+    #[cfg_attr(not(feature = "iupac"), default)]
+    Nist,
+    /// IUPAC Standard Atomic Weights 2013
+    Iupac2013,
+    /// IUPAC Standard Atomic Weights 2017
+    ///
+    /// Includes the 2015 revision of Yb.
+    Iupac2017,
+    /// IUPAC Standard Atomic Weights 2019
+    Iupac2019,
+    /// IUPAC Standard Atomic Weights 2021
+    ///
+    /// Includes the 2020 revision of Pb.
+    #[cfg_attr(feature = "iupac", default)]
+    Iupac2021,
+}
+
+impl Source {
+    /// Default source
+    ///
+    /// [`Source::Iupac2021`] if the `iupac` feature is enabled, otherwise
+    /// [`Source::Nist`].
+    pub const DEFAULT: Self = if cfg!(feature = "iupac") {
+        Self::Iupac2021
+    } else {
+        Self::Nist
+    };
+
+    /// Changes
+    ///
+    /// Elements whose standard atomic weight differs between two sources.
+    pub fn changes(from: Self, to: Self) -> impl Iterator<Item = Element> {
+        ELEMENTS.into_iter().filter(move |element| {
+            element.standard_atomic_weight_from(from) != element.standard_atomic_weight_from(to)
+        })
+    }
+}
 
 impl Element {
     /// Standard atomic weight
     ///
-    /// Standard atomic weight of the element
+    /// Standard atomic weight of the element from the [default
+    /// source](Source::DEFAULT)
     #[must_use]
     pub const fn standard_atomic_weight(&self) -> Option<Uncertain> {
+        self.standard_atomic_weight_from(Source::DEFAULT)
+    }
+
+    /// Standard atomic weight from the given source
+    #[must_use]
+    pub const fn standard_atomic_weight_from(&self, source: Source) -> Option<Uncertain> {
+        match source {
+            // The NIST table reproduces the IUPAC 2013 values
+            Source::Nist | Source::Iupac2013 => self.nist(),
+            Source::Iupac2017 => self.iupac_2017(),
+            Source::Iupac2019 => self.iupac_2019(),
+            Source::Iupac2021 => self.iupac_2021(),
+        }
+    }
+
+    const fn nist(&self) -> Option<Uncertain> {
         match self {
             H => Some(uncertain!(1.007_84..1.008_11)),
             He => Some(uncertain!(4.002_602, 0.000_002)),
@@ -24,43 +92,25 @@ impl Element {
             C => Some(uncertain!(12.009_6..12.011_6)),
             N => Some(uncertain!(14.006_43..14.007_28)),
             O => Some(uncertain!(15.999_03..15.999_77)),
-            #[cfg(feature = "nist")]
             F => Some(uncertain!(18.998_403_163, 0.000_000_006)),
-            #[cfg(feature = "iupac")]
-            F => Some(uncertain!(18.998_403_162, 0.000_000_005)),
             Ne => Some(uncertain!(20.179_7, 0.000_6)),
             Na => Some(uncertain!(22.989_769_28, 0.000_000_02)),
             Mg => Some(uncertain!(24.304..24.307)),
-            #[cfg(feature = "nist")]
             Al => Some(uncertain!(26.981_538_5, 0.000_000_7)),
-            #[cfg(feature = "iupac")]
-            Al => Some(uncertain!(26.981_538_4, 0.000_000_3)),
             Si => Some(uncertain!(28.084..28.086)),
             P => Some(uncertain!(30.973_761_998, 0.000_000_005)),
             S => Some(uncertain!(32.059..32.076)),
             Cl => Some(uncertain!(35.446..35.457)),
-            #[cfg(feature = "nist")]
             Ar => Some(uncertain!(39.948, 0.001)),
-            #[cfg(feature = "iupac")]
-            Ar => Some(uncertain!(39.792..39.963)),
             K => Some(uncertain!(39.098_3, 0.000_1)),
             Ca => Some(uncertain!(40.078, 0.004)),
-            #[cfg(feature = "nist")]
             Sc => Some(uncertain!(44.955_908, 0.000_005)),
-            #[cfg(feature = "iupac")]
-            Sc => Some(uncertain!(44.955_907, 0.000_004)),
             Ti => Some(uncertain!(47.867, 0.001)),
             V => Some(uncertain!(50.941_5, 0.000_1)),
             Cr => Some(uncertain!(51.996_1, 0.000_6)),
-            #[cfg(feature = "nist")]
             Mn => Some(uncertain!(54.938_044, 0.000_003)),
-            #[cfg(feature = "iupac")]
-            Mn => Some(uncertain!(54.938_043, 0.000_002)),
             Fe => Some(uncertain!(55.845, 0.002)),
-            #[cfg(feature = "nist")]
             Co => Some(uncertain!(58.933_194, 0.000_004)),
-            #[cfg(feature = "iupac")]
-            Co => Some(uncertain!(58.933_194, 0.000_003)),
             Ni => Some(uncertain!(58.693_4, 0.000_4)),
             Cu => Some(uncertain!(63.546, 0.003)),
             Zn => Some(uncertain!(65.38, 0.02)),
@@ -72,21 +122,12 @@ impl Element {
             Kr => Some(uncertain!(83.798, 0.002)),
             Rb => Some(uncertain!(85.467_8, 0.000_3)),
             Sr => Some(uncertain!(87.62, 0.01)),
-            #[cfg(feature = "nist")]
             Y => Some(uncertain!(88.905_84, 0.000_02)),
-            #[cfg(feature = "iupac")]
-            Y => Some(uncertain!(88.905_838, 0.000_002)),
             Zr => Some(uncertain!(91.224, 0.002)),
-            #[cfg(feature = "nist")]
             Nb => Some(uncertain!(92.906_37, 0.000_02)),
-            #[cfg(feature = "iupac")]
-            Nb => Some(uncertain!(92.906_37, 0.000_01)),
             Mo => Some(uncertain!(95.95, 0.01)),
             Ru => Some(uncertain!(101.07, 0.02)),
-            #[cfg(feature = "nist")]
             Rh => Some(uncertain!(102.905_50, 0.000_02)),
-            #[cfg(feature = "iupac")]
-            Rh => Some(uncertain!(102.905_49, 0.000_02)),
             Pd => Some(uncertain!(106.42, 0.01)),
             Ag => Some(uncertain!(107.868_2, 0.000_2)),
             Cd => Some(uncertain!(112.414, 0.004)),
@@ -100,82 +141,328 @@ impl Element {
             Ba => Some(uncertain!(137.327, 0.007)),
             La => Some(uncertain!(138.905_47, 0.000_07)),
             Ce => Some(uncertain!(140.116, 0.001)),
-            #[cfg(feature = "nist")]
             Pr => Some(uncertain!(140.907_66, 0.000_02)),
-            #[cfg(feature = "iupac")]
-            Pr => Some(uncertain!(140.907_66, 0.000_01)),
             Nd => Some(uncertain!(144.242, 0.003)),
             Sm => Some(uncertain!(150.36, 0.02)),
             Eu => Some(uncertain!(151.964, 0.001)),
             Gd => Some(uncertain!(157.25, 0.03)),
-            #[cfg(feature = "nist")]
             Tb => Some(uncertain!(158.925_35, 0.000_02)),
-            #[cfg(feature = "iupac")]
-            Tb => Some(uncertain!(158.925_354, 0.000_007)),
             Dy => Some(uncertain!(162.500, 0.001)),
-            #[cfg(feature = "nist")]
             Ho => Some(uncertain!(164.930_33, 0.000_02)),
-            #[cfg(feature = "iupac")]
-            Ho => Some(uncertain!(164.930_329, 0.000_005)),
             Er => Some(uncertain!(167.259, 0.003)),
-            #[cfg(feature = "nist")]
             Tm => Some(uncertain!(168.934_22, 0.000_02)),
-            #[cfg(feature = "iupac")]
-            Tm => Some(uncertain!(168.934_219, 0.000_005)),
-            #[cfg(feature = "nist")]
             Yb => Some(uncertain!(173.054, 0.005)),
-            #[cfg(feature = "iupac")]
-            Yb => Some(uncertain!(173.045, 0.010)),
             Lu => Some(uncertain!(174.966_8, 0.000_1)),
-            #[cfg(feature = "nist")]
             Hf => Some(uncertain!(178.49, 0.02)),
-            #[cfg(feature = "iupac")]
-            Hf => Some(uncertain!(178.486, 0.006)),
             Ta => Some(uncertain!(180.947_88, 0.000_02)),
             W => Some(uncertain!(183.84, 0.01)),
             Re => Some(uncertain!(186.207, 0.001)),
             Os => Some(uncertain!(190.23, 0.03)),
-            #[cfg(feature = "nist")]
             Ir => Some(uncertain!(192.217, 0.003)),
-            #[cfg(feature = "iupac")]
-            Ir => Some(uncertain!(192.217, 0.002)),
             Pt => Some(uncertain!(195.084, 0.009)),
-            #[cfg(feature = "nist")]
             Au => Some(uncertain!(196.966_569, 0.000_005)),
-            #[cfg(feature = "iupac")]
-            Au => Some(uncertain!(196.966_570, 0.000_004)),
             Hg => Some(uncertain!(200.592, 0.003)),
             Tl => Some(uncertain!(204.382..204.385)),
-            #[cfg(feature = "nist")]
             Pb => Some(uncertain!(207.2, 0.1)),
-            #[cfg(feature = "iupac")]
-            Pb => Some(uncertain!(206.14..207.94)),
             Bi => Some(uncertain!(208.980_40, 0.000_01)),
             Th => Some(uncertain!(232.037_7, 0.000_4)),
-            #[cfg(feature = "nist")]
             Pa => Some(uncertain!(231.035_88, 0.000_02)),
-            #[cfg(feature = "iupac")]
-            Pa => Some(uncertain!(231.035_88, 0.000_01)),
             U => Some(uncertain!(238.028_91, 0.000_03)),
             _ => None,
         }
     }
+
+    /// Values revised since [`Source::Iupac2013`]
+    const fn iupac_2017(&self) -> Option<Uncertain> {
+        match self {
+            Ar => Some(uncertain!(39.792..39.963)),
+            Yb => Some(uncertain!(173.045, 0.010)),
+            Ir => Some(uncertain!(192.217, 0.002)),
+            _ => self.nist(),
+        }
+    }
+
+    /// Values revised since [`Source::Iupac2017`]
+    const fn iupac_2019(&self) -> Option<Uncertain> {
+        match self {
+            Hf => Some(uncertain!(178.486, 0.006)),
+            _ => self.iupac_2017(),
+        }
+    }
+
+    /// Values revised since [`Source::Iupac2019`]
+    const fn iupac_2021(&self) -> Option<Uncertain> {
+        match self {
+            F => Some(uncertain!(18.998_403_162, 0.000_000_005)),
+            Al => Some(uncertain!(26.981_538_4, 0.000_000_3)),
+            Sc => Some(uncertain!(44.955_907, 0.000_004)),
+            Mn => Some(uncertain!(54.938_043, 0.000_002)),
+            Co => Some(uncertain!(58.933_194, 0.000_003)),
+            Y => Some(uncertain!(88.905_838, 0.000_002)),
+            Nb => Some(uncertain!(92.906_37, 0.000_01)),
+            Rh => Some(uncertain!(102.905_49, 0.000_02)),
+            Pr => Some(uncertain!(140.907_66, 0.000_01)),
+            Tb => Some(uncertain!(158.925_354, 0.000_007)),
+            Ho => Some(uncertain!(164.930_329, 0.000_005)),
+            Tm => Some(uncertain!(168.934_219, 0.000_005)),
+            Au => Some(uncertain!(196.966_570, 0.000_004)),
+            Pb => Some(uncertain!(206.14..207.94)),
+            Pa => Some(uncertain!(231.035_88, 0.000_01)),
+            _ => self.iupac_2019(),
+        }
+    }
+}
+
+/// Atomic weight
+///
+/// Standard atomic weight of an element with the notes of its source or, for
+/// an element without one, its longest-lived isotope (printed as `[98]` for
+/// Tc in periodic tables).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AtomicWeight {
+    Standard(
+        Uncertain,
+        #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_notes"))]
+        &'static [Notes],
+    ),
+    MostStable(Isotope),
 }
 
-// Tc None,
-// Pm None,
-// Po None,
-// At None,
-// Rn None,
-// Fr None,
-// Ra None,
-// Ac None,
-// Np None,
-// Pu None,
-// /// Mass number of the most stable isotope
-// #[cfg(feature = "nist")]
-// MostStable(usize),
-// #[cfg(feature = "nist")]
-// const fn most_stable(value: usize) -> StandardAtomicWeight {
-//     StandardAtomicWeight::MostStable(value)
-// }
+impl AtomicWeight {
+    /// Value
+    ///
+    /// Standard atomic weight or relative atomic mass of the most stable
+    /// isotope.
+    #[must_use]
+    pub const fn value(&self) -> Uncertain {
+        match self {
+            Self::Standard(standard, _) => *standard,
+            Self::MostStable(isotope) => isotope.relative_atomic_mass(),
+        }
+    }
+
+    /// Mass number of the most stable isotope
+    #[must_use]
+    pub const fn mass_number(&self) -> Option<usize> {
+        match self {
+            Self::Standard(..) => None,
+            Self::MostStable(isotope) => Some(isotope.mass_number()),
+        }
+    }
+
+    /// Notes
+    ///
+    /// Notes of the standard atomic weight, empty for the most stable isotope.
+    #[must_use]
+    pub const fn notes(&self) -> &'static [Notes] {
+        match self {
+            Self::Standard(_, notes) => notes,
+            Self::MostStable(_) => &[],
+        }
+    }
+}
+
+impl Display for AtomicWeight {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Standard(standard, _) => Display::fmt(standard, f),
+            Self::MostStable(isotope) => write!(f, "[{}]", isotope.mass_number()),
+        }
+    }
+}
+
+impl Element {
+    /// Atomic weight
+    ///
+    /// Atomic weight of the element from the [default
+    /// source](Source::DEFAULT)
+    #[must_use]
+    pub const fn atomic_weight(&self) -> Option<AtomicWeight> {
+        self.atomic_weight_from(Source::DEFAULT)
+    }
+
+    /// Atomic weight from the given source
+    ///
+    /// With the notes of the source. Falls back to the most stable isotope if
+    /// the source gives no standard atomic weight, `None` if there is neither.
+    #[must_use]
+    pub const fn atomic_weight_from(&self, source: Source) -> Option<AtomicWeight> {
+        match self.standard_atomic_weight_from(source) {
+            Some(standard) => Some(AtomicWeight::Standard(standard, self.notes_from(source))),
+            None => match self.most_stable_isotope() {
+                Some(isotope) => Some(AtomicWeight::MostStable(isotope)),
+                None => None,
+            },
+        }
+    }
+
+    /// Most stable isotope
+    ///
+    /// Longest-lived isotope of an element without stable isotopes.
+    #[must_use]
+    pub const fn most_stable_isotope(&self) -> Option<Isotope> {
+        match self {
+            Tc => Some(Isotope::Tc(isotopes::Tc::NinetyEight)),
+            Pm => Some(Isotope::Pm(isotopes::Pm::OneHundredFortyFive)),
+            Bi => Some(Isotope::Bi(isotopes::Bi::TwoHundredNine)),
+            Po => Some(Isotope::Po(isotopes::Po::TwoHundredNine)),
+            At => Some(Isotope::At(isotopes::At::TwoHundredTen)),
+            Rn => Some(Isotope::Rn(isotopes::Rn::TwoHundredTwentyTwo)),
+            Fr => Some(Isotope::Fr(isotopes::Fr::TwoHundredTwentyThree)),
+            Ra => Some(Isotope::Ra(isotopes::Ra::TwoHundredTwentySix)),
+            Ac => Some(Isotope::Ac(isotopes::Ac::TwoHundredTwentySeven)),
+            Th => Some(Isotope::Th(isotopes::Th::TwoHundredThirtyTwo)),
+            Pa => Some(Isotope::Pa(isotopes::Pa::TwoHundredThirtyOne)),
+            U => Some(Isotope::U(isotopes::U::TwoHundredThirtyEight)),
+            Np => Some(Isotope::Np(isotopes::Np::TwoHundredThirtySeven)),
+            Pu => Some(Isotope::Pu(isotopes::Pu::TwoHundredFortyFour)),
+            Am => Some(Isotope::Am(isotopes::Am::TwoHundredFortyThree)),
+            Cm => Some(Isotope::Cm(isotopes::Cm::TwoHundredFortySeven)),
+            Bk => Some(Isotope::Bk(isotopes::Bk::TwoHundredFortySeven)),
+            Cf => Some(Isotope::Cf(isotopes::Cf::TwoHundredFiftyOne)),
+            Es => Some(Isotope::Es(isotopes::Es::TwoHundredFiftyTwo)),
+            Fm => Some(Isotope::Fm(isotopes::Fm::TwoHundredFiftySeven)),
+            Md => Some(Isotope::Md(isotopes::Md::TwoHundredFiftyEight)),
+            No => Some(Isotope::No(isotopes::No::TwoHundredFiftyNine)),
+            Lr => Some(Isotope::Lr(isotopes::Lr::TwoHundredSixtyTwo)),
+            Rf => Some(Isotope::Rf(isotopes::Rf::TwoHundredSixtySeven)),
+            Db => Some(Isotope::Db(isotopes::Db::TwoHundredSixtyEight)),
+            Sg => Some(Isotope::Sg(isotopes::Sg::TwoHundredSeventyOne)),
+            Bh => Some(Isotope::Bh(isotopes::Bh::TwoHundredSeventyTwo)),
+            Hs => Some(Isotope::Hs(isotopes::Hs::TwoHundredSeventy)),
+            Mt => Some(Isotope::Mt(isotopes::Mt::TwoHundredSeventySix)),
+            Ds => Some(Isotope::Ds(isotopes::Ds::TwoHundredEightyOne)),
+            Rg => Some(Isotope::Rg(isotopes::Rg::TwoHundredEighty)),
+            Cn => Some(Isotope::Cn(isotopes::Cn::TwoHundredEightyFive)),
+            Nh => Some(Isotope::Nh(isotopes::Nh::TwoHundredEightyFour)),
+            Fl => Some(Isotope::Fl(isotopes::Fl::TwoHundredEightyNine)),
+            Mc => Some(Isotope::Mc(isotopes::Mc::TwoHundredEightyEight)),
+            Lv => Some(Isotope::Lv(isotopes::Lv::TwoHundredNinetyThree)),
+            Ts => Some(Isotope::Ts(isotopes::Ts::TwoHundredNinetyTwo)),
+            Og => Some(Isotope::Og(isotopes::Og::TwoHundredNinetyFour)),
+            _ => None,
+        }
+    }
+}
+
+/// Notes
+///
+/// Notes for Representative Isotopic Composition and Standard Atomic Weight.
+///
+/// - g - Geological materials are known in which the element has an isotopic
+///   composition outside the limits for normal material. The difference between
+///   the atomic weight of the element in such materials and that given in the
+///   table may exceed the stated uncertainty.
+/// - m - Modified isotopic compositions may be found in commercially available
+///   material because the material has been subjected to an undisclosed or
+///   inadvertent isotopic fractionation. Substantial deviations in atomic
+///   weight of the element from that given in the table can occur.
+/// - r - Range in isotopic composition of normal terrestrial material prevents
+///   a more precise standard atomic weight being given; the tabulated
+///   atomic-weight value and uncertainty should be applicable to normal
+///   materials.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Notes {
+    G,
+    M,
+    R,
+}
+
+impl Notes {
+    /// Description
+    #[must_use]
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::G => {
+                "Geological materials are known in which the element has an isotopic composition \
+                 outside the limits for normal material."
+            }
+            Self::M => {
+                "Modified isotopic compositions may be found in commercially available material."
+            }
+            Self::R => {
+                "Range in isotopic composition of normal terrestrial material prevents a more \
+                 precise standard atomic weight being given."
+            }
+        }
+    }
+}
+
+impl Display for Notes {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::G => f.write_str("g"),
+            Self::M => f.write_str("m"),
+            Self::R => f.write_str("r"),
+        }
+    }
+}
+
+impl Element {
+    /// Notes
+    ///
+    /// Notes for the representative isotopic composition and the standard
+    /// atomic weight of the element from the [default source](Source::DEFAULT).
+    #[must_use]
+    pub const fn notes(&self) -> &'static [Notes] {
+        self.notes_from(Source::DEFAULT)
+    }
+
+    /// Notes from the given source
+    ///
+    /// The IUPAC editions give the range of normal materials as an interval
+    /// instead of noting it with r.
+    #[must_use]
+    pub const fn notes_from(&self, source: Source) -> &'static [Notes] {
+        use Notes::{G, M};
+
+        match (source, self) {
+            (Source::Nist, _) => self.nist_notes(),
+            (_, H | Li | B) => &[G, M],
+            (_, C | N | O | S) => &[G],
+            (_, Si) => &[],
+            (Source::Iupac2017 | Source::Iupac2019 | Source::Iupac2021, Ar)
+            | (Source::Iupac2021, Pb) => &[G],
+            _ => self.nist_notes(),
+        }
+    }
+
+    const fn nist_notes(&self) -> &'static [Notes] {
+        use Notes::{G, M, R};
+
+        match self {
+            H | Li | B => &[G, M, R],
+            He | C | N | O | S | Ar | Sr | Pb => &[G, R],
+            Ne | Kr | Xe | U => &[G, M],
+            Si | Ni | Cu | Zn | Se => &[R],
+            Cl => &[M],
+            Ca | Rb | Zr | Mo | Ru | Pd | Ag | Cd | Sn | Sb | Te | La | Ce | Nd | Sm | Eu | Gd
+            | Dy | Er | Yb | Lu | Os | Th => &[G],
+            _ => &[],
+        }
+    }
+}
+
+/// Notes as one of the static combinations the tables use
+#[cfg(feature = "serde")]
+fn deserialize_notes<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<&'static [Notes], D::Error> {
+    use Notes::{G, M, R};
+
+    let mut notes = Vec::<Notes>::deserialize(deserializer)?;
+    notes.sort_unstable();
+    notes.dedup();
+    Ok(match notes[..] {
+        [] => &[],
+        [G] => &[G],
+        [M] => &[M],
+        [R] => &[R],
+        [G, M] => &[G, M],
+        [G, R] => &[G, R],
+        [M, R] => &[M, R],
+        _ => &[G, M, R],
+    })
+}
@@ -0,0 +1,170 @@
+use crate::{Element, Error, Result, ELEMENTS};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+const ROOTS: [&str; 10] = [
+    "nil", "un", "bi", "tri", "quad", "pent", "hex", "sept", "oct", "enn",
+];
+
+/// Systematic element
+///
+/// IUPAC systematic name and symbol of an element built from the digits of
+/// its atomic number (`Ununennium`/`Uue` for 119). Unlike [`Element`] it is
+/// not bounded by `Og`, so it covers hypothetical elements as well.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Systematic(usize);
+
+impl Systematic {
+    /// New systematic element
+    ///
+    /// Atomic numbers beyond `Og` are accepted. `None` if `atomic_number` is 0.
+    #[must_use]
+    pub const fn new(atomic_number: usize) -> Option<Self> {
+        if atomic_number == 0 {
+            return None;
+        }
+        Some(Self(atomic_number))
+    }
+
+    /// Atomic number
+    #[must_use]
+    pub const fn atomic_number(&self) -> usize {
+        self.0
+    }
+
+    /// Element
+    ///
+    /// Named element with the same atomic number, if it exists.
+    #[must_use]
+    pub const fn element(&self) -> Option<Element> {
+        if self.0 > ELEMENTS.len() {
+            return None;
+        }
+        Some(ELEMENTS[self.0 - 1])
+    }
+
+    /// Name
+    #[must_use]
+    pub fn name(&self) -> String {
+        let mut name = String::new();
+        for digit in self.digits() {
+            let root = ROOTS[digit];
+            // "enn" followed by "nil" drops one "n"
+            if name.ends_with("nn") && root == "nil" {
+                name.pop();
+            }
+            name.push_str(root);
+        }
+        // "bi" and "tri" followed by "ium" drop the "i"
+        if name.ends_with('i') {
+            name.pop();
+        }
+        name.push_str("ium");
+        capitalize(&name)
+    }
+
+    /// Symbol
+    #[must_use]
+    pub fn symbol(&self) -> String {
+        let symbol: String = self.digits().map(|digit| &ROOTS[digit][..1]).collect();
+        capitalize(&symbol)
+    }
+
+    fn digits(&self) -> impl Iterator<Item = usize> {
+        self.0
+            .to_string()
+            .into_bytes()
+            .into_iter()
+            .map(|digit| (digit - b'0') as _)
+    }
+}
+
+impl Display for Systematic {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if f.alternate() {
+            Display::fmt(&self.name(), f)
+        } else {
+            Display::fmt(&self.symbol(), f)
+        }
+    }
+}
+
+impl From<Element> for Systematic {
+    fn from(value: Element) -> Self {
+        Self(value.atomic_number())
+    }
+}
+
+impl FromStr for Systematic {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        parse(value).ok_or_else(|| {
+            let trimmed = value.trim();
+            let suggestion =
+                parse(&capitalize(&trimmed.to_lowercase())).map(|systematic| systematic.symbol());
+            let offset = value.len() - value.trim_start().len();
+            Error::unknown_symbol(trimmed, offset, suggestion)
+        })
+    }
+}
+
+impl TryFrom<Systematic> for Element {
+    type Error = Error;
+
+    fn try_from(value: Systematic) -> Result<Self> {
+        value
+            .element()
+            .ok_or_else(|| Error::unknown_symbol(&value.symbol(), 0, None::<&str>))
+    }
+}
+
+/// Parses either a systematic name or a systematic symbol
+fn parse(value: &str) -> Option<Systematic> {
+    let lowercase = value.to_lowercase();
+    // Name
+    if let Some(stem) = lowercase.strip_suffix("um") {
+        // "bi" and "tri" already end in "i"
+        let stem = match stem.strip_suffix('i') {
+            Some(root) if !root.ends_with('b') && !root.ends_with("tr") => root,
+            _ => stem,
+        };
+        let stem = stem.replace("ennil", "ennnil");
+        let mut atomic_number = 0;
+        let mut rest = &*stem;
+        while !rest.is_empty() {
+            let (digit, root) = ROOTS
+                .iter()
+                .enumerate()
+                .find(|(_, root)| rest.starts_with(*root))?;
+            atomic_number = push(atomic_number, digit)?;
+            rest = &rest[root.len()..];
+        }
+        return Systematic::new(atomic_number)
+            .filter(|systematic| systematic.name() == capitalize(&lowercase));
+    }
+    // Symbol
+    let mut atomic_number = 0;
+    for initial in lowercase.chars() {
+        let digit = ROOTS.iter().position(|root| root.starts_with(initial))?;
+        atomic_number = push(atomic_number, digit)?;
+    }
+    Systematic::new(atomic_number).filter(|systematic| systematic.symbol() == value)
+}
+
+fn push(atomic_number: usize, digit: usize) -> Option<usize> {
+    atomic_number.checked_mul(10)?.checked_add(digit)
+}
+
+fn capitalize(value: &str) -> String {
+    let mut chars = value.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,86 @@
+use atom::{
+    isotopes::{Ra, C},
+    uncertain, Isomer, Isotope, Radionuclide,
+};
+use std::time::{Duration, SystemTime};
+
+// ¹⁸F (NUBASE 2020, AME 2020), not tabulated
+const F18: Radionuclide = Radionuclide::new(
+    uncertain!(6_584.04, 0.48),
+    uncertain!(18.000_937_324, 0.000_000_497),
+);
+
+#[test]
+fn radionuclide() {
+    assert_eq!(Isotope::C(C::Twelve).radionuclide(), None);
+    let c14 = Isotope::C(C::Fourteen).radionuclide().unwrap();
+    assert_eq!(
+        c14.half_life(),
+        Isotope::C(C::Fourteen).half_life().unwrap()
+    );
+    let tc99m: Isomer = "99mTc".parse().unwrap();
+    let radionuclide = tc99m.radionuclide().unwrap();
+    assert!((radionuclide.half_life().value - 6.006_7 * 3_600.0).abs() < 1e-6);
+    assert!(
+        radionuclide.relative_atomic_mass().value
+            > tc99m.isotope().unwrap().relative_atomic_mass().value
+    );
+    // Not observed to decay
+    assert_eq!("180mTa".parse::<Isomer>().unwrap().radionuclide(), None);
+}
+
+#[test]
+fn specific_activity() {
+    // The curie was defined as the activity of 1 g of ²²⁶Ra
+    let ra226 = Isotope::Ra(Ra::TwoHundredTwentySix).radionuclide().unwrap();
+    let specific_activity = Radionuclide::to_curies(ra226.specific_activity());
+    assert!((specific_activity.value - 0.989).abs() < 1e-3);
+    assert!(specific_activity.uncertainty > 0.0);
+    let c14 = Isotope::C(C::Fourteen).radionuclide().unwrap();
+    assert!((Radionuclide::to_curies(c14.specific_activity()).value - 4.48).abs() < 1e-2);
+    let tc99m = "99mTc".parse::<Isomer>().unwrap().radionuclide().unwrap();
+    assert!((tc99m.specific_activity().value / 1.95e17 - 1.0).abs() < 1e-2);
+}
+
+#[test]
+fn conversions() {
+    let mass = uncertain!(1e-9, 1e-12);
+    let atoms = F18.mass_to_atoms(mass);
+    assert!((atoms.value / 3.345_5e13 - 1.0).abs() < 1e-4);
+    let round_trip = F18.atoms_to_mass(atoms);
+    assert!((round_trip.value - mass.value).abs() < 1e-21);
+    assert!(round_trip.uncertainty >= mass.uncertainty);
+    let activity = F18.mass_to_activity(mass);
+    assert_eq!(activity.value, F18.atoms_to_activity(atoms).value);
+    assert!((F18.activity_to_mass(activity).value - mass.value).abs() < 1e-21);
+    assert!((F18.activity_to_atoms(activity).value / atoms.value - 1.0).abs() < 1e-12);
+    assert_eq!(
+        Radionuclide::from_curies(uncertain!(1.0)),
+        uncertain!(3.7e10)
+    );
+    assert_eq!(
+        Radionuclide::to_curies(uncertain!(3.7e10, 3.7e8)),
+        uncertain!(1.0, 0.01)
+    );
+}
+
+#[test]
+fn decay() {
+    let dose = uncertain!(370e6, 1e6);
+    // One half-life
+    let decayed = F18.decay(dose, F18.half_life().value);
+    assert!((decayed.value - 185e6).abs() < 1e-3);
+    assert!(decayed.uncertainty > dose.uncertainty / 2.0);
+    // Two hours
+    assert!((F18.decay(dose, 7_200.0).value - 173.4e6).abs() < 1e5);
+    let corrected = F18.decay(decayed, -F18.half_life().value);
+    assert!((corrected.value - dose.value).abs() < 1e-3);
+
+    let tc99m = "99mTc".parse::<Isomer>().unwrap().radionuclide().unwrap();
+    let calibration = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    let injection = calibration + Duration::from_secs(3 * 3_600);
+    let activity = tc99m.decay_between(uncertain!(1e9), calibration, injection);
+    assert!((activity.value - 1e9 * 0.5f64.powf(3.0 / 6.006_7)).abs() < 1.0);
+    let back = tc99m.decay_between(activity, injection, calibration);
+    assert!((back.value - 1e9).abs() < 1e-3);
+}
@@ -0,0 +1,143 @@
+use atom::{
+    isotopes::{C, H, N, O},
+    uncertain, Correction, Element, Error, Isotope, IsotopicComposition, Uncertain,
+};
+
+const C13: Isotope = Isotope::C(C::Thirteen);
+
+// Glucose
+const FORMULA: &[(Element, usize)] = &[(Element::C, 6), (Element::H, 12), (Element::O, 6)];
+
+fn measure(correction: &Correction, fractions: &[f64]) -> Vec<Uncertain> {
+    correction
+        .matrix()
+        .iter()
+        .map(|row| {
+            let value = row.iter().zip(fractions).map(|(a, x)| a * x).sum();
+            Uncertain::new(value, 0.001)
+        })
+        .collect()
+}
+
+#[test]
+fn matrix() {
+    let correction = Correction::new(FORMULA, C13, 6, 7).unwrap();
+    let matrix = correction.matrix();
+    assert_eq!(matrix.len(), 7);
+    assert!(matrix.iter().all(|row| row.len() == 7));
+    // Upper triangle is zero
+    for (row, values) in matrix.iter().enumerate() {
+        for value in &values[row + 1..] {
+            assert_eq!(*value, 0.0);
+        }
+    }
+    // M+0 of the unlabeled molecule
+    let c12 = Isotope::C(C::Twelve).isotopic_composition().unwrap().value;
+    let h1 = Isotope::H(H::One).isotopic_composition().unwrap().value;
+    let o16 = Isotope::O(O::Sixteen).isotopic_composition().unwrap().value;
+    let expected = c12.powi(6) * h1.powi(12) * o16.powi(6);
+    assert!((matrix[0][0] - expected).abs() < 1e-4);
+    // Fully labeled molecule carries no natural carbon
+    assert!((matrix[6][6] - h1.powi(12) * o16.powi(6)).abs() < 1e-4);
+}
+
+#[test]
+fn correct() {
+    let correction = Correction::new(FORMULA, C13, 6, 7).unwrap();
+    let expected = [0.5, 0.0, 0.2, 0.0, 0.0, 0.0, 0.3];
+    let corrected = correction
+        .correct(&measure(&correction, &expected))
+        .unwrap();
+    for (corrected, expected) in corrected.iter().zip(expected) {
+        assert!((corrected.value - expected).abs() < 1e-9);
+        assert!(corrected.value >= 0.0);
+    }
+    assert!(corrected[0].uncertainty > 0.0);
+    let sum: f64 = corrected.iter().map(|fraction| fraction.value).sum();
+    assert!((sum - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn non_negative() {
+    let correction = Correction::new(&[(Element::C, 3)], C13, 3, 4).unwrap();
+    // Less M+1 than natural abundance alone explains
+    let measured = [
+        uncertain!(1.0, 0.01),
+        uncertain!(0.0, 0.01),
+        uncertain!(0.0, 0.01),
+        uncertain!(0.0, 0.01),
+    ];
+    let corrected = correction.correct(&measured).unwrap();
+    assert!(corrected.iter().all(|fraction| fraction.value >= 0.0));
+    assert!((corrected[0].value - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn compositions() {
+    // Derivatization agent of fully ¹²C carbon shows no natural M+1 from it
+    let carbon = IsotopicComposition::new(Element::C)
+        .with(Isotope::C(C::Twelve), uncertain!(0.99))
+        .unwrap()
+        .with(C13, uncertain!(0.01))
+        .unwrap();
+    let natural = Correction::new(&[(Element::C, 2)], C13, 2, 3).unwrap();
+    let custom = Correction::with_compositions(&[(Element::C, 2)], C13, 2, 3, &[carbon]).unwrap();
+    assert!((custom.matrix()[0][0] - 0.99 * 0.99).abs() < 1e-12);
+    assert_ne!(natural.matrix(), custom.matrix());
+}
+
+#[test]
+fn errors() {
+    assert!(matches!(
+        Correction::new(&[(Element::C, 2)], C13, 3, 4),
+        Err(Error::TooManyLabels { .. })
+    ));
+    let correction = Correction::new(&[(Element::C, 2)], C13, 2, 3).unwrap();
+    assert!(matches!(
+        correction.correct(&[uncertain!(1.0)]),
+        Err(Error::InvalidLength {
+            expected: 3,
+            found: 1
+        })
+    ));
+    let nitrogen = Isotope::N(N::Fifteen);
+    assert!(Correction::new(&[(Element::N, 1)], nitrogen, 1, 2).is_ok());
+}
+
+#[test]
+fn enriched_background() {
+    // Fully ¹³C background, the lighter ¹²C is absent
+    let carbon = IsotopicComposition::new(Element::C)
+        .with(Isotope::C(C::Twelve), uncertain!(0.0))
+        .unwrap()
+        .with(C13, uncertain!(1.0))
+        .unwrap();
+    let correction = Correction::with_compositions(
+        &[(Element::C, 2), (Element::H, 4)],
+        C13,
+        1,
+        3,
+        std::slice::from_ref(&carbon),
+    )
+    .unwrap();
+    assert_eq!(correction.matrix()[0][0], correction.matrix()[0][1]);
+    let nitrogen = IsotopicComposition::new(Element::N)
+        .with(Isotope::N(N::Fourteen), uncertain!(0.0))
+        .unwrap()
+        .with(Isotope::N(N::Fifteen), uncertain!(1.0))
+        .unwrap();
+    assert!(Correction::with_compositions(
+        &[(Element::C, 2), (Element::N, 1)],
+        C13,
+        2,
+        3,
+        &[nitrogen],
+    )
+    .is_ok());
+    assert_eq!(
+        Correction::with_compositions(&[(Element::C, 2)], Isotope::C(C::Twelve), 1, 3, &[carbon],),
+        Err(Error::InvalidTracer {
+            tracer: Isotope::C(C::Twelve)
+        })
+    );
+}
@@ -1,4 +1,8 @@
-use atom::{uncertain, Element::*};
+use atom::{
+    isotopes, uncertain, AtomicWeight, Element, Element::*, Error, Isotope, Notes, Source,
+    Source::*, ELEMENTS, ISOTOPES,
+};
+use strum::IntoEnumIterator;
 
 #[test]
 #[rustfmt::skip]
@@ -11,43 +15,31 @@ fn standard_atomic_weight() {
     assert_eq!(C.standard_atomic_weight(), Some(uncertain!(12.009_6..12.011_6)));
     assert_eq!(N.standard_atomic_weight(), Some(uncertain!(14.006_43..14.007_28)));
     assert_eq!(O.standard_atomic_weight(), Some(uncertain!(15.999_03..15.999_77)));
-    #[cfg(feature = "nist")]
-    assert_eq!(F.standard_atomic_weight(), Some(uncertain!(18.998_403_163, 0.000_000_006)));
-    #[cfg(feature = "iupac")]
-    assert_eq!(F.standard_atomic_weight(), Some(uncertain!(18.998_403_162, 0.000_000_005)));
+    assert_eq!(F.standard_atomic_weight_from(Nist), Some(uncertain!(18.998_403_163, 0.000_000_006)));
+    assert_eq!(F.standard_atomic_weight_from(Iupac2021), Some(uncertain!(18.998_403_162, 0.000_000_005)));
     assert_eq!(Ne.standard_atomic_weight(), Some(uncertain!(20.179_7, 0.000_6)));
     assert_eq!(Na.standard_atomic_weight(), Some(uncertain!(22.989_769_28, 0.000_000_02)));
     assert_eq!(Mg.standard_atomic_weight(), Some(uncertain!(24.304..24.307)));
-    #[cfg(feature = "nist")]
-    assert_eq!(Al.standard_atomic_weight(), Some(uncertain!(26.981_538_5, 0.000_000_7)));
-    #[cfg(feature = "iupac")]
-    assert_eq!(Al.standard_atomic_weight(), Some(uncertain!(26.981_538_4, 0.000_000_3)));
+    assert_eq!(Al.standard_atomic_weight_from(Nist), Some(uncertain!(26.981_538_5, 0.000_000_7)));
+    assert_eq!(Al.standard_atomic_weight_from(Iupac2021), Some(uncertain!(26.981_538_4, 0.000_000_3)));
     assert_eq!(Si.standard_atomic_weight(), Some(uncertain!(28.084..28.086)));
     assert_eq!(P.standard_atomic_weight(), Some(uncertain!(30.973_761_998, 0.000_000_005)));
     assert_eq!(S.standard_atomic_weight(), Some(uncertain!(32.059..32.076)));
     assert_eq!(Cl.standard_atomic_weight(), Some(uncertain!(35.446..35.457)));
-    #[cfg(feature = "nist")]
-    assert_eq!(Ar.standard_atomic_weight(), Some(uncertain!(39.948, 0.001)));
-    #[cfg(feature = "iupac")]
-    assert_eq!(Ar.standard_atomic_weight(), Some(uncertain!(39.792..39.963)));
+    assert_eq!(Ar.standard_atomic_weight_from(Nist), Some(uncertain!(39.948, 0.001)));
+    assert_eq!(Ar.standard_atomic_weight_from(Iupac2021), Some(uncertain!(39.792..39.963)));
     assert_eq!(K.standard_atomic_weight(), Some(uncertain!(39.098_3, 0.000_1)));
     assert_eq!(Ca.standard_atomic_weight(), Some(uncertain!(40.078, 0.004)));
-    #[cfg(feature = "nist")]
-    assert_eq!(Sc.standard_atomic_weight(), Some(uncertain!(44.955_908, 0.000_005)));
-    #[cfg(feature = "iupac")]
-    assert_eq!(Sc.standard_atomic_weight(), Some(uncertain!(44.955_907, 0.000_004)));
+    assert_eq!(Sc.standard_atomic_weight_from(Nist), Some(uncertain!(44.955_908, 0.000_005)));
+    assert_eq!(Sc.standard_atomic_weight_from(Iupac2021), Some(uncertain!(44.955_907, 0.000_004)));
     assert_eq!(Ti.standard_atomic_weight(), Some(uncertain!(47.867, 0.001)));
     assert_eq!(V.standard_atomic_weight(), Some(uncertain!(50.941_5, 0.000_1)));
     assert_eq!(Cr.standard_atomic_weight(), Some(uncertain!(51.996_1, 0.000_6)));
-    #[cfg(feature = "nist")]
-    assert_eq!(Mn.standard_atomic_weight(), Some(uncertain!(54.938_044, 0.000_003)));
-    #[cfg(feature = "iupac")]
-    assert_eq!(Mn.standard_atomic_weight(), Some(uncertain!(54.938_043, 0.000_002)));
+    assert_eq!(Mn.standard_atomic_weight_from(Nist), Some(uncertain!(54.938_044, 0.000_003)));
+    assert_eq!(Mn.standard_atomic_weight_from(Iupac2021), Some(uncertain!(54.938_043, 0.000_002)));
     assert_eq!(Fe.standard_atomic_weight(), Some(uncertain!(55.845, 0.002)));
-    #[cfg(feature = "nist")]
-    assert_eq!(Co.standard_atomic_weight(), Some(uncertain!(58.933_194, 0.000_004)));
-    #[cfg(feature = "iupac")]
-    assert_eq!(Co.standard_atomic_weight(), Some(uncertain!(58.933_194, 0.000_003)));
+    assert_eq!(Co.standard_atomic_weight_from(Nist), Some(uncertain!(58.933_194, 0.000_004)));
+    assert_eq!(Co.standard_atomic_weight_from(Iupac2021), Some(uncertain!(58.933_194, 0.000_003)));
     assert_eq!(Ni.standard_atomic_weight(), Some(uncertain!(58.693_4, 0.000_4)));
     assert_eq!(Cu.standard_atomic_weight(), Some(uncertain!(63.546, 0.003)));
     assert_eq!(Zn.standard_atomic_weight(), Some(uncertain!(65.38, 0.02)));
@@ -59,22 +51,16 @@ fn standard_atomic_weight() {
     assert_eq!(Kr.standard_atomic_weight(), Some(uncertain!(83.798, 0.002)));
     assert_eq!(Rb.standard_atomic_weight(), Some(uncertain!(85.467_8, 0.000_3)));
     assert_eq!(Sr.standard_atomic_weight(), Some(uncertain!(87.62, 0.01)));
-    #[cfg(feature = "nist")]
-    assert_eq!(Y.standard_atomic_weight(), Some(uncertain!(88.905_84, 0.000_02)));
-    #[cfg(feature = "iupac")]
-    assert_eq!(Y.standard_atomic_weight(), Some(uncertain!(88.905_838, 0.000_002)));
+    assert_eq!(Y.standard_atomic_weight_from(Nist), Some(uncertain!(88.905_84, 0.000_02)));
+    assert_eq!(Y.standard_atomic_weight_from(Iupac2021), Some(uncertain!(88.905_838, 0.000_002)));
     assert_eq!(Zr.standard_atomic_weight(), Some(uncertain!(91.224, 0.002)));
-    #[cfg(feature = "nist")]
-    assert_eq!(Nb.standard_atomic_weight(), Some(uncertain!(92.906_37, 0.000_02)));
-    #[cfg(feature = "iupac")]
-    assert_eq!(Nb.standard_atomic_weight(), Some(uncertain!(92.906_37, 0.000_01)));
+    assert_eq!(Nb.standard_atomic_weight_from(Nist), Some(uncertain!(92.906_37, 0.000_02)));
+    assert_eq!(Nb.standard_atomic_weight_from(Iupac2021), Some(uncertain!(92.906_37, 0.000_01)));
     assert_eq!(Mo.standard_atomic_weight(), Some(uncertain!(95.95, 0.01)));
     assert_eq!(Tc.standard_atomic_weight(), None);
     assert_eq!(Ru.standard_atomic_weight(), Some(uncertain!(101.07, 0.02)));
-    #[cfg(feature = "nist")]
-    assert_eq!(Rh.standard_atomic_weight(), Some(uncertain!(102.905_50, 0.000_02)));
-    #[cfg(feature = "iupac")]
-    assert_eq!(Rh.standard_atomic_weight(), Some(uncertain!(102.905_49, 0.000_02)));
+    assert_eq!(Rh.standard_atomic_weight_from(Nist), Some(uncertain!(102.905_50, 0.000_02)));
+    assert_eq!(Rh.standard_atomic_weight_from(Iupac2021), Some(uncertain!(102.905_49, 0.000_02)));
     assert_eq!(Pd.standard_atomic_weight(), Some(uncertain!(106.42, 0.01)));
     assert_eq!(Ag.standard_atomic_weight(), Some(uncertain!(107.868_2, 0.000_2)));
     assert_eq!(Cd.standard_atomic_weight(), Some(uncertain!(112.414, 0.004)));
@@ -88,57 +74,39 @@ fn standard_atomic_weight() {
     assert_eq!(Ba.standard_atomic_weight(), Some(uncertain!(137.327, 0.007)));
     assert_eq!(La.standard_atomic_weight(), Some(uncertain!(138.905_47, 0.000_07)));
     assert_eq!(Ce.standard_atomic_weight(), Some(uncertain!(140.116, 0.001)));
-    #[cfg(feature = "nist")]
-    assert_eq!(Pr.standard_atomic_weight(), Some(uncertain!(140.907_66, 0.000_02)));
-    #[cfg(feature = "iupac")]
-    assert_eq!(Pr.standard_atomic_weight(), Some(uncertain!(140.907_66, 0.000_01)));
+    assert_eq!(Pr.standard_atomic_weight_from(Nist), Some(uncertain!(140.907_66, 0.000_02)));
+    assert_eq!(Pr.standard_atomic_weight_from(Iupac2021), Some(uncertain!(140.907_66, 0.000_01)));
     assert_eq!(Nd.standard_atomic_weight(), Some(uncertain!(144.242, 0.003)));
     assert_eq!(Pm.standard_atomic_weight(), None);
     assert_eq!(Sm.standard_atomic_weight(), Some(uncertain!(150.36, 0.02)));
     assert_eq!(Eu.standard_atomic_weight(), Some(uncertain!(151.964, 0.001)));
     assert_eq!(Gd.standard_atomic_weight(), Some(uncertain!(157.25, 0.03)));
-    #[cfg(feature = "nist")]
-    assert_eq!(Tb.standard_atomic_weight(), Some(uncertain!(158.925_35, 0.000_02)));
-    #[cfg(feature = "iupac")]
-    assert_eq!(Tb.standard_atomic_weight(), Some(uncertain!(158.925_354, 0.000_007)));
+    assert_eq!(Tb.standard_atomic_weight_from(Nist), Some(uncertain!(158.925_35, 0.000_02)));
+    assert_eq!(Tb.standard_atomic_weight_from(Iupac2021), Some(uncertain!(158.925_354, 0.000_007)));
     assert_eq!(Dy.standard_atomic_weight(), Some(uncertain!(162.500, 0.001)));
-    #[cfg(feature = "nist")]
-    assert_eq!(Ho.standard_atomic_weight(), Some(uncertain!(164.930_33, 0.000_02)));
-    #[cfg(feature = "iupac")]
-    assert_eq!(Ho.standard_atomic_weight(), Some(uncertain!(164.930_329, 0.000_005)));
+    assert_eq!(Ho.standard_atomic_weight_from(Nist), Some(uncertain!(164.930_33, 0.000_02)));
+    assert_eq!(Ho.standard_atomic_weight_from(Iupac2021), Some(uncertain!(164.930_329, 0.000_005)));
     assert_eq!(Er.standard_atomic_weight(), Some(uncertain!(167.259, 0.003)));
-    #[cfg(feature = "nist")]
-    assert_eq!(Tm.standard_atomic_weight(), Some(uncertain!(168.934_22, 0.000_02)));
-    #[cfg(feature = "iupac")]
-    assert_eq!(Tm.standard_atomic_weight(), Some(uncertain!(168.934_219, 0.000_005)));
-    #[cfg(feature = "nist")]
-    assert_eq!(Yb.standard_atomic_weight(), Some(uncertain!(173.054, 0.005)));
-    #[cfg(feature = "iupac")]
-    assert_eq!(Yb.standard_atomic_weight(), Some(uncertain!(173.045, 0.010)));
+    assert_eq!(Tm.standard_atomic_weight_from(Nist), Some(uncertain!(168.934_22, 0.000_02)));
+    assert_eq!(Tm.standard_atomic_weight_from(Iupac2021), Some(uncertain!(168.934_219, 0.000_005)));
+    assert_eq!(Yb.standard_atomic_weight_from(Nist), Some(uncertain!(173.054, 0.005)));
+    assert_eq!(Yb.standard_atomic_weight_from(Iupac2021), Some(uncertain!(173.045, 0.010)));
     assert_eq!(Lu.standard_atomic_weight(), Some(uncertain!(174.966_8, 0.000_1)));
-    #[cfg(feature = "nist")]
-    assert_eq!(Hf.standard_atomic_weight(), Some(uncertain!(178.49, 0.02)));
-    #[cfg(feature = "iupac")]
-    assert_eq!(Hf.standard_atomic_weight(), Some(uncertain!(178.486, 0.006)));
+    assert_eq!(Hf.standard_atomic_weight_from(Nist), Some(uncertain!(178.49, 0.02)));
+    assert_eq!(Hf.standard_atomic_weight_from(Iupac2021), Some(uncertain!(178.486, 0.006)));
     assert_eq!(Ta.standard_atomic_weight(), Some(uncertain!(180.947_88, 0.000_02)));
     assert_eq!(W.standard_atomic_weight(), Some(uncertain!(183.84, 0.01)));
     assert_eq!(Re.standard_atomic_weight(), Some(uncertain!(186.207, 0.001)));
     assert_eq!(Os.standard_atomic_weight(), Some(uncertain!(190.23, 0.03)));
-    #[cfg(feature = "nist")]
-    assert_eq!(Ir.standard_atomic_weight(), Some(uncertain!(192.217, 0.003)));
-    #[cfg(feature = "iupac")]
-    assert_eq!(Ir.standard_atomic_weight(), Some(uncertain!(192.217, 0.002)));
+    assert_eq!(Ir.standard_atomic_weight_from(Nist), Some(uncertain!(192.217, 0.003)));
+    assert_eq!(Ir.standard_atomic_weight_from(Iupac2021), Some(uncertain!(192.217, 0.002)));
     assert_eq!(Pt.standard_atomic_weight(), Some(uncertain!(195.084, 0.009)));
-    #[cfg(feature = "nist")]
-    assert_eq!(Au.standard_atomic_weight(), Some(uncertain!(196.966_569, 0.000_005)));
-    #[cfg(feature = "iupac")]
-    assert_eq!(Au.standard_atomic_weight(), Some(uncertain!(196.966_570, 0.000_004)));
+    assert_eq!(Au.standard_atomic_weight_from(Nist), Some(uncertain!(196.966_569, 0.000_005)));
+    assert_eq!(Au.standard_atomic_weight_from(Iupac2021), Some(uncertain!(196.966_570, 0.000_004)));
     assert_eq!(Hg.standard_atomic_weight(), Some(uncertain!(200.592, 0.003)));
     assert_eq!(Tl.standard_atomic_weight(), Some(uncertain!(204.382..204.385)));
-    #[cfg(feature = "nist")]
-    assert_eq!(Pb.standard_atomic_weight(), Some(uncertain!(207.2, 0.1)));
-    #[cfg(feature = "iupac")]
-    assert_eq!(Pb.standard_atomic_weight(), Some(uncertain!(206.14..207.94)));
+    assert_eq!(Pb.standard_atomic_weight_from(Nist), Some(uncertain!(207.2, 0.1)));
+    assert_eq!(Pb.standard_atomic_weight_from(Iupac2021), Some(uncertain!(206.14..207.94)));
     assert_eq!(Bi.standard_atomic_weight(), Some(uncertain!(208.980_40, 0.000_01)));
     assert_eq!(Po.standard_atomic_weight(), None);
     assert_eq!(At.standard_atomic_weight(), None);
@@ -147,10 +115,8 @@ fn standard_atomic_weight() {
     assert_eq!(Ra.standard_atomic_weight(), None);
     assert_eq!(Ac.standard_atomic_weight(), None);
     assert_eq!(Th.standard_atomic_weight(), Some(uncertain!(232.037_7, 0.000_4)));
-    #[cfg(feature = "nist")]
-    assert_eq!(Pa.standard_atomic_weight(), Some(uncertain!(231.035_88, 0.000_02)));
-    #[cfg(feature = "iupac")]
-    assert_eq!(Pa.standard_atomic_weight(), Some(uncertain!(231.035_88, 0.000_01)));
+    assert_eq!(Pa.standard_atomic_weight_from(Nist), Some(uncertain!(231.035_88, 0.000_02)));
+    assert_eq!(Pa.standard_atomic_weight_from(Iupac2021), Some(uncertain!(231.035_88, 0.000_01)));
     assert_eq!(U.standard_atomic_weight(), Some(uncertain!(238.028_91, 0.000_03)));
     assert_eq!(Np.standard_atomic_weight(), None);
     assert_eq!(Pu.standard_atomic_weight(), None);
@@ -302,3 +268,179 @@ fn standard_atomic_weight() {
 //     assert_eq!(Ts.standard_atomic_weight().abridged(), None);
 //     assert_eq!(Og.standard_atomic_weight().abridged(), None);
 // }
+
+#[test]
+fn parse() {
+    assert_eq!("Cl".parse(), Ok(Cl));
+    assert_eq!(
+        "CL".parse::<Element>(),
+        Err(Error::UnknownSymbol {
+            text: "CL".to_owned(),
+            offset: 0,
+            suggestion: Some("Cl".to_owned()),
+        }),
+    );
+    assert_eq!(
+        "carbon".parse::<Element>().unwrap_err().suggestion(),
+        Some("C")
+    );
+    assert_eq!("Xx".parse::<Element>().unwrap_err().suggestion(), None);
+    let error = "CL".parse::<Element>().unwrap_err().with_offset(42);
+    assert_eq!(error.offset(), Some(42));
+    assert_eq!(error.text(), Some("CL"));
+    assert_eq!(
+        error.to_string(),
+        "unknown symbol `CL` at byte 42, did you mean `Cl`?",
+    );
+}
+
+#[test]
+fn source() {
+    assert_eq!(Source::default(), Source::DEFAULT);
+    for element in atom::ELEMENTS {
+        assert_eq!(
+            element.standard_atomic_weight(),
+            element.standard_atomic_weight_from(Source::DEFAULT),
+        );
+    }
+    assert_ne!(
+        Pb.standard_atomic_weight_from(Nist),
+        Pb.standard_atomic_weight_from(Iupac2021),
+    );
+    assert_eq!(Tc.standard_atomic_weight_from(Iupac2021), None);
+}
+
+#[test]
+fn changes() {
+    assert_eq!(
+        Source::changes(Iupac2013, Iupac2017).collect::<Vec<_>>(),
+        [Ar, Yb, Ir]
+    );
+    assert_eq!(
+        Source::changes(Iupac2017, Iupac2019).collect::<Vec<_>>(),
+        [Hf]
+    );
+    assert_eq!(
+        Source::changes(Iupac2019, Iupac2021).collect::<Vec<_>>(),
+        [F, Al, Sc, Mn, Co, Y, Nb, Rh, Pr, Tb, Ho, Tm, Au, Pb, Pa],
+    );
+    assert_eq!(Source::changes(Nist, Iupac2013).count(), 0);
+    assert_eq!(Source::changes(Iupac2021, Iupac2021).count(), 0);
+    assert_eq!(
+        Hf.standard_atomic_weight_from(Iupac2017),
+        Some(uncertain!(178.49, 0.02))
+    );
+    assert_eq!(
+        Hf.standard_atomic_weight_from(Iupac2019),
+        Some(uncertain!(178.486, 0.006))
+    );
+}
+
+#[test]
+fn atomic_weight() {
+    for source in Source::iter() {
+        for element in atom::ELEMENTS {
+            // Every element has either a standard weight or a most stable isotope
+            let atomic_weight = element.atomic_weight_from(source).unwrap();
+            assert_eq!(
+                atomic_weight.mass_number().is_none(),
+                element.standard_atomic_weight_from(source).is_some(),
+            );
+        }
+    }
+    assert_eq!(
+        C.atomic_weight(),
+        Some(AtomicWeight::Standard(
+            C.standard_atomic_weight().unwrap(),
+            C.notes()
+        ))
+    );
+    assert_eq!(Tc.atomic_weight().unwrap().mass_number(), Some(98));
+    assert_eq!(Tc.atomic_weight().unwrap().to_string(), "[98]");
+    assert_eq!(Pu.atomic_weight().unwrap().mass_number(), Some(244));
+    assert_eq!(Og.atomic_weight().unwrap().mass_number(), Some(294));
+    assert_eq!(
+        Rn.atomic_weight().unwrap().value(),
+        uncertain!(222.017_578_2, 0.000_002_5),
+    );
+}
+
+#[test]
+fn notes() {
+    assert_eq!(H.notes(), H.notes_from(Source::DEFAULT));
+    assert_eq!(Cl.notes(), [Notes::M]);
+    assert_eq!(F.notes(), []);
+    assert_eq!(Tc.notes(), []);
+    assert_eq!(
+        H.notes_from(Nist)
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        ["g", "m", "r"],
+    );
+}
+
+#[test]
+fn notes_from() {
+    assert_eq!(H.notes_from(Nist), [Notes::G, Notes::M, Notes::R]);
+    assert_eq!(H.notes_from(Iupac2013), [Notes::G, Notes::M]);
+    assert_eq!(Si.notes_from(Iupac2021), []);
+    // Ar became an interval in 2017, Pb in 2021
+    assert_eq!(Ar.notes_from(Iupac2013), [Notes::G, Notes::R]);
+    assert_eq!(Ar.notes_from(Iupac2017), [Notes::G]);
+    assert_eq!(Pb.notes_from(Iupac2019), [Notes::G, Notes::R]);
+    assert_eq!(Pb.notes_from(Iupac2021), [Notes::G]);
+    assert_eq!(Cl.notes_from(Iupac2021), [Notes::M]);
+    for source in Source::iter() {
+        for element in atom::ELEMENTS {
+            let atomic_weight = element.atomic_weight_from(source).unwrap();
+            match atomic_weight {
+                AtomicWeight::Standard(..) => {
+                    assert_eq!(atomic_weight.notes(), element.notes_from(source));
+                }
+                AtomicWeight::MostStable(_) => assert_eq!(atomic_weight.notes(), []),
+            }
+        }
+    }
+}
+
+#[test]
+fn isotopes() {
+    assert_eq!(
+        C.isotopes().collect::<Vec<_>>(),
+        [
+            Isotope::C(isotopes::C::Twelve),
+            Isotope::C(isotopes::C::Thirteen),
+            Isotope::C(isotopes::C::Fourteen),
+        ],
+    );
+    assert_eq!(
+        ELEMENTS
+            .into_iter()
+            .map(|element| element.isotopes().count())
+            .sum::<usize>(),
+        ISOTOPES.len(),
+    );
+    assert_eq!(C.stable_isotopes().count(), 2);
+    assert_eq!(Sn.stable_isotopes().count(), 10);
+    assert_eq!(Tc.stable_isotopes().count(), 0);
+}
+
+#[test]
+fn most_abundant_isotope() {
+    assert_eq!(
+        C.most_abundant_isotope(),
+        Some(Isotope::C(isotopes::C::Twelve))
+    );
+    assert_eq!(
+        Cl.most_abundant_isotope(),
+        Some(Isotope::Cl(isotopes::Cl::ThirtyFive))
+    );
+    assert_eq!(Tc.most_abundant_isotope(), None);
+    assert_eq!(C.monoisotopic_mass(), Some(uncertain!(12.0)));
+    assert_eq!(
+        Cl.monoisotopic_mass(),
+        Some(Isotope::Cl(isotopes::Cl::ThirtyFive).relative_atomic_mass())
+    );
+    assert_eq!(Tc.monoisotopic_mass(), None);
+}
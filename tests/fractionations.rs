@@ -0,0 +1,147 @@
+use atom::{
+    isotopes::{C, O},
+    uncertain, Element, Error, Fractionation, Isotope, IsotopeRatio, IsotopicComposition,
+};
+
+fn initial() -> IsotopeRatio {
+    IsotopeRatio::new(
+        Isotope::C(C::Thirteen),
+        Isotope::C(C::Twelve),
+        uncertain!(0.011, 0.000_001),
+    )
+    .unwrap()
+}
+
+#[test]
+fn conversions() {
+    let fractionation = Fractionation::from_epsilon(uncertain!(-20.0, 0.5));
+    assert!((fractionation.alpha().value - 0.98).abs() < 1e-12);
+    assert!((fractionation.alpha().uncertainty - 0.000_5).abs() < 1e-12);
+    assert!((fractionation.epsilon().value + 20.0).abs() < 1e-9);
+    let difference = fractionation.difference();
+    assert!((difference.value - 0.98f64.ln() * 1000.0).abs() < 1e-9);
+    let roundtrip = Fractionation::from_difference(difference);
+    assert!((roundtrip.alpha().value - 0.98).abs() < 1e-12);
+    let deltas = Fractionation::from_deltas(uncertain!(-30.0), uncertain!(-10.0));
+    assert!((deltas.alpha().value - 970.0 / 990.0).abs() < 1e-12);
+}
+
+#[test]
+fn rayleigh() {
+    let fractionation = Fractionation::new(uncertain!(0.98, 0.001));
+    let initial = initial();
+    let evolution = fractionation.rayleigh(&initial, uncertain!(0.5)).unwrap();
+    let residual = evolution.residual.value();
+    assert!((residual.value - 0.011 * 0.5f64.powf(-0.02)).abs() < 1e-12);
+    // Uncertainty of α propagates
+    assert!(residual.uncertainty > initial.value().uncertainty);
+    assert!((evolution.instantaneous.value().value - 0.98 * residual.value).abs() < 1e-12);
+    // Mass balance
+    let accumulated = evolution.accumulated.value().value;
+    assert!((0.5 * residual.value + 0.5 * accumulated - 0.011).abs() < 1e-12);
+    // Nothing has reacted yet
+    let start = fractionation.rayleigh(&initial, uncertain!(1.0)).unwrap();
+    assert!((start.residual.value().value - 0.011).abs() < 1e-12);
+    assert!((start.accumulated.value().value - 0.98 * 0.011).abs() < 1e-12);
+}
+
+#[test]
+fn equilibrium() {
+    let fractionation = Fractionation::new(uncertain!(0.98));
+    let evolution = fractionation
+        .equilibrium(&initial(), uncertain!(0.5))
+        .unwrap();
+    let residual = evolution.residual.value().value;
+    assert!((residual - 0.011 / 0.99).abs() < 1e-12);
+    assert!((evolution.accumulated.value().value - 0.98 * residual).abs() < 1e-12);
+}
+
+#[test]
+fn batch() {
+    let fractionation = Fractionation::new(uncertain!(0.98));
+    let initial = initial();
+    let remainder = uncertain!(0.2);
+    let equilibrium = fractionation.equilibrium(&initial, remainder).unwrap();
+    let single = fractionation.batch(&initial, remainder, 1).unwrap();
+    assert!((single.residual.value().value - equilibrium.residual.value().value).abs() < 1e-15);
+    let rayleigh = fractionation.rayleigh(&initial, remainder).unwrap();
+    let many = fractionation.batch(&initial, remainder, 10_000).unwrap();
+    assert!((many.residual.value().value - rayleigh.residual.value().value).abs() < 1e-7);
+    assert!(matches!(
+        fractionation.batch(&initial, remainder, 0),
+        Err(Error::InvalidBatches { batches: 0 })
+    ));
+}
+
+#[test]
+fn remainder() {
+    let fractionation = Fractionation::new(uncertain!(0.98));
+    for remainder in [uncertain!(0.0), uncertain!(1.5)] {
+        assert!(matches!(
+            fractionation.rayleigh(&initial(), remainder),
+            Err(Error::InvalidRemainder { .. })
+        ));
+    }
+}
+
+#[test]
+fn edges() {
+    let fractionation = Fractionation::new(uncertain!(0.98, 0.001));
+    // Steps around a tiny remainder stay positive
+    let evolution = fractionation
+        .rayleigh(&initial(), uncertain!(5e-7, 1e-7))
+        .unwrap();
+    for ratio in [
+        evolution.residual,
+        evolution.instantaneous,
+        evolution.accumulated,
+    ] {
+        assert!(ratio.value().uncertainty.is_finite());
+    }
+    // And a full one does not exceed 1
+    let start = fractionation
+        .rayleigh(&initial(), uncertain!(1.0, 0.01))
+        .unwrap();
+    assert!(start.residual.value().uncertainty.is_finite());
+}
+
+#[test]
+fn composition() {
+    let fractionation = Fractionation::new(uncertain!(0.98));
+    let oxygen = IsotopicComposition::natural(Element::O);
+    let (o16, o17, o18) = (
+        Isotope::O(O::Sixteen),
+        Isotope::O(O::Seventeen),
+        Isotope::O(O::Eighteen),
+    );
+    let evolution = fractionation
+        .rayleigh_composition(&oxygen, o18, o16, uncertain!(0.5))
+        .unwrap();
+    let residual = evolution.residual;
+    let fraction =
+        |composition: &IsotopicComposition, isotope| composition.fraction(isotope).unwrap().value;
+    assert!((residual.sum().value - 1.0).abs() < 1e-12);
+    // The ratio of the pair follows the ratio model
+    let ratio = fraction(&oxygen, o18) / fraction(&oxygen, o16);
+    let expected = ratio * 0.5f64.powf(-0.02);
+    assert!((fraction(&residual, o18) / fraction(&residual, o16) - expected).abs() < 1e-12);
+    // ¹⁷O shifts about half as much
+    let shift = |isotope| {
+        (fraction(&residual, isotope) / fraction(&residual, o16))
+            / (fraction(&oxygen, isotope) / fraction(&oxygen, o16))
+            - 1.0
+    };
+    assert!((shift(o17) / shift(o18) - 0.52).abs() < 0.01);
+    // Mass balance
+    let accumulated = evolution.accumulated;
+    let balance = 0.5 * fraction(&residual, o18) + 0.5 * fraction(&accumulated, o18);
+    assert!((balance - fraction(&oxygen, o18)).abs() < 1e-6);
+    assert!(matches!(
+        fractionation.rayleigh_composition(&oxygen, Isotope::C(C::Thirteen), o16, uncertain!(0.5)),
+        Err(Error::ForeignIsotope { .. })
+    ));
+    assert!(matches!(
+        fractionation.batch_composition(&oxygen, o18, o16, uncertain!(0.5), 0),
+        Err(Error::InvalidBatches { .. })
+    ));
+}
@@ -0,0 +1,59 @@
+use atom::{
+    isotopes::{Ar, Ca, Cl, Fe, Ni, K, S},
+    Isotope,
+};
+
+const AR40: Isotope = Isotope::Ar(Ar::Forty);
+const CA40: Isotope = Isotope::Ca(Ca::Forty);
+
+#[test]
+fn isobars() {
+    assert_eq!(
+        AR40.isobars().collect::<Vec<_>>(),
+        [Isotope::K(K::Forty), CA40]
+    );
+    assert_eq!(
+        Isotope::Fe(Fe::FiftyEight).isobars().collect::<Vec<_>>(),
+        [Isotope::Ni(Ni::FiftyEight)]
+    );
+    assert!(CA40.isobars().all(|isobar| isobar.mass_number() == 40));
+}
+
+#[test]
+fn isotones() {
+    let isotones: Vec<_> = CA40.isotones().collect();
+    for isotone in [
+        Isotope::S(S::ThirtySix),
+        Isotope::Cl(Cl::ThirtySeven),
+        Isotope::Ar(Ar::ThirtyEight),
+        Isotope::K(K::ThirtyNine),
+    ] {
+        assert!(isotones.contains(&isotone));
+    }
+    assert!(!isotones.contains(&CA40));
+    assert!(isotones
+        .iter()
+        .all(|isotone| isotone.neutron_number() == 20));
+}
+
+#[test]
+fn sister_isotopes() {
+    assert_eq!(
+        AR40.sister_isotopes().collect::<Vec<_>>(),
+        [Isotope::Ar(Ar::ThirtySix), Isotope::Ar(Ar::ThirtyEight)]
+    );
+    assert_eq!(CA40.sister_isotopes().count(), 5);
+}
+
+#[test]
+fn resolving_power() {
+    let difference = AR40.mass_difference(&CA40);
+    assert!((difference.value - 0.000_208).abs() < 1e-6);
+    assert!(difference.uncertainty > 0.0);
+    assert_eq!(CA40.mass_difference(&AR40).value, -difference.value);
+    let resolving_power = CA40.resolving_power(&AR40);
+    assert!((resolving_power - 192_000.0).abs() < 1_000.0);
+    // Isobars need a far higher resolution than sister isotopes
+    assert!(AR40.resolving_power(&Isotope::Ar(Ar::ThirtyEight)) < 100.0);
+    assert_eq!(AR40.resolving_power(&AR40), f64::INFINITY);
+}
@@ -0,0 +1,90 @@
+use atom::{isotopes::Tc, Error, Isomer, Isotope, Nuclide, Parity, SpinParity};
+
+#[test]
+fn data() {
+    let technetium = Isomer::new(Nuclide::new(43, 99).unwrap(), 1).unwrap();
+    assert_eq!(technetium.isotope(), Some(Isotope::Tc(Tc::NinetyNine)));
+    assert!((technetium.excitation_energy().unwrap().value - 142.683_6).abs() < 1e-9);
+    assert!((technetium.half_life().unwrap().value - 6.006_7 * 3_600.0).abs() < 1e-6);
+    assert_eq!(
+        technetium.spin_parity(),
+        Some(SpinParity::new(1, Parity::Negative))
+    );
+    // Ground state not tabulated
+    let americium: Isomer = "242mAm".parse().unwrap();
+    assert_eq!(americium.isotope(), None);
+    assert_eq!(americium.spin_parity().unwrap().to_string(), "5-");
+    // No decay observed
+    let tantalum: Isomer = "180mTa".parse().unwrap();
+    assert!(tantalum.half_life().unwrap().value.is_infinite());
+    // Not tabulated
+    assert_eq!(Isomer::new(Nuclide::new(43, 99).unwrap(), 2), None);
+    // Ordered by excitation energy
+    for isomer in Isomer::all() {
+        assert!(isomer.excitation_energy().unwrap().value > 0.0);
+        if isomer.state() > 1 {
+            let lower = Isomer::new(isomer.nuclide(), isomer.state() - 1).unwrap();
+            assert!(lower.excitation_energy() < isomer.excitation_energy());
+        }
+    }
+}
+
+#[test]
+fn display() {
+    assert_eq!(
+        Isomer::new(Nuclide::new(43, 99).unwrap(), 1)
+            .unwrap()
+            .to_string(),
+        "99mTc"
+    );
+    let hafnium = Nuclide::new(72, 178).unwrap();
+    assert_eq!(Isomer::new(hafnium, 1).unwrap().to_string(), "178m1Hf");
+    assert_eq!(Isomer::new(hafnium, 2).unwrap().to_string(), "178m2Hf");
+    assert_eq!(Isomer::of(hafnium).count(), 2);
+    for isomer in Isomer::all() {
+        assert_eq!(isomer.to_string().parse::<Isomer>(), Ok(isomer));
+    }
+}
+
+#[test]
+fn parse() {
+    let technetium = Isomer::new(Nuclide::new(43, 99).unwrap(), 1).unwrap();
+    assert_eq!("99mTc".parse(), Ok(technetium));
+    assert_eq!("Tc-99m".parse(), Ok(technetium));
+    assert_eq!("Tc99m".parse(), Ok(technetium));
+    assert_eq!("99m1Tc".parse(), Ok(technetium));
+    let hafnium = Isomer::new(Nuclide::new(72, 178).unwrap(), 2).unwrap();
+    assert_eq!("178m2Hf".parse(), Ok(hafnium));
+    assert_eq!("Hf-178m2".parse(), Ok(hafnium));
+    // Errors
+    let error = "99mXx".parse::<Isomer>().unwrap_err();
+    assert!(matches!(error, Error::UnknownSymbol { .. }));
+    assert_eq!(error.offset(), Some(3));
+    assert!(matches!(
+        "Tc-99".parse::<Isomer>(),
+        Err(Error::UnknownIsomer { .. })
+    ));
+    assert!(matches!(
+        "98mTc".parse::<Isomer>(),
+        Err(Error::UnknownIsomer { .. })
+    ));
+    assert_eq!(
+        "178m0Hf".parse::<Isomer>(),
+        Err(Error::InvalidCount {
+            text: "0".to_owned(),
+            offset: 4,
+        })
+    );
+    assert_eq!(
+        "Hf-178m300".parse::<Isomer>().unwrap_err().offset(),
+        Some(7)
+    );
+    assert!(matches!(
+        "99m3Tc".parse::<Isomer>(),
+        Err(Error::UnknownIsomer { .. })
+    ));
+    assert!(matches!(
+        "1mTc".parse::<Isomer>(),
+        Err(Error::InvalidMassNumber { offset: 0, .. })
+    ));
+}
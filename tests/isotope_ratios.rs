@@ -0,0 +1,142 @@
+use atom::{
+    isotopes::{C, H, N, O},
+    uncertain, Error, Isotope, IsotopeRatio, ReferenceMaterial,
+};
+
+const C13: Isotope = Isotope::C(C::Thirteen);
+const C12: Isotope = Isotope::C(C::Twelve);
+
+#[test]
+fn delta() {
+    let vpdb = IsotopeRatio::reference(C13, C12, ReferenceMaterial::Vpdb).unwrap();
+    assert!(vpdb.delta(ReferenceMaterial::Vpdb).unwrap().value.abs() < 1e-12);
+
+    let ratio = IsotopeRatio::from_delta(C13, C12, uncertain!(-25.0, 0.1), ReferenceMaterial::Vpdb)
+        .unwrap();
+    assert!((ratio.value().value - 0.011_180_2 * 0.975).abs() < 1e-12);
+    let delta = ratio.delta(ReferenceMaterial::Vpdb).unwrap();
+    assert!((delta.value + 25.0).abs() < 1e-9);
+    assert!(delta.uncertainty > 0.1);
+
+    let water = IsotopeRatio::from_delta(
+        Isotope::O(O::Eighteen),
+        Isotope::O(O::Sixteen),
+        uncertain!(-55.5),
+        ReferenceMaterial::Vsmow,
+    )
+    .unwrap();
+    let slap = IsotopeRatio::reference(
+        Isotope::O(O::Eighteen),
+        Isotope::O(O::Sixteen),
+        ReferenceMaterial::Slap,
+    )
+    .unwrap();
+    assert!((water.value().value - slap.value().value).abs() < 1e-8);
+
+    assert_eq!(
+        ratio.delta(ReferenceMaterial::Vsmow),
+        Err(Error::UnknownRatio {
+            reference: ReferenceMaterial::Vsmow,
+            numerator: C13,
+            denominator: C12,
+        }),
+    );
+    assert_eq!(
+        ratio
+            .delta(ReferenceMaterial::Vsmow)
+            .unwrap_err()
+            .to_string(),
+        format!(
+            "{} has no {C13}/{C12} ratio",
+            ReferenceMaterial::Vsmow.name()
+        ),
+    );
+}
+
+#[test]
+fn fraction() {
+    let ratio = IsotopeRatio::new(C13, C12, uncertain!(1.0, 0.04)).unwrap();
+    assert_eq!(ratio.fraction(&[]).unwrap(), uncertain!(0.5, 0.01));
+    assert_eq!(ratio.atom_percent(&[]).unwrap(), uncertain!(50.0, 1.0));
+    assert_eq!(
+        IsotopeRatio::from_fraction(C13, C12, uncertain!(0.5, 0.01), &[])
+            .unwrap()
+            .value(),
+        uncertain!(1.0, 0.04)
+    );
+    assert_eq!(
+        IsotopeRatio::from_atom_percent(C13, C12, uncertain!(50.0, 1.0), &[]).unwrap(),
+        ratio
+    );
+    assert!(matches!(
+        IsotopeRatio::from_fraction(C13, C12, uncertain!(1.0), &[]),
+        Err(Error::InvalidFraction { .. })
+    ));
+}
+
+#[test]
+fn atom_percent_excess() {
+    let background = IsotopeRatio::from_atom_percent(
+        Isotope::N(N::Fifteen),
+        Isotope::N(N::Fourteen),
+        uncertain!(0.366_3),
+        &[],
+    )
+    .unwrap();
+    let enriched = IsotopeRatio::from_atom_percent(
+        Isotope::N(N::Fifteen),
+        Isotope::N(N::Fourteen),
+        uncertain!(5.366_3),
+        &[],
+    )
+    .unwrap();
+    let excess = enriched.atom_percent_excess(&[], &background, &[]).unwrap();
+    assert!((excess.value - 5.0).abs() < 1e-9);
+    let other = IsotopeRatio::new(C13, C12, uncertain!(0.011)).unwrap();
+    assert_eq!(
+        enriched.atom_percent_excess(&[], &other, &[]),
+        Err(Error::RatioMismatch {
+            expected_numerator: Isotope::N(N::Fifteen),
+            expected_denominator: Isotope::N(N::Fourteen),
+            numerator: C13,
+            denominator: C12,
+        })
+    );
+}
+
+#[test]
+fn new() {
+    assert!(IsotopeRatio::new(C13, Isotope::H(H::One), uncertain!(1.0)).is_err());
+}
+
+#[test]
+fn multiple_isotopes() {
+    let o16 = Isotope::O(O::Sixteen);
+    let o17 = Isotope::O(O::Seventeen);
+    let o18 = Isotope::O(O::Eighteen);
+    let r18 = IsotopeRatio::new(o18, o16, uncertain!(0.002_005_2, 0.000_000_5)).unwrap();
+    let r17 = IsotopeRatio::new(o17, o16, uncertain!(0.000_379_9, 0.000_000_8)).unwrap();
+    // ¹⁷O can not be ignored
+    assert_eq!(
+        r18.fraction(&[]),
+        Err(Error::MissingRatio {
+            numerator: o17,
+            denominator: o16,
+        })
+    );
+    let fraction = r18.fraction(&[r17]).unwrap();
+    assert!((fraction.value - 0.002_005_2 / 1.002_385_1).abs() < 1e-12);
+    assert!(fraction.uncertainty > 0.000_000_5 / 1.002_385_1_f64.powi(2));
+    let ratio = IsotopeRatio::from_fraction(o18, o16, fraction, &[r17]).unwrap();
+    assert!((ratio.value().value - r18.value().value).abs() < 1e-15);
+    // Ratios to another denominator or of the same isotope are rejected
+    let r16 = IsotopeRatio::new(o16, o18, uncertain!(498.7)).unwrap();
+    assert!(matches!(
+        r18.fraction(&[r17, r16]),
+        Err(Error::RatioMismatch { .. })
+    ));
+    assert!(matches!(
+        r18.fraction(&[r17, r17]),
+        Err(Error::RatioMismatch { .. })
+    ));
+}
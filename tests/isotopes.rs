@@ -1,6 +1,8 @@
-#![cfg(feature = "nist")]
-
-use atom::{isotopes::*, uncertain};
+use atom::{
+    isotopes::*, uncertain, Consistency, DecayMode, Element, Error, Isotope, IsotopicComposition,
+    Parity, Source, SpinParity, ISOTOPES,
+};
+use strum::IntoEnumIterator;
 
 #[test]
 #[rustfmt::skip]
@@ -363,6 +365,7 @@ fn isotopic_composition() {
 
 #[test]
 #[rustfmt::skip]
+#[allow(clippy::float_equality_without_abs)]
 fn isotopic_composition_sum_is_one() {
     assert!((H::One.isotopic_composition().unwrap_or_default() + H::Two.isotopic_composition().unwrap_or_default() + H::Three.isotopic_composition().unwrap_or_default()).value - 1.0 < f64::EPSILON);
     assert!((He::Three.isotopic_composition().unwrap_or_default() + He::Four.isotopic_composition().unwrap_or_default()).value - 1.0 < f64::EPSILON);
@@ -1200,3 +1203,415 @@ fn relative_atomic_mass() {
     assert_eq!(Ts::TwoHundredNinetyTwo.relative_atomic_mass(), uncertain!(292.20746, 000.00075));
     assert_eq!(Og::TwoHundredNinetyFour.relative_atomic_mass(), uncertain!(294.21392, 000.00071));
 }
+
+#[test]
+fn isotopic_atomic_weight() {
+    for source in Source::iter() {
+        for consistency in Consistency::check(source) {
+            assert!(consistency.is_consistent(), "{consistency:?}");
+        }
+    }
+    assert_eq!(Consistency::check(Source::Nist).count(), 84);
+    assert_eq!(Element::Tc.isotopic_atomic_weight(), None);
+    assert_eq!(
+        Element::C.isotopic_atomic_weight_with(|isotope| {
+            (*isotope == Isotope::C(C::Thirteen)).then_some(uncertain!(1.0))
+        }),
+        Some(C::Thirteen.relative_atomic_mass()),
+    );
+}
+
+#[test]
+fn isotopic_composition_runtime() {
+    let natural = IsotopicComposition::natural(Element::C);
+    assert_eq!(
+        natural.fraction(Isotope::C(C::Twelve)),
+        C::Twelve.isotopic_composition()
+    );
+    assert_eq!(natural.fraction(Isotope::C(C::Fourteen)), None);
+    assert_eq!(natural.validate(), Ok(()));
+    assert_eq!(natural.atomic_weight(), Element::C.isotopic_atomic_weight());
+
+    // 99 % 13C
+    let labeled = IsotopicComposition::enriched(Isotope::C(C::Thirteen), uncertain!(0.99)).unwrap();
+    assert_eq!(labeled.validate(), Ok(()));
+    let c12 = labeled.fraction(Isotope::C(C::Twelve)).unwrap();
+    assert!((c12.value - 0.01).abs() < 1e-12);
+    // The natural uncertainty is rescaled with the fraction
+    assert!((c12.uncertainty - 0.000_8 * 0.01 / 0.989_3).abs() < 1e-12);
+    assert!(labeled.atomic_weight().unwrap().value > 12.99);
+    // No other natural isotope can make up the rest
+    assert!(matches!(
+        IsotopicComposition::enriched(Isotope::F(F::Nineteen), uncertain!(0.5)),
+        Err(Error::NotNormalized {
+            element: Element::F,
+            ..
+        })
+    ));
+    assert!(matches!(
+        IsotopicComposition::enriched(Isotope::Tc(Tc::NinetyNine), uncertain!(0.5)),
+        Err(Error::NotNormalized { .. })
+    ));
+    let fluorine = IsotopicComposition::enriched(Isotope::F(F::Nineteen), uncertain!(1.0)).unwrap();
+    assert_eq!(fluorine.validate(), Ok(()));
+
+    // D2O
+    let deuterium = IsotopicComposition::new(Element::H)
+        .with(Isotope::H(H::Two), uncertain!(1.0))
+        .unwrap();
+    assert_eq!(
+        deuterium.atomic_weight(),
+        Some(H::Two.relative_atomic_mass())
+    );
+
+    let mut error = IsotopicComposition::new(Element::U)
+        .with(Isotope::U(U::TwoHundredThirtyFive), uncertain!(0.2))
+        .unwrap()
+        .with(Isotope::U(U::TwoHundredThirtyEight), uncertain!(99.8))
+        .unwrap_err();
+    assert!(matches!(error, Error::InvalidFraction { .. }));
+    error = IsotopicComposition::new(Element::U)
+        .with(Isotope::C(C::Twelve), uncertain!(1.0))
+        .unwrap_err();
+    assert!(matches!(error, Error::ForeignIsotope { .. }));
+
+    let mut unnormalized = IsotopicComposition::new(Element::U)
+        .with(Isotope::U(U::TwoHundredThirtyFive), uncertain!(0.2))
+        .unwrap()
+        .with(Isotope::U(U::TwoHundredThirtyEight), uncertain!(0.6))
+        .unwrap();
+    assert!(matches!(
+        unnormalized.validate(),
+        Err(Error::NotNormalized { .. })
+    ));
+    unnormalized.normalize();
+    assert_eq!(unnormalized.validate(), Ok(()));
+    assert_eq!(
+        unnormalized.fraction(Isotope::U(U::TwoHundredThirtyFive)),
+        Some(uncertain!(0.25))
+    );
+}
+
+#[test]
+fn half_life() {
+    const YEAR: f64 = 31_556_926.0;
+    let tritium = H::Three.half_life().unwrap();
+    assert!((tritium.value / YEAR - 12.32).abs() < 1e-9);
+    assert!((tritium.uncertainty / YEAR - 0.02).abs() < 1e-9);
+    assert!((Isotope::C(C::Fourteen).half_life().unwrap().value / YEAR - 5_700.0).abs() < 1e-6);
+    assert!(
+        (Isotope::Rn(Rn::TwoHundredTwentyTwo)
+            .half_life()
+            .unwrap()
+            .value
+            - 330_177.6)
+            .abs()
+            < 1e-3
+    );
+    // Stable
+    assert!(Isotope::C(C::Twelve)
+        .half_life()
+        .unwrap()
+        .value
+        .is_infinite());
+    assert!(Isotope::C(C::Twelve).is_stable());
+    assert!(!Isotope::C(C::Fourteen).is_stable());
+    // Unknown
+    assert_eq!(Isotope::Ts(Ts::TwoHundredNinetyTwo).half_life(), None);
+    assert!(!Isotope::Ts(Ts::TwoHundredNinetyTwo).is_stable());
+    // Primordial radionuclides
+    assert!(!Isotope::K(K::Forty).is_stable());
+    assert!(!Isotope::U(U::TwoHundredThirtyEight).is_stable());
+    // Isotopes without a natural occurrence are radioactive
+    for isotope in ISOTOPES {
+        if isotope.isotopic_composition().is_none() {
+            assert!(!isotope.is_stable(), "{isotope:?}");
+        }
+    }
+}
+
+#[test]
+fn decay_modes() {
+    assert_eq!(H::Three.decay_modes(), &[(DecayMode::BetaMinus, 1.0)]);
+    assert_eq!(C::Twelve.decay_modes(), &[]);
+    assert_eq!(
+        Isotope::K(K::Forty).decay_modes(),
+        &[
+            (DecayMode::BetaMinus, 0.892_8),
+            (DecayMode::ElectronCapture, 0.107_2)
+        ]
+    );
+    for isotope in ISOTOPES {
+        let modes = isotope.decay_modes();
+        assert_eq!(
+            modes.is_empty(),
+            isotope.is_stable() || isotope.half_life().is_none()
+        );
+        if !modes.is_empty() {
+            let sum: f64 = modes.iter().map(|(_, ratio)| ratio).sum();
+            assert!((sum - 1.0).abs() < 1e-9, "{isotope:?}");
+        }
+    }
+    assert_eq!(DecayMode::Alpha.to_string(), "α");
+}
+
+#[test]
+fn spin_parity() {
+    assert_eq!(
+        H::One.spin_parity(),
+        Some(SpinParity::new(1, Parity::Positive))
+    );
+    assert_eq!(H::One.spin_parity().unwrap().to_string(), "1/2+");
+    assert_eq!(
+        Isotope::U(U::TwoHundredThirtyFive)
+            .spin_parity()
+            .unwrap()
+            .to_string(),
+        "7/2-"
+    );
+    assert_eq!(
+        Isotope::K(K::Forty).spin_parity().unwrap().to_string(),
+        "4-"
+    );
+    assert_eq!(
+        Isotope::C(C::Twelve).spin_parity().unwrap().to_string(),
+        "0+"
+    );
+    assert_eq!(Isotope::Ts(Ts::TwoHundredNinetyTwo).spin_parity(), None);
+    for isotope in ISOTOPES {
+        let Some(spin_parity) = isotope.spin_parity() else {
+            continue;
+        };
+        // Half-integer spin for odd mass numbers
+        assert_eq!(
+            spin_parity.doubled() % 2,
+            isotope.mass_number() as u8 % 2,
+            "{isotope:?}"
+        );
+        let protons = isotope.element().atomic_number();
+        if protons % 2 == 0 && (isotope.mass_number() - protons) % 2 == 0 {
+            assert_eq!(spin_parity, SpinParity::new(0, Parity::Positive));
+        }
+    }
+}
+
+#[test]
+fn mass_excess() {
+    assert_eq!(Isotope::C(C::Twelve).mass_excess().value, 0.0);
+    assert!((Isotope::H(H::One).mass_excess().value - 7_288.971).abs() < 1e-3);
+    assert!((Isotope::H(H::Two).mass_excess().value - 13_135.723).abs() < 1e-3);
+    assert!(Isotope::Fe(Fe::FiftySix).mass_excess().value < 0.0);
+}
+
+#[test]
+fn new() {
+    assert_eq!(Isotope::new(Element::C, 13), Some(Isotope::C(C::Thirteen)));
+    assert_eq!(Isotope::new(Element::C, 11), None);
+    assert_eq!(Isotope::new(Element::Be, 9), Some(Isotope::Be(Be::Nine)));
+    assert_eq!(C::from_mass_number(14), Some(C::Fourteen));
+    assert_eq!(
+        Isotope::try_from((Element::U, 235)),
+        Ok(Isotope::U(U::TwoHundredThirtyFive))
+    );
+    assert_eq!(
+        Isotope::try_from((Element::U, 237)),
+        Err(Error::UnknownIsotope {
+            element: Element::U,
+            mass_number: 237
+        })
+    );
+    // Inverse of `element` and `mass_number`
+    for isotope in ISOTOPES {
+        assert_eq!(
+            Isotope::new(isotope.element(), isotope.mass_number()),
+            Some(isotope)
+        );
+    }
+}
+
+#[test]
+fn parse() {
+    assert_eq!("C".parse(), Ok(Isotope::C(C::Twelve)));
+    assert_eq!("Fe".parse(), Ok(Isotope::Fe(Fe::FiftySix)));
+    // No natural isotopic composition
+    assert_eq!("Tc".parse(), Ok(Isotope::Tc(Tc::NinetyNine)));
+    assert_eq!("Og".parse(), Ok(Isotope::Og(Og::TwoHundredNinetyFour)));
+    assert_eq!(
+        "CL".parse::<Isotope>().unwrap_err().suggestion(),
+        Some("Cl")
+    );
+}
+
+#[test]
+fn notation() {
+    let carbon = Isotope::C(C::Thirteen);
+    for text in [
+        "13C",
+        "[13C]",
+        "¹³C",
+        "C-13",
+        "C13",
+        "Carbon-13",
+        "carbon-13",
+    ] {
+        assert_eq!(text.parse(), Ok(carbon), "{text}");
+    }
+    assert_eq!("D".parse(), Ok(Isotope::H(H::Two)));
+    assert_eq!("T".parse(), Ok(Isotope::H(H::Three)));
+    assert_eq!("[2H]".parse(), Ok(Isotope::H(H::Two)));
+    assert_eq!("²³⁸U".parse(), Ok(Isotope::U(U::TwoHundredThirtyEight)));
+
+    assert_eq!(carbon.to_string(), "¹³C");
+    assert_eq!(format!("{carbon:#}"), "Carbon-13");
+    assert_eq!(format!("{carbon:+}"), "13C");
+    assert_eq!(format!("{carbon:-}"), "C-13");
+    assert_eq!(format!("{carbon:+#}"), r"^{13}\mathrm{C}");
+    assert_eq!(format!("[{carbon:>5}]"), "[  ¹³C]");
+    assert_eq!(format!("[{carbon:*<+6}]"), "[13C***]");
+    assert_eq!(format!("[{carbon:^-8}]"), "[  C-13  ]");
+    assert_eq!(
+        format!("{:#}", Isotope::Og(Og::TwoHundredNinetyFour)),
+        "Oganesson-294"
+    );
+    for isotope in ISOTOPES {
+        for text in [
+            format!("{isotope}"),
+            format!("{isotope:#}"),
+            format!("{isotope:+}"),
+            format!("{isotope:-}"),
+        ] {
+            assert_eq!(text.parse(), Ok(isotope), "{text}");
+        }
+    }
+}
+
+#[test]
+fn notation_errors() {
+    let error = "13X".parse::<Isotope>().unwrap_err();
+    assert!(matches!(error, Error::UnknownSymbol { .. }));
+    assert_eq!(error.offset(), Some(2));
+    assert_eq!(
+        "13c".parse::<Isotope>().unwrap_err().suggestion(),
+        Some("C")
+    );
+    let error = "C-15".parse::<Isotope>().unwrap_err();
+    assert_eq!(
+        error,
+        Error::InvalidMassNumber {
+            text: "15".to_owned(),
+            offset: 2,
+            suggestion: Some("14".to_owned()),
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "invalid mass number `15` at byte 2, did you mean `14`?"
+    );
+    assert_eq!("[15C]".parse::<Isotope>().unwrap_err().offset(), Some(1));
+    assert!(matches!(
+        "C-".parse::<Isotope>(),
+        Err(Error::InvalidMassNumber { offset: 2, .. })
+    ));
+    assert_eq!(
+        "[13C".parse::<Isotope>(),
+        Err(Error::UnbalancedBracket {
+            text: "[".to_owned(),
+            offset: 0
+        })
+    );
+    assert_eq!(
+        "13C]".parse::<Isotope>(),
+        Err(Error::UnbalancedBracket {
+            text: "]".to_owned(),
+            offset: 3
+        })
+    );
+}
+
+#[test]
+fn codes() {
+    let carbon = Isotope::C(C::Thirteen);
+    assert_eq!(carbon.zaid(), 6_013);
+    assert_eq!(Isotope::from_zaid(6_013), Some(carbon));
+    assert_eq!(Isotope::U(U::TwoHundredThirtyFive).zaid(), 92_235);
+    assert_eq!(Isotope::from_zaid(6_011), None);
+    assert_eq!(Isotope::from_zaid(0), None);
+    assert_eq!(Isotope::from_zaid(200_001), None);
+
+    assert_eq!(carbon.pdg(), 1_000_060_130);
+    assert_eq!(Isotope::from_pdg(1_000_060_130), Some(carbon));
+    // Isomer
+    assert_eq!(Isotope::from_pdg(1_000_060_131), None);
+    // Hypernucleus
+    assert_eq!(Isotope::from_pdg(1_010_060_130), None);
+    assert_eq!(Isotope::from_pdg(2_212), None);
+
+    assert_eq!(Isotope::H(H::One).index(), 0);
+    assert_eq!(Isotope::from_index(354), None);
+    for (index, isotope) in ISOTOPES.into_iter().enumerate() {
+        assert_eq!(isotope.index() as usize, index);
+        assert_eq!(Isotope::from_index(isotope.index()), Some(isotope));
+        assert_eq!(Isotope::from_zaid(isotope.zaid()), Some(isotope));
+        assert_eq!(Isotope::from_pdg(isotope.pdg()), Some(isotope));
+    }
+}
+
+#[test]
+fn nucleons() {
+    let fe56 = Isotope::Fe(Fe::FiftySix);
+    assert_eq!(fe56.atomic_number(), 26);
+    assert_eq!(fe56.neutron_number(), 30);
+    assert_eq!(Isotope::H(H::One).neutron_number(), 0);
+    assert_eq!(Isotope::U(U::TwoHundredThirtyEight).neutron_number(), 146);
+}
+
+#[test]
+fn binding_energy() {
+    assert!(Isotope::H(H::One).binding_energy().value.abs() < 1e-6);
+    assert!((Isotope::H(H::Two).binding_energy().value - 2_224.566).abs() < 1e-2);
+    assert!((Isotope::He(He::Four).binding_energy().value - 28_295.67).abs() < 1e-1);
+    let fe56 = Isotope::Fe(Fe::FiftySix);
+    assert!((fe56.binding_energy_per_nucleon().value - 8_790.36).abs() < 1e-1);
+    assert!(fe56.binding_energy().uncertainty > 0.0);
+    let most_bound = ISOTOPES
+        .into_iter()
+        .max_by(|left, right| {
+            left.binding_energy_per_nucleon()
+                .cmp(&right.binding_energy_per_nucleon())
+        })
+        .unwrap();
+    assert_eq!(most_bound, Isotope::Ni(Ni::SixtyTwo));
+}
+
+#[test]
+fn separation_energy() {
+    let deuterium = Isotope::H(H::Two);
+    let binding_energy = deuterium.binding_energy().value;
+    assert!((deuterium.neutron_separation_energy().unwrap().value - binding_energy).abs() < 1e-9);
+    assert!((deuterium.proton_separation_energy().unwrap().value - binding_energy).abs() < 1e-9);
+    assert_eq!(Isotope::H(H::One).neutron_separation_energy(), None);
+    let c13 = Isotope::C(C::Thirteen).neutron_separation_energy().unwrap();
+    assert!((c13.value - 4_946.31).abs() < 1e-1);
+    // M(¹H) and mₙ enter once, not through both binding energies
+    let binding_energies = Isotope::C(C::Thirteen).binding_energy().uncertainty
+        + Isotope::C(C::Twelve).binding_energy().uncertainty;
+    assert!(c13.uncertainty < binding_energies);
+    let o16 = Isotope::O(O::Sixteen);
+    assert!((o16.proton_separation_energy().unwrap().value - 12_127.4).abs() < 1e-1);
+    assert_eq!(o16.two_neutron_separation_energy(), None);
+    let o18 = Isotope::O(O::Eighteen)
+        .two_neutron_separation_energy()
+        .unwrap();
+    assert!((o18.value - 12_188.5).abs() < 1e-1);
+    assert_eq!(Isotope::He(He::Four).two_neutron_separation_energy(), None);
+    // Nothing bound remains
+    let he4 = Isotope::He(He::Four);
+    assert!(
+        (he4.two_proton_separation_energy().unwrap().value - he4.binding_energy().value).abs()
+            < 1e-9
+    );
+    let ca40 = Isotope::Ca(Ca::Forty)
+        .two_proton_separation_energy()
+        .unwrap();
+    assert!((ca40.value - 14_709.5).abs() < 1e-1);
+}
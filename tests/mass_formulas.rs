@@ -0,0 +1,74 @@
+use atom::{
+    isotopes::{Fe, Pb, U},
+    Isotope, MassFormula, Nuclide,
+};
+use strum::IntoEnumIterator;
+
+#[test]
+fn binding_energy() {
+    for (isotope, tolerance) in [
+        (Isotope::Fe(Fe::FiftySix), 0.01),
+        (Isotope::Pb(Pb::TwoHundredEight), 0.02),
+        (Isotope::U(U::TwoHundredThirtyEight), 0.02),
+    ] {
+        let tabulated = isotope.binding_energy().value;
+        for formula in MassFormula::iter() {
+            let predicted = formula.binding_energy(isotope.into());
+            assert!((predicted / tabulated - 1.0).abs() < tolerance);
+        }
+    }
+    let fe56 = MassFormula::Rohlf.binding_energy(Nuclide::new(26, 56).unwrap());
+    assert!((fe56 - 495_383.7).abs() < 1e-1);
+}
+
+#[test]
+fn pairing() {
+    let formula = MassFormula::Krane;
+    let liquid_drop = |atomic_number: f64, mass_number: f64| {
+        formula.volume() * mass_number
+            - formula.surface() * mass_number.powf(2.0 / 3.0)
+            - formula.coulomb() * atomic_number * (atomic_number - 1.0) / mass_number.cbrt()
+            - formula.asymmetry() * (mass_number - 2.0 * atomic_number).powi(2) / mass_number
+    };
+    let pairing =
+        |mass_number: f64| formula.pairing() / mass_number.powf(formula.pairing_exponent());
+    let energy = |atomic_number, mass_number| {
+        formula.binding_energy(Nuclide::new(atomic_number, mass_number).unwrap())
+    };
+    // Even-even
+    assert!((energy(8, 16) - liquid_drop(8.0, 16.0) - pairing(16.0)).abs() < 1e-6);
+    // Odd-odd
+    assert!((energy(7, 14) - liquid_drop(7.0, 14.0) + pairing(14.0)).abs() < 1e-6);
+    // Odd A
+    assert!((energy(6, 13) - liquid_drop(6.0, 13.0)).abs() < 1e-6);
+    assert_eq!(MassFormula::default(), MassFormula::Rohlf);
+}
+
+#[test]
+fn relative_atomic_mass() {
+    // Not tabulated
+    let nuclide = Nuclide::new(50, 100).unwrap();
+    assert_eq!(nuclide.isotope(), None);
+    let mass = MassFormula::Rohlf.relative_atomic_mass(nuclide);
+    assert!((mass - 99.94).abs() < 1e-1);
+    let fe56 = Isotope::Fe(Fe::FiftySix);
+    let mass = MassFormula::Rohlf.relative_atomic_mass(fe56.into());
+    assert!((mass - fe56.relative_atomic_mass().value).abs() < 1e-2);
+}
+
+#[test]
+fn residuals() {
+    for formula in MassFormula::iter() {
+        let residuals: Vec<_> = formula.residuals().collect();
+        assert_eq!(residuals.len(), atom::ISOTOPES.len());
+        let (isotope, residual) = residuals
+            .iter()
+            .find(|(isotope, _)| *isotope == Isotope::Fe(Fe::FiftySix))
+            .unwrap();
+        let expected = formula.binding_energy((*isotope).into()) - isotope.binding_energy().value;
+        assert!((residual.value - expected).abs() < 1e-3);
+        assert!(residual.uncertainty > 0.0);
+        assert!(formula.rms_residual() < 15_000.0);
+    }
+    assert!(MassFormula::Rohlf.rms_residual() < MassFormula::Wapstra.rms_residual());
+}
@@ -0,0 +1,54 @@
+use atom::{
+    isotopes::{C, F, H, N, O, P},
+    Isotope, ISOTOPES,
+};
+
+#[test]
+fn gyromagnetic_ratio() {
+    let proton = Isotope::H(H::One).gyromagnetic_ratio().unwrap();
+    assert!((proton / 2.675_221_874e8 - 1.0).abs() < 1e-6);
+    let carbon = Isotope::C(C::Thirteen).gyromagnetic_ratio().unwrap();
+    assert!((carbon / 6.728_284e7 - 1.0).abs() < 1e-4);
+    assert!(Isotope::N(N::Fifteen).gyromagnetic_ratio().unwrap() < 0.0);
+    assert_eq!(Isotope::C(C::Twelve).gyromagnetic_ratio(), None);
+}
+
+#[test]
+fn quadrupole_moment() {
+    assert_eq!(Isotope::H(H::One).quadrupole_moment(), Some(0.0));
+    assert_eq!(Isotope::H(H::Two).quadrupole_moment(), Some(0.286));
+    assert_eq!(Isotope::O(O::Sixteen).quadrupole_moment(), None);
+    for isotope in ISOTOPES {
+        if let Some(quadrupole_moment) = isotope.quadrupole_moment() {
+            let spin = isotope.spin_parity().unwrap().spin();
+            assert!(spin > 0.0, "{isotope:?}");
+            assert_eq!(spin < 1.0, quadrupole_moment == 0.0, "{isotope:?}");
+        }
+    }
+}
+
+#[test]
+fn larmor_frequency() {
+    let proton = Isotope::H(H::One).larmor_frequency(9.394_67).unwrap();
+    assert!((proton - 400.0).abs() < 0.01);
+    let carbon = Isotope::C(C::Thirteen).frequency(400.0).unwrap();
+    assert!((carbon - 100.60).abs() < 0.01);
+    let fluorine = Isotope::F(F::Nineteen).frequency(600.0).unwrap();
+    assert!((fluorine - 564.77).abs() < 0.01);
+    let phosphorus = Isotope::P(P::ThirtyOne).frequency(600.0).unwrap();
+    assert!((phosphorus - 243.11).abs() < 0.01);
+    // Negative γ
+    let nitrogen = Isotope::N(N::Fifteen).frequency(600.0).unwrap();
+    assert!((nitrogen - 60.84).abs() < 0.01);
+}
+
+#[test]
+fn receptivity() {
+    assert_eq!(Isotope::H(H::One).receptivity(), Some(1.0));
+    let carbon = Isotope::C(C::Thirteen).receptivity().unwrap();
+    assert!((carbon / 1.70e-4 - 1.0).abs() < 0.01);
+    let phosphorus = Isotope::P(P::ThirtyOne).receptivity().unwrap();
+    assert!((phosphorus / 6.65e-2 - 1.0).abs() < 0.01);
+    // No natural isotopic composition
+    assert_eq!(Isotope::H(H::Three).receptivity(), None);
+}
@@ -0,0 +1,77 @@
+use atom::{isotopes::C, Element, Isotope, Nuclide};
+
+#[test]
+fn nuclide() {
+    let nuclide = Nuclide::new(6, 14).unwrap();
+    assert_eq!(nuclide.neutron_number(), 8);
+    assert_eq!(nuclide.element(), Some(Element::C));
+    assert_eq!(nuclide.isotope(), Some(Isotope::C(C::Fourteen)));
+    assert_eq!(Nuclide::from(Isotope::C(C::Fourteen)), nuclide);
+    assert_eq!(nuclide.to_string(), "14C");
+    // Not tabulated
+    assert_eq!(Nuclide::new(6, 11).unwrap().isotope(), None);
+    assert_eq!(Nuclide::new(0, 1).unwrap().to_string(), "n");
+    assert_eq!(Nuclide::new(119, 300).unwrap().to_string(), "300Uue");
+    assert_eq!(Nuclide::new(6, 5), None);
+    assert_eq!(Nuclide::new(0, 0), None);
+    assert_eq!(
+        nuclide.relative_atomic_mass(),
+        Some(Isotope::C(C::Fourteen).relative_atomic_mass())
+    );
+}
+
+#[cfg(feature = "nuclides")]
+mod table {
+    use atom::{Error, Nuclide, NuclideTable, ISOTOPES};
+
+    const AME: &str = r"1    N-Z    N    Z   A  EL    O     MASS EXCESS(keV)     BINDING ENERGY/A (keV)        BETA-DECAY ENERGY(keV)      ATOMIC MASS(micro-u)
+0  1    1    0    1 n           8071.31806     0.00044          0.0        0.0 B-     782.3470     0.0004   1  008664.91590     0.00047
+  -1    0    1    1 H          7288.971064    0.000013          0.0        0.0 B-            *              1 007825.031898    0.000014
+0  0    6    6   12 C                  0.0         0.0    7680.1446     0.0002 B-  -17338.0681     0.9999  12      000000.0         0.0
+0 59  176  117  293 Ts -a          194340#        720#        7111#         2# B-            *            293       208630#        770#";
+
+    #[test]
+    fn new() {
+        let table = NuclideTable::new();
+        assert_eq!(table.len(), ISOTOPES.len());
+        for isotope in ISOTOPES {
+            assert_eq!(
+                table.relative_atomic_mass(isotope.into()),
+                Some(isotope.relative_atomic_mass())
+            );
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let table = NuclideTable::parse(AME).unwrap();
+        // Neutron and Ts-293 are new
+        assert_eq!(table.len(), ISOTOPES.len() + 2);
+        let neutron = table
+            .relative_atomic_mass(Nuclide::new(0, 1).unwrap())
+            .unwrap();
+        assert!((neutron.value - 1.008_664_915_90).abs() < 1e-12);
+        let hydrogen = table
+            .relative_atomic_mass(Nuclide::new(1, 1).unwrap())
+            .unwrap();
+        assert!((hydrogen.value - 1.007_825_031_898).abs() < 1e-12);
+        assert!((hydrogen.uncertainty - 0.000_000_000_014).abs() < 1e-15);
+        let tennessine = Nuclide::new(117, 293).unwrap();
+        assert!((table.relative_atomic_mass(tennessine).unwrap().value - 293.208_63).abs() < 1e-9);
+        assert_eq!(table.is_estimated(tennessine), Some(true));
+        assert_eq!(
+            table.is_estimated(Nuclide::new(6, 12).unwrap()),
+            Some(false)
+        );
+        assert_eq!(table.isotopes(117).count(), 2);
+    }
+
+    #[test]
+    fn invalid() {
+        let line = "0  1    1    0    1 n           8071.31806     0.00044          0.0        0.0 B-     782.3470     0.0004   1  0086x4.91590     0.00047";
+        assert!(matches!(
+            NuclideTable::parse(line),
+            Err(Error::InvalidRecord { line: 1, .. })
+        ));
+    }
+}
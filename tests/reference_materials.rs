@@ -0,0 +1,42 @@
+use atom::{
+    isotopes::{C, H, O},
+    uncertain, Element, Isotope, ReferenceMaterial,
+};
+
+#[test]
+fn ratio() {
+    assert_eq!(
+        ReferenceMaterial::Vsmow.ratio(Isotope::H(H::Two), Isotope::H(H::One)),
+        Some(uncertain!(0.000_155_76, 0.000_000_05)),
+    );
+    let inverse = ReferenceMaterial::Vpdb
+        .ratio(Isotope::C(C::Twelve), Isotope::C(C::Thirteen))
+        .unwrap();
+    assert!((inverse.value - 1.0 / 0.011_180_2).abs() < 1e-9);
+    assert!((inverse.uncertainty / inverse.value - 0.000_002_8 / 0.011_180_2).abs() < 1e-12);
+    assert_eq!(
+        ReferenceMaterial::Vpdb.ratio(Isotope::O(O::Seventeen), Isotope::O(O::Sixteen)),
+        None,
+    );
+}
+
+#[test]
+fn elements() {
+    for reference in ReferenceMaterial::of(Element::O) {
+        assert!(reference.elements().contains(&Element::O));
+    }
+    assert_eq!(
+        ReferenceMaterial::of(Element::H).collect::<Vec<_>>(),
+        [ReferenceMaterial::Vsmow, ReferenceMaterial::Slap],
+    );
+    for reference in [
+        ReferenceMaterial::Vsmow,
+        ReferenceMaterial::Vcdt,
+        ReferenceMaterial::Srm951,
+    ] {
+        for (numerator, denominator, _) in reference.ratios() {
+            assert_eq!(numerator.element(), denominator.element());
+            assert!(reference.elements().contains(&numerator.element()));
+        }
+    }
+}
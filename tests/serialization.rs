@@ -0,0 +1,176 @@
+#![cfg(feature = "serde")]
+
+use atom::{
+    isotopes::{Ag, C, H, U},
+    serialization, uncertain, AtomicWeight, Element, Isotope, Source, Uncertain,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{from_str, json, to_value};
+
+#[test]
+fn element() {
+    assert_eq!(to_value(Element::Fe).unwrap(), json!("Fe"));
+    assert_eq!(from_str::<Element>(r#""Fe""#).unwrap(), Element::Fe);
+    assert_eq!(from_str::<Element>(r#""fe""#).unwrap(), Element::Fe);
+    assert_eq!(from_str::<Element>(r#""Iron""#).unwrap(), Element::Fe);
+    assert_eq!(from_str::<Element>("26").unwrap(), Element::Fe);
+    assert!(from_str::<Element>("0").is_err());
+    assert!(from_str::<Element>("119").is_err());
+    assert!(from_str::<Element>(r#""Xx""#).is_err());
+    let atomic_weight = Element::H.atomic_weight_from(Source::Nist).unwrap();
+    let value = to_value(atomic_weight).unwrap();
+    assert_eq!(value["Standard"][1], json!(["G", "M", "R"]));
+    assert_eq!(
+        serde_json::from_value::<AtomicWeight>(value).unwrap(),
+        atomic_weight
+    );
+}
+
+#[test]
+fn isotope() {
+    let c13 = Isotope::C(C::Thirteen);
+    assert_eq!(to_value(c13).unwrap(), json!("13C"));
+    assert_eq!(
+        to_value(Isotope::Ag(Ag::OneHundredNine)).unwrap(),
+        json!("109Ag")
+    );
+    for text in ["13C", "¹³C", "C-13", "C13", "carbon-13", "[13C]"] {
+        assert_eq!(from_str::<Isotope>(&json!(text).to_string()).unwrap(), c13);
+    }
+    assert_eq!(from_str::<Isotope>(r#""D""#).unwrap(), Isotope::H(H::Two));
+    assert_eq!(from_str::<Isotope>("6013").unwrap(), c13);
+    assert!(from_str::<Isotope>(r#""C-15""#).is_err());
+    assert!(from_str::<Isotope>("6015").is_err());
+    for isotope in atom::ISOTOPES {
+        let value = to_value(isotope).unwrap();
+        assert_eq!(serde_json::from_value::<Isotope>(value).unwrap(), isotope);
+    }
+}
+
+#[test]
+fn uncertain() {
+    let weight = uncertain!(12.011, 0.001);
+    assert_eq!(
+        to_value(weight).unwrap(),
+        json!({ "value": 12.011, "uncertainty": 0.001 })
+    );
+    assert_eq!(
+        from_str::<Uncertain>(r#"{"value":12.011,"uncertainty":0.001}"#).unwrap(),
+        weight
+    );
+    assert_eq!(
+        from_str::<Uncertain>(r#"{"value":12}"#).unwrap(),
+        uncertain!(12.0)
+    );
+    assert_eq!(from_str::<Uncertain>(r#""12.011(1)""#).unwrap(), weight);
+    assert_eq!(
+        from_str::<Uncertain>(r#""12.011 ± 0.001""#).unwrap(),
+        weight
+    );
+    assert_eq!(from_str::<Uncertain>(r#""12.011(0.001)""#).unwrap(), weight);
+    assert_eq!(from_str::<Uncertain>("12").unwrap(), uncertain!(12.0));
+    assert_eq!(from_str::<Uncertain>("12.5").unwrap(), uncertain!(12.5));
+    assert_eq!(
+        from_str::<Uncertain>(r#""0.999885(70)""#).unwrap(),
+        uncertain!(0.999_885, 0.000_070)
+    );
+    assert_eq!(
+        from_str::<Uncertain>(r#""5700(30)""#).unwrap(),
+        uncertain!(5700.0, 30.0)
+    );
+    assert_eq!(
+        from_str::<Uncertain>(r#""1.23e3(4)""#).unwrap(),
+        uncertain!(1230.0, 40.0)
+    );
+    assert!(from_str::<Uncertain>(r#""12.011(1""#).is_err());
+    assert!(from_str::<Uncertain>(r#""12.011(x)""#).is_err());
+    assert!(from_str::<Uncertain>(r#"{"uncertainty":0.1}"#).is_err());
+}
+
+#[test]
+fn with() {
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Record {
+        #[serde(with = "serialization::atomic_number")]
+        element: Element,
+        isotope: Isotope,
+        #[serde(with = "serialization::concise")]
+        weight: Uncertain,
+        #[serde(with = "serialization::concise")]
+        composition: Uncertain,
+        #[serde(with = "serialization::concise")]
+        exact: Uncertain,
+    }
+
+    let record = Record {
+        element: Element::C,
+        isotope: Isotope::C(C::Thirteen),
+        weight: uncertain!(12.011, 0.001),
+        composition: uncertain!(0.010_7, 0.000_8),
+        exact: uncertain!(12.0),
+    };
+    let value = to_value(&record).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "element": 6,
+            "isotope": "13C",
+            "weight": "12.011(1)",
+            "composition": "0.0107(8)",
+            "exact": "12",
+        })
+    );
+    assert_eq!(serde_json::from_value::<Record>(value).unwrap(), record);
+    let lenient = json!({
+        "element": "carbon",
+        "isotope": "C-13",
+        "weight": { "value": 12.011, "uncertainty": 0.001 },
+        "composition": "0.0107 ± 0.0008",
+        "exact": 12,
+    });
+    assert_eq!(serde_json::from_value::<Record>(lenient).unwrap(), record);
+}
+
+#[test]
+fn binary() {
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Record {
+        #[serde(with = "serialization::atomic_number")]
+        element: Element,
+        isotope: Isotope,
+        weight: Uncertain,
+        #[serde(with = "serialization::concise")]
+        composition: Uncertain,
+    }
+
+    assert_eq!(bincode::serialize(&Element::Fe).unwrap(), [26]);
+    // ZAID 92238
+    assert_eq!(
+        bincode::serialize(&Isotope::U(U::TwoHundredThirtyEight)).unwrap(),
+        [0x4e, 0x68, 0x01, 0x00]
+    );
+    assert_eq!(
+        bincode::serialize(&uncertain!(12.011, 0.001))
+            .unwrap()
+            .len(),
+        16
+    );
+    for element in atom::ELEMENTS {
+        let bytes = bincode::serialize(&element).unwrap();
+        assert_eq!(bincode::deserialize::<Element>(&bytes).unwrap(), element);
+    }
+    for isotope in atom::ISOTOPES {
+        let bytes = bincode::serialize(&isotope).unwrap();
+        assert_eq!(bincode::deserialize::<Isotope>(&bytes).unwrap(), isotope);
+    }
+    assert!(bincode::deserialize::<Element>(&[0]).is_err());
+    assert!(bincode::deserialize::<Isotope>(&6_015u32.to_le_bytes()).is_err());
+    let record = Record {
+        element: Element::C,
+        isotope: Isotope::C(C::Thirteen),
+        weight: uncertain!(12.011, 0.001),
+        composition: uncertain!(0.010_7, 0.000_8),
+    };
+    let bytes = bincode::serialize(&record).unwrap();
+    assert_eq!(bincode::deserialize::<Record>(&bytes).unwrap(), record);
+}
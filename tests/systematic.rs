@@ -0,0 +1,52 @@
+use atom::{Element, Systematic};
+
+#[test]
+fn name() {
+    assert_eq!(Systematic::new(118).unwrap().name(), "Ununoctium");
+    assert_eq!(Systematic::new(119).unwrap().name(), "Ununennium");
+    assert_eq!(Systematic::new(120).unwrap().name(), "Unbinilium");
+    assert_eq!(Systematic::new(112).unwrap().name(), "Ununbium");
+    assert_eq!(Systematic::new(113).unwrap().name(), "Ununtrium");
+    assert_eq!(Systematic::new(190).unwrap().name(), "Unennilium");
+    assert_eq!(Systematic::new(200).unwrap().name(), "Binilnilium");
+}
+
+#[test]
+fn symbol() {
+    assert_eq!(Systematic::new(118).unwrap().symbol(), "Uuo");
+    assert_eq!(Systematic::new(119).unwrap().symbol(), "Uue");
+    assert_eq!(Systematic::new(120).unwrap().symbol(), "Ubn");
+    assert_eq!(format!("{}", Systematic::new(119).unwrap()), "Uue");
+    assert_eq!(format!("{:#}", Systematic::new(119).unwrap()), "Ununennium");
+}
+
+#[test]
+fn parse() {
+    for atomic_number in 1..1000 {
+        let systematic = Systematic::new(atomic_number).unwrap();
+        assert_eq!(systematic.name().parse(), Ok(systematic));
+        assert_eq!(systematic.symbol().parse(), Ok(systematic));
+    }
+    assert_eq!("ununennium".parse(), Ok(Systematic::new(119).unwrap()));
+    assert!("Ununenium".parse::<Systematic>().is_err());
+    assert_eq!(
+        "UUE".parse::<Systematic>().unwrap_err().suggestion(),
+        Some("Uue")
+    );
+    assert!("Xyz".parse::<Systematic>().is_err());
+    let error = "  Xyz".parse::<Systematic>().unwrap_err();
+    assert_eq!(error.text(), Some("Xyz"));
+    assert_eq!(error.offset(), Some(2));
+}
+
+#[test]
+fn element() {
+    assert_eq!(Systematic::new(0), None);
+    assert_eq!(Systematic::new(118).unwrap().element(), Some(Element::Og));
+    assert_eq!(Systematic::new(119).unwrap().element(), None);
+    assert_eq!(Systematic::from(Element::Og).atomic_number(), 118);
+    assert_eq!(
+        Element::try_from(Systematic::new(6).unwrap()),
+        Ok(Element::C)
+    );
+}